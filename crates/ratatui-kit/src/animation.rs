@@ -0,0 +1,276 @@
+// 动画基础：插值、缓动曲线与弹簧。
+//
+// - [`Animatable`]：可在两个值之间插值的类型（数字、`Color`、`Rect` 等）。
+// - [`Transition`]：从旧值过渡到新值的方式——定时长的缓动（[`Tween`]）或弹簧物理（[`Spring`]）。
+// - [`crate::UseAnimation`]：在组件中驱动过渡。动画进行中按帧间隔向渲染循环请求下一帧，
+//   结束后不再请求，静止的界面不产生额外渲染。
+//
+// 时间取自组件树的帧时钟（`SystemContext::frame_time`），同一帧内的所有动画在同一时刻采样，
+// 测试装入假时钟即可逐帧断言。
+
+use std::time::{Duration, Instant};
+
+use ratatui::{layout::Rect, style::Color};
+
+// 动画进行中请求下一帧的间隔（约 60 FPS）。
+pub(crate) const FRAME_INTERVAL: Duration = Duration::from_millis(16);
+
+// 可插值的值。`t` 为进度：0 得到 `self`，1 得到 `to`；弹簧过冲时可能略超出 [0, 1]。
+pub trait Animatable: Clone + PartialEq {
+    fn interpolate(&self, to: &Self, t: f64) -> Self;
+}
+
+impl Animatable for f64 {
+    fn interpolate(&self, to: &Self, t: f64) -> Self {
+        self + (to - self) * t
+    }
+}
+
+impl Animatable for f32 {
+    fn interpolate(&self, to: &Self, t: f64) -> Self {
+        f64::from(*self).interpolate(&f64::from(*to), t) as f32
+    }
+}
+
+impl Animatable for u16 {
+    fn interpolate(&self, to: &Self, t: f64) -> Self {
+        f64::from(*self)
+            .interpolate(&f64::from(*to), t)
+            .round()
+            .clamp(0.0, f64::from(u16::MAX)) as u16
+    }
+}
+
+impl Animatable for i32 {
+    fn interpolate(&self, to: &Self, t: f64) -> Self {
+        f64::from(*self)
+            .interpolate(&f64::from(*to), t)
+            .round()
+            .clamp(f64::from(i32::MIN), f64::from(i32::MAX)) as i32
+    }
+}
+
+// 两端都是 `Color::Rgb` 时逐通道插值；其余颜色（具名色、索引色、`Reset`）没有确定的 RGB 值，
+// 在进度过半时直接切换。
+impl Animatable for Color {
+    fn interpolate(&self, to: &Self, t: f64) -> Self {
+        match (self, to) {
+            (Color::Rgb(r1, g1, b1), Color::Rgb(r2, g2, b2)) => {
+                let channel = |from: &u8, to: &u8| {
+                    f64::from(*from)
+                        .interpolate(&f64::from(*to), t)
+                        .round()
+                        .clamp(0.0, 255.0) as u8
+                };
+                Color::Rgb(channel(r1, r2), channel(g1, g2), channel(b1, b2))
+            }
+            _ if t < 0.5 => *self,
+            _ => *to,
+        }
+    }
+}
+
+impl Animatable for Rect {
+    fn interpolate(&self, to: &Self, t: f64) -> Self {
+        Rect {
+            x: self.x.interpolate(&to.x, t),
+            y: self.y.interpolate(&to.y, t),
+            width: self.width.interpolate(&to.width, t),
+            height: self.height.interpolate(&to.height, t),
+        }
+    }
+}
+
+// 缓动曲线，把线性时间进度映射为动画进度。
+#[derive(Clone, Copy, Debug, Default)]
+pub enum Easing {
+    Linear,
+    EaseIn,
+    // 先快后慢，适合进入的元素。
+    #[default]
+    EaseOut,
+    EaseInOut,
+    // 自定义曲线，要求 `f(0) = 0`、`f(1) = 1`。
+    Custom(fn(f64) -> f64),
+}
+
+impl Easing {
+    pub fn apply(self, t: f64) -> f64 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Easing::Linear => t,
+            Easing::EaseIn => t * t * t,
+            Easing::EaseOut => 1.0 - (1.0 - t).powi(3),
+            Easing::EaseInOut if t < 0.5 => 4.0 * t * t * t,
+            Easing::EaseInOut => 1.0 - (-2.0 * t + 2.0).powi(3) / 2.0,
+            Easing::Custom(f) => f(t),
+        }
+    }
+}
+
+// 定时长的缓动过渡。
+#[derive(Clone, Copy, Debug)]
+pub struct Tween {
+    pub duration: Duration,
+    pub easing: Easing,
+}
+
+impl Default for Tween {
+    fn default() -> Self {
+        Self {
+            duration: Duration::from_millis(200),
+            easing: Easing::default(),
+        }
+    }
+}
+
+// 阻尼弹簧。刚度越大越快，阻尼越小回弹越明显；默认参数轻微过冲后停稳。
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Spring {
+    pub stiffness: f64,
+    pub damping: f64,
+    pub mass: f64,
+}
+
+impl Default for Spring {
+    fn default() -> Self {
+        Self {
+            stiffness: 170.0,
+            damping: 20.0,
+            mass: 1.0,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum Transition {
+    Tween(Tween),
+    Spring(Spring),
+}
+
+impl Default for Transition {
+    fn default() -> Self {
+        Transition::Tween(Tween::default())
+    }
+}
+
+impl From<Tween> for Transition {
+    fn from(tween: Tween) -> Self {
+        Transition::Tween(tween)
+    }
+}
+
+impl From<Spring> for Transition {
+    fn from(spring: Spring) -> Self {
+        Transition::Spring(spring)
+    }
+}
+
+// 一次过渡的进度。弹簧以「距终点的位移」建模：从 1 出发，衰减到 0 即到达。
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Progress {
+    started_at: Instant,
+    displacement: f64,
+    velocity: f64,
+    sampled_at: Instant,
+}
+
+impl Progress {
+    // 弹簧的静止阈值（进度单位）。
+    const REST: f64 = 1e-3;
+    // 弹簧积分步长。
+    const STEP: f64 = 0.001;
+
+    pub(crate) fn start(now: Instant) -> Self {
+        Self {
+            started_at: now,
+            displacement: 1.0,
+            velocity: 0.0,
+            sampled_at: now,
+        }
+    }
+
+    // 采样 `now` 时的进度，返回 `(进度, 是否结束)`。
+    pub(crate) fn sample(&mut self, transition: &Transition, now: Instant) -> (f64, bool) {
+        match transition {
+            Transition::Tween(tween) => {
+                let elapsed = now.saturating_duration_since(self.started_at);
+                if elapsed >= tween.duration {
+                    return (1.0, true);
+                }
+                let t = elapsed.as_secs_f64() / tween.duration.as_secs_f64();
+                (tween.easing.apply(t), false)
+            }
+            Transition::Spring(spring) => {
+                // 半隐式欧拉，固定步长积分；长时间未采样（如渲染线程阻塞）时最多补算 1 秒。
+                let elapsed = now
+                    .saturating_duration_since(self.sampled_at)
+                    .as_secs_f64()
+                    .min(1.0);
+                self.sampled_at = now;
+                let steps = (elapsed / Self::STEP).round() as u32;
+                for _ in 0..steps {
+                    let force =
+                        -spring.stiffness * self.displacement - spring.damping * self.velocity;
+                    self.velocity += force / spring.mass * Self::STEP;
+                    self.displacement += self.velocity * Self::STEP;
+                }
+                let at_rest =
+                    self.displacement.abs() < Self::REST && self.velocity.abs() < Self::REST;
+                if at_rest {
+                    (1.0, true)
+                } else {
+                    (1.0 - self.displacement, false)
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn easings_start_at_zero_and_end_at_one() {
+        for easing in [
+            Easing::Linear,
+            Easing::EaseIn,
+            Easing::EaseOut,
+            Easing::EaseInOut,
+        ] {
+            assert_eq!(easing.apply(0.0), 0.0, "{easing:?}");
+            assert_eq!(easing.apply(1.0), 1.0, "{easing:?}");
+        }
+        assert!(Easing::EaseOut.apply(0.5) > 0.5);
+        assert!(Easing::EaseIn.apply(0.5) < 0.5);
+    }
+
+    #[test]
+    fn colors_blend_rgb_and_switch_named() {
+        let from = Color::Rgb(0, 100, 200);
+        let to = Color::Rgb(100, 100, 0);
+        assert_eq!(from.interpolate(&to, 0.5), Color::Rgb(50, 100, 100));
+        assert_eq!(Color::Red.interpolate(&Color::Blue, 0.4), Color::Red);
+        assert_eq!(Color::Red.interpolate(&Color::Blue, 0.6), Color::Blue);
+    }
+
+    #[test]
+    fn spring_overshoots_then_settles() {
+        let start = Instant::now();
+        let mut progress = Progress::start(start);
+        let spring = Transition::Spring(Spring::default());
+        let mut peak: f64 = 0.0;
+        let mut settled_at = None;
+        for frame in 1..=200 {
+            let (value, done) = progress.sample(&spring, start + FRAME_INTERVAL * frame);
+            peak = peak.max(value);
+            if done {
+                settled_at = Some(frame);
+                break;
+            }
+        }
+        assert!(peak > 1.0, "默认弹簧应有轻微过冲");
+        assert!(settled_at.is_some(), "弹簧应在 200 帧内停稳");
+    }
+}
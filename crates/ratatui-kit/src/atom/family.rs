@@ -0,0 +1,278 @@
+use std::{
+    collections::HashMap,
+    hash::Hash,
+    sync::{LazyLock, Mutex},
+    task::Poll,
+};
+
+use crate::{AtomState, ElementKey, Hook};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::Hooks<'_, '_> {}
+}
+
+struct FamilyEntry<T>
+where
+    T: Send + Sync + 'static,
+{
+    state: AtomState<T>,
+    // 经 `use_atom_family` 订阅此 key 的组件数，`remove_unused` 据此回收。
+    subscribers: usize,
+}
+
+// 按参数派生的一组原子（类 Jotai `atomFamily`）：每个 key 惰性创建一个 `AtomState<T>`，
+// 适合“每个打开的文档 / 每条连接一个原子”。
+//
+// 同 `Atom` 一样以 `static` 声明：`static DOCS: AtomFamily<u32, Doc> = AtomFamily::new(|id| Doc::new(*id));`。
+// 状态不会自动释放：用 [`AtomFamily::remove`] 显式删除，或用 [`AtomFamily::remove_unused`]
+// 回收当前没有组件订阅的 key。
+pub struct AtomFamily<K, T>
+where
+    K: Eq + Hash + Clone + Send + Sync + 'static,
+    T: Send + Sync + 'static,
+{
+    init: fn(&K) -> T,
+    entries: LazyLock<Mutex<HashMap<K, FamilyEntry<T>>>>,
+}
+
+impl<K, T> AtomFamily<K, T>
+where
+    K: Eq + Hash + Clone + Send + Sync + 'static,
+    T: Send + Sync + 'static,
+{
+    // 以无捕获初始化器声明原子族（`const fn`，可作 `static`）。
+    pub const fn new(init: fn(&K) -> T) -> Self {
+        Self {
+            init,
+            entries: LazyLock::new(Mutex::default),
+        }
+    }
+
+    // `key` 对应的句柄，首次访问时以 `init(key)` 创建。
+    pub fn state(&self, key: &K) -> AtomState<T> {
+        self.entry(key, |entry| entry.state)
+    }
+
+    // 组件外直接读（`T: Copy`）。
+    pub fn get(&self, key: &K) -> T
+    where
+        T: Copy,
+    {
+        self.state(key).get()
+    }
+
+    // 组件外直接写（触发该 key 的订阅者重渲）。
+    pub fn set(&self, key: &K, value: T) {
+        self.state(key).set(value);
+    }
+
+    pub fn contains(&self, key: &K) -> bool {
+        self.entries.lock().unwrap().contains_key(key)
+    }
+
+    // 当前存活的 key（快照，顺序不定）。
+    pub fn keys(&self) -> Vec<K> {
+        self.entries.lock().unwrap().keys().cloned().collect()
+    }
+
+    // 删除 `key` 并释放其状态；之前取得的该 key 句柄随之失效，再读写会 panic。
+    // 仍在订阅的组件会被唤醒，下一帧拿到以 `init(key)` 重新创建的状态。
+    pub fn remove(&self, key: &K) -> bool {
+        let removed = self.entries.lock().unwrap().remove(key);
+        match removed {
+            Some(entry) => {
+                entry.state.dispose();
+                true
+            }
+            None => false,
+        }
+    }
+
+    // 删除所有没有组件订阅的 key，返回删除的数量。仅经 `state`/`get`/`set` 访问过的 key
+    // 也算作未订阅。
+    pub fn remove_unused(&self) -> usize {
+        let mut removed = Vec::new();
+        self.entries.lock().unwrap().retain(|_, entry| {
+            let keep = entry.subscribers > 0;
+            if !keep {
+                removed.push(entry.state);
+            }
+            keep
+        });
+        for state in &removed {
+            state.dispose();
+        }
+        removed.len()
+    }
+
+    fn entry<R>(&self, key: &K, f: impl FnOnce(&mut FamilyEntry<T>) -> R) -> R {
+        let mut entries = self.entries.lock().unwrap();
+        let entry = entries.entry(key.clone()).or_insert_with(|| FamilyEntry {
+            state: AtomState::new((self.init)(key)),
+            subscribers: 0,
+        });
+        f(entry)
+    }
+
+    // 当前登记的句柄，不创建。
+    fn peek(&self, key: &K) -> Option<AtomState<T>> {
+        self.entries
+            .lock()
+            .unwrap()
+            .get(key)
+            .map(|entry| entry.state)
+    }
+
+    fn acquire(&self, key: &K) -> AtomState<T> {
+        self.entry(key, |entry| {
+            entry.subscribers += 1;
+            entry.state
+        })
+    }
+
+    // 退订；`state` 已被删除（key 被重新创建）时不影响新状态的计数。
+    fn release(&self, key: &K, state: &AtomState<T>) {
+        if let Some(entry) = self.entries.lock().unwrap().get_mut(key)
+            && entry.state.same_storage(state)
+        {
+            entry.subscribers -= 1;
+        }
+    }
+}
+
+pub trait UseAtomFamily: private::Sealed {
+    // 订阅原子族中 `key` 对应的原子，返回 `Copy + Send` 句柄。`key` 变化时改订新 key，
+    // 卸载时退订；写入只唤醒订阅了同一 key 的组件。
+    fn use_atom_family<K, T>(&mut self, family: &'static AtomFamily<K, T>, key: K) -> AtomState<T>
+    where
+        K: Eq + Hash + Clone + Unpin + Send + Sync + 'static,
+        T: Unpin + Send + Sync + 'static;
+}
+
+impl UseAtomFamily for crate::Hooks<'_, '_> {
+    fn use_atom_family<K, T>(&mut self, family: &'static AtomFamily<K, T>, key: K) -> AtomState<T>
+    where
+        K: Eq + Hash + Clone + Unpin + Send + Sync + 'static,
+        T: Unpin + Send + Sync + 'static,
+    {
+        let hook = self.use_hook(|| UseAtomFamilyImpl {
+            family,
+            state: family.acquire(&key),
+            key: key.clone(),
+            element_key: None,
+        });
+        if !std::ptr::eq(hook.family, family) || hook.key != key || hook.is_replaced() {
+            hook.release();
+            hook.family = family;
+            hook.state = family.acquire(&key);
+            hook.key = key;
+        }
+        hook.state
+    }
+}
+
+struct UseAtomFamilyImpl<K, T>
+where
+    K: Eq + Hash + Clone + Unpin + Send + Sync + 'static,
+    T: Unpin + Send + Sync + 'static,
+{
+    family: &'static AtomFamily<K, T>,
+    key: K,
+    state: AtomState<T>,
+    element_key: Option<ElementKey>,
+}
+
+impl<K, T> UseAtomFamilyImpl<K, T>
+where
+    K: Eq + Hash + Clone + Unpin + Send + Sync + 'static,
+    T: Unpin + Send + Sync + 'static,
+{
+    // 订阅的 key 已被 `remove` 删除（或删除后重建）。
+    fn is_replaced(&self) -> bool {
+        self.family
+            .peek(&self.key)
+            .is_none_or(|state| !state.same_storage(&self.state))
+    }
+
+    fn release(&self) {
+        if let Some(element_key) = &self.element_key {
+            self.state.remove_waker(element_key);
+        }
+        self.family.release(&self.key, &self.state);
+    }
+}
+
+impl<K, T> Hook for UseAtomFamilyImpl<K, T>
+where
+    K: Eq + Hash + Clone + Unpin + Send + Sync + 'static,
+    T: Unpin + Send + Sync + 'static,
+{
+    fn poll_change(&mut self, cx: &mut std::task::Context) -> Poll<()> {
+        let Some(element_key) = &self.element_key else {
+            return Poll::Pending;
+        };
+
+        if self.is_replaced() {
+            return Poll::Ready(());
+        }
+        self.state.poll_change(Some(element_key), cx)
+    }
+
+    fn post_component_update(&mut self, updater: &mut crate::ComponentUpdater) {
+        if self.element_key.is_none() {
+            self.element_key = Some(updater.key().clone());
+        }
+    }
+
+    fn on_drop(&mut self) {
+        self.release();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn creates_one_state_per_key() {
+        static COUNTS: AtomFamily<&'static str, i32> = AtomFamily::new(|key| key.len() as i32);
+
+        assert_eq!(COUNTS.get(&"ab"), 2);
+        COUNTS.set(&"ab", 10);
+        assert_eq!(COUNTS.get(&"ab"), 10);
+        assert_eq!(COUNTS.get(&"xyz"), 3);
+        assert!(COUNTS.state(&"ab").same_storage(&COUNTS.state(&"ab")));
+
+        let mut keys = COUNTS.keys();
+        keys.sort();
+        assert_eq!(keys, ["ab", "xyz"]);
+    }
+
+    #[test]
+    fn remove_disposes_and_recreates_on_next_access() {
+        static DOCS: AtomFamily<u32, String> = AtomFamily::new(|id| format!("doc {id}"));
+
+        let old = DOCS.state(&1);
+        DOCS.state(&1).set("edited".to_string());
+        assert!(DOCS.remove(&1));
+        assert!(!DOCS.remove(&1));
+        assert!(!DOCS.contains(&1));
+        assert!(old.try_read().is_none(), "删除后旧句柄失效");
+        assert_eq!(*DOCS.state(&1).read(), "doc 1");
+    }
+
+    #[test]
+    fn remove_unused_keeps_subscribed_keys() {
+        static FLAGS: AtomFamily<u8, bool> = AtomFamily::new(|_| false);
+
+        let subscribed = FLAGS.acquire(&1);
+        FLAGS.state(&2);
+        assert_eq!(FLAGS.remove_unused(), 1);
+        assert_eq!(FLAGS.keys(), [1]);
+
+        FLAGS.release(&1, &subscribed);
+        assert_eq!(FLAGS.remove_unused(), 1);
+        assert!(FLAGS.keys().is_empty());
+    }
+}
@@ -1,11 +1,25 @@
-use std::sync::{LazyLock, OnceLock};
+use std::{
+    sync::{Arc, LazyLock, Mutex, OnceLock},
+    task::{Wake, Waker},
+};
 
 use generational_box::{Owner, SyncStorage};
 
-use crate::{ReactiveHandle, ReactiveMutRef, ReactiveRef, WakerMap};
+use crate::{
+    ReactiveHandle, ReactiveMutRef, ReactiveRef, WakerMap,
+    reactive_handle::{Dependency, record, track},
+};
 
+mod family;
+pub(crate) mod store;
+mod subscribe;
 mod use_atom;
-pub use use_atom::UseAtom;
+mod use_store;
+pub use family::{AtomFamily, UseAtomFamily};
+pub use store::{ActionLog, Store, StoreMiddleware};
+pub use subscribe::AtomSubscription;
+pub use use_atom::{UseAtom, UseAtomSelector};
+pub use use_store::UseStore;
 
 pub(crate) static OWNER: LazyLock<Owner<SyncStorage>> = LazyLock::new(Owner::default);
 
@@ -22,12 +36,83 @@ pub type AtomStateMut<'a, T> = ReactiveMutRef<'a, T, WakerMap>;
 //
 // 在组件内用 [`crate::UseAtom::use_atom`] 订阅；组件外/后台任务可经 [`Atom::get`]/[`Atom::set`]
 // 或经 `use_atom` 返回的 `Copy + Send` 句柄直接读写。
+//
+// 派生原子 `Atom::derived(|atoms| atoms.get(&A) * 2)` 由其它原子计算而来：求值时读取的原子
+// 即为依赖，任一依赖变更时唤醒订阅者，下次读取时重新计算。
 pub struct Atom<T>
 where
     T: Send + Sync + 'static,
 {
-    init: fn() -> T,
+    init: AtomInit<T>,
     cell: OnceLock<AtomState<T>>,
+    // 派生原子上一次求值读到的依赖。
+    dependencies: Mutex<Vec<Dependency>>,
+    // 派生原子登记到依赖上的 waker，固定一个以便依赖侧按 `will_wake` 去重。
+    waker: OnceLock<Waker>,
+}
+
+enum AtomInit<T>
+where
+    T: Send + Sync + 'static,
+{
+    Value(fn() -> T),
+    Derived(fn(&AtomGetter) -> T),
+    #[cfg(feature = "serde")]
+    Persisted {
+        key: &'static str,
+        version: u32,
+        init: fn() -> T,
+        // 单态化的 `restore::<T>`，使 `Atom<T>` 本身无需 serde 约束。
+        restore: RestoreFn<T>,
+    },
+}
+
+#[cfg(feature = "serde")]
+type RestoreFn<T> = fn(&'static str, u32, fn() -> T) -> AtomState<T>;
+
+// 读取持久化的值（失败时用 `init()`）创建句柄，并开始持久化。
+#[cfg(feature = "serde")]
+fn restore<T>(key: &'static str, version: u32, init: fn() -> T) -> AtomState<T>
+where
+    T: serde::Serialize + serde::de::DeserializeOwned + Send + Sync + 'static,
+{
+    let state = AtomState::new(crate::persist::load(key, version).unwrap_or_else(init));
+    crate::persist::Persisted::attach(key.to_owned(), version, state);
+    state
+}
+
+// 派生原子求值时读取其它原子的入口，读到的原子记为依赖。
+pub struct AtomGetter(());
+
+impl AtomGetter {
+    pub fn get<U>(&self, atom: &'static Atom<U>) -> U
+    where
+        U: Clone + Send + Sync + 'static,
+    {
+        atom.state().read().clone()
+    }
+}
+
+// 依赖变更时只标记派生原子已变更、唤醒其订阅者；重新计算推迟到下次读取。
+// 这里不能读依赖：唤醒发生在依赖的写锁释放之前。
+struct DerivedWake<T>
+where
+    T: Send + Sync + 'static,
+{
+    state: AtomState<T>,
+}
+
+impl<T> Wake for DerivedWake<T>
+where
+    T: Send + Sync + 'static,
+{
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.state.mark_changed();
+    }
 }
 
 impl<T> Atom<T>
@@ -36,15 +121,68 @@ where
 {
     // 以无捕获初始化器声明一个全局原子（`const fn`，可作 `static`）。
     pub const fn new(init: fn() -> T) -> Self {
+        Self::with_init(AtomInit::Value(init))
+    }
+
+    // 声明一个派生原子：`compute` 经 `AtomGetter` 读取其它原子（派生原子亦可）。
+    // 派生原子只读：直接写入的值会在下次依赖变更后被重新计算的结果覆盖。
+    pub const fn derived(compute: fn(&AtomGetter) -> T) -> Self {
+        Self::with_init(AtomInit::Derived(compute))
+    }
+
+    const fn with_init(init: AtomInit<T>) -> Self {
         Self {
             init,
             cell: OnceLock::new(),
+            dependencies: Mutex::new(Vec::new()),
+            waker: OnceLock::new(),
         }
     }
 
     // 惰性解析底层句柄（首次调用时以 `init()` 创建并插入全局 OWNER）。
+    // 派生原子在依赖变更后于此重新计算。
     pub fn state(&self) -> AtomState<T> {
-        *self.cell.get_or_init(|| AtomState::new((self.init)()))
+        match self.init {
+            AtomInit::Value(init) => *self.cell.get_or_init(|| AtomState::new(init())),
+            AtomInit::Derived(compute) => self.derived_state(compute),
+            #[cfg(feature = "serde")]
+            AtomInit::Persisted {
+                key,
+                version,
+                init,
+                restore,
+            } => *self.cell.get_or_init(|| restore(key, version, init)),
+        }
+    }
+
+    fn derived_state(&self, compute: fn(&AtomGetter) -> T) -> AtomState<T> {
+        let mut dependencies = self.dependencies.lock().unwrap();
+        let cached = self.cell.get().copied();
+        let state = match cached {
+            Some(state) if !dependencies.iter().any(Dependency::is_stale) => state,
+            _ => {
+                let (value, tracked) = track(|| compute(&AtomGetter(())));
+                let state = match cached {
+                    Some(mut state) => {
+                        state.set_no_update(value);
+                        state
+                    }
+                    None => *self.cell.get_or_init(|| AtomState::new(value)),
+                };
+                let waker = self
+                    .waker
+                    .get_or_init(|| Waker::from(Arc::new(DerivedWake { state })));
+                for dependency in &tracked {
+                    dependency.observe(waker);
+                }
+                *dependencies = tracked;
+                state
+            }
+        };
+        // 外层求值读到派生原子时连同其依赖一并追踪：依赖的版本在写入时立即推进，
+        // 外层据此即可判断过期，不必等派生原子的唤醒（批次中会推迟）。
+        record(dependencies.iter().cloned());
+        state
     }
 
     // 组件外直接读（`T: Copy`）。
@@ -55,10 +193,36 @@ where
         self.state().get()
     }
 
-    // 组件外直接写（触发订阅者重渲）。
+    // 组件外直接写（触发订阅者重渲）。派生原子不应写入。
     pub fn set(&self, value: T) {
         self.state().set(value);
     }
+
+    // 组件树之外订阅变更：返回的 `Stream` 在之后每次写入时产出新值，
+    // `into_blocking_iter` 转为阻塞迭代器。
+    pub fn subscribe(&'static self) -> AtomSubscription<T>
+    where
+        T: Clone,
+    {
+        AtomSubscription::new(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T> Atom<T>
+where
+    T: serde::Serialize + serde::de::DeserializeOwned + Send + Sync + 'static,
+{
+    // 声明一个持久化原子：首次访问时从持久化目录下的 `<key>.json` 恢复，之后的写入防抖落盘。
+    // 文件缺失、`version` 与文件中的不符或无法解析时用 `init()`；改变结构时递增 `version`。
+    pub const fn persisted(key: &'static str, version: u32, init: fn() -> T) -> Self {
+        Self::with_init(AtomInit::Persisted {
+            key,
+            version,
+            init,
+            restore: restore::<T>,
+        })
+    }
 }
 
 #[cfg(test)]
@@ -109,4 +273,38 @@ mod tests {
         assert_eq!(B.get(), 5);
         assert_eq!(B.state().get(), 5);
     }
+
+    #[test]
+    fn derived_atom_recomputes_after_a_dependency_changes() {
+        static WIDTH: Atom<i32> = Atom::new(|| 2);
+        static HEIGHT: Atom<i32> = Atom::new(|| 3);
+        static AREA: Atom<i32> = Atom::derived(|atoms| atoms.get(&WIDTH) * atoms.get(&HEIGHT));
+        static LABEL: Atom<String> = Atom::derived(|atoms| format!("area {}", atoms.get(&AREA)));
+
+        assert_eq!(AREA.get(), 6);
+        assert_eq!(*LABEL.state().read(), "area 6");
+        WIDTH.set(4);
+        assert_eq!(AREA.get(), 12);
+        // 批次中派生原子的唤醒被推迟，外层仍经传递依赖判断过期。
+        crate::batch(|| {
+            HEIGHT.set(10);
+            assert_eq!(*LABEL.state().read(), "area 40");
+        });
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn persisted_atom_restores_and_falls_back_on_version_mismatch() {
+        crate::persist::tests::use_temp_dir();
+        static FILTER: Atom<String> = Atom::persisted("atom-filter", 2, || "all".to_string());
+        static FILTER_V1: Atom<String> = Atom::persisted("atom-filter", 1, || "v1".to_string());
+        static FILTER_AGAIN: Atom<String> = Atom::persisted("atom-filter", 2, String::new);
+
+        assert_eq!(*FILTER.state().read(), "all");
+        FILTER.set("done".to_string());
+        crate::flush_persisted().unwrap();
+
+        assert_eq!(*FILTER_AGAIN.state().read(), "done");
+        assert_eq!(*FILTER_V1.state().read(), "v1", "版本不符时回退到初始值");
+    }
 }
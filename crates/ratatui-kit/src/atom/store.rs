@@ -0,0 +1,422 @@
+// Redux 式全局 store：状态 + 类型化动作 + 纯归约器，构建在 `Atom` 之上。
+//
+// 同 `Atom` 一样以 `static` 声明：`static TODOS: Store<Todos, TodoAction> = Store::new(Todos::default, reduce);`。
+// `dispatch` 依次经过中间件（日志、鉴权、埋点等）后由归约器修改状态，订阅者经 `use_store` /
+// `use_store_selector` 按需重渲。
+//
+// - 异步 thunk：`dispatch_async(|store| async move { ... store.dispatch(..) })` 把 future 交给
+//   store，由挂载中的 `use_store*` 订阅者在渲染循环中轮询，不依赖具体的异步运行时。
+// - 持久化（`serde` 特性）：`Store::persisted` 以 `Atom::persisted` 承载状态，写入防抖落盘。
+// - 动作日志：`record_actions(limit)` 之后每个到达归约器的动作连同状态快照记入日志，可经
+//   `use_action_log` 展示（如 `ActionLogPanel`）、`jump_to` 回到任一历史状态，或 `dump_log` 写入文件。
+
+use std::{
+    fmt::Debug,
+    fs, io,
+    path::Path,
+    sync::{Arc, Mutex, RwLock},
+    task::{Context, Waker},
+};
+
+use futures::{FutureExt, future::BoxFuture};
+
+use crate::{Atom, AtomState};
+
+// store 中间件：包在归约器外层，决定是否、以及用什么动作调用 `next`（可调用零次或多次）。
+// 可在 `next` 前后经 `store.state()` 读取状态，或经 `store.dispatch` 派发后续动作。
+// 闭包 `Fn(&'static Store<S, A>, A, &dyn Fn(A))` 自动实现本 trait。
+pub trait StoreMiddleware<S, A>: Send + Sync + 'static
+where
+    S: Send + Sync + 'static,
+    A: 'static,
+{
+    fn handle(&self, store: &'static Store<S, A>, action: A, next: &dyn Fn(A));
+}
+
+impl<S, A, F> StoreMiddleware<S, A> for F
+where
+    S: Send + Sync + 'static,
+    A: 'static,
+    F: Fn(&'static Store<S, A>, A, &dyn Fn(A)) + Send + Sync + 'static,
+{
+    fn handle(&self, store: &'static Store<S, A>, action: A, next: &dyn Fn(A)) {
+        self(store, action, next)
+    }
+}
+
+// 动作日志：每条为动作的 `Debug` 文本，首条 `@@init` 对应开始记录时的状态。
+// `cursor` 指向当前状态所在的条目，`jump_to` 之后可能不是最后一条。
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ActionLog {
+    entries: Vec<String>,
+    cursor: usize,
+}
+
+impl ActionLog {
+    pub fn entries(&self) -> &[String] {
+        &self.entries
+    }
+
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+}
+
+// 与 `ActionLog` 条目一一对应的状态快照。`S: Clone` / `A: Debug` 的约束只在
+// `record_actions` 上出现，以函数指针保存。
+struct Recorder<S, A> {
+    snapshots: Vec<S>,
+    limit: usize,
+    describe: fn(&A) -> String,
+    snapshot: fn(&S) -> S,
+}
+
+pub struct Store<S, A>
+where
+    S: Send + Sync + 'static,
+    A: 'static,
+{
+    state: Atom<S>,
+    reducer: fn(&mut S, A),
+    middleware: RwLock<Vec<Arc<dyn StoreMiddleware<S, A>>>>,
+    recorder: Mutex<Option<Recorder<S, A>>>,
+    log: Atom<ActionLog>,
+    thunks: Mutex<Vec<BoxFuture<'static, ()>>>,
+    // 最近一次轮询 thunk 的订阅者 waker，新 thunk 到达时唤醒它。
+    waker: Mutex<Option<Waker>>,
+}
+
+impl<S, A> Store<S, A>
+where
+    S: Send + Sync + 'static,
+    A: 'static,
+{
+    // 以无捕获初始化器与归约器声明 store（`const fn`，可作 `static`）。
+    pub const fn new(init: fn() -> S, reducer: fn(&mut S, A)) -> Self {
+        Self::with_state(Atom::new(init), reducer)
+    }
+
+    const fn with_state(state: Atom<S>, reducer: fn(&mut S, A)) -> Self {
+        Self {
+            state,
+            reducer,
+            middleware: RwLock::new(Vec::new()),
+            recorder: Mutex::new(None),
+            log: Atom::new(ActionLog::default),
+            thunks: Mutex::new(Vec::new()),
+            waker: Mutex::new(None),
+        }
+    }
+
+    // 底层状态句柄。直接写入会绕过归约器、中间件与动作日志，通常只用于读取。
+    pub fn state(&self) -> AtomState<S> {
+        self.state.state()
+    }
+
+    // 追加一个中间件。先追加的在外层，应在首次派发前完成注册。
+    pub fn add_middleware(&self, middleware: impl StoreMiddleware<S, A>) {
+        self.middleware.write().unwrap().push(Arc::new(middleware));
+    }
+
+    // 派发一个动作。在中间件或归约器内部再次派发的动作排队，待当前动作处理完后依次处理。
+    pub fn dispatch(&'static self, action: A) {
+        crate::hooks::dispatch_queued(self as *const Self as usize, action, |action| {
+            self.run(0, action)
+        });
+    }
+
+    // 从第 `index` 个中间件开始处理动作，中间件都调用过 `next` 后交给归约器。
+    fn run(&'static self, index: usize, action: A) {
+        let middleware = self.middleware.read().unwrap().get(index).cloned();
+        match middleware {
+            Some(middleware) => {
+                middleware.handle(self, action, &|action| self.run(index + 1, action))
+            }
+            None => self.reduce(action),
+        }
+    }
+
+    fn reduce(&self, action: A) {
+        let description = self
+            .recorder
+            .lock()
+            .unwrap()
+            .as_ref()
+            .map(|recorder| (recorder.describe)(&action));
+        let state = self.state();
+        let Some(mut value) = state.try_write() else {
+            return;
+        };
+        (self.reducer)(&mut value, action);
+        let Some(description) = description else {
+            return;
+        };
+        let mut recorder = self.recorder.lock().unwrap();
+        let Some(recorder) = recorder.as_mut() else {
+            return;
+        };
+        let snapshot = (recorder.snapshot)(&value);
+        drop(value);
+
+        let log_state = self.log.state();
+        let mut log = log_state.write();
+        // 回到过去的状态后再派发：丢弃被跳过的分支。
+        let keep = log.cursor + 1;
+        log.entries.truncate(keep);
+        recorder.snapshots.truncate(keep);
+        log.entries.push(description);
+        recorder.snapshots.push(snapshot);
+        let overflow = recorder.snapshots.len().saturating_sub(recorder.limit);
+        if overflow > 0 {
+            log.entries.drain(..overflow);
+            recorder.snapshots.drain(..overflow);
+        }
+        log.cursor = log.entries.len() - 1;
+    }
+
+    // 开始记录动作日志，最多保留 `limit` 条（含初始条目，至少 1 条）。重复调用会清空日志。
+    pub fn record_actions(&self, limit: usize)
+    where
+        S: Clone,
+        A: Debug,
+    {
+        let initial = self.state().read().clone();
+        *self.recorder.lock().unwrap() = Some(Recorder {
+            snapshots: vec![initial],
+            limit: limit.max(1),
+            describe: |action| format!("{action:?}"),
+            snapshot: S::clone,
+        });
+        self.log.set(ActionLog {
+            entries: vec!["@@init".to_string()],
+            cursor: 0,
+        });
+    }
+
+    // 当前的动作日志；未开启记录时为空。
+    pub fn action_log(&self) -> ActionLog {
+        self.log.state().read().clone()
+    }
+
+    // 时间旅行：把状态恢复为第 `index` 条日志之后的快照（不经过中间件，也不记入日志）。
+    // 之后再派发动作会丢弃 `index` 之后的条目。越界或未开启记录时返回 `false`。
+    pub fn jump_to(&self, index: usize) -> bool {
+        let snapshot = {
+            let recorder = self.recorder.lock().unwrap();
+            let Some(recorder) = recorder.as_ref() else {
+                return false;
+            };
+            let Some(snapshot) = recorder.snapshots.get(index) else {
+                return false;
+            };
+            (recorder.snapshot)(snapshot)
+        };
+        self.state().set(snapshot);
+        self.log.state().write().cursor = index;
+        true
+    }
+
+    // 把动作日志写入文本文件，每行一条，当前状态所在的条目以 `>` 标出。
+    pub fn dump_log(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let log = self.action_log();
+        let mut text = String::new();
+        for (index, entry) in log.entries.iter().enumerate() {
+            let marker = if index == log.cursor { '>' } else { ' ' };
+            text.push_str(&format!("{marker} {index:>4} {entry}\n"));
+        }
+        fs::write(path, text)
+    }
+
+    // 派发异步 thunk：`thunk` 拿到 store 并返回 future，其中可以 await 后再 `dispatch`。
+    // future 由挂载中的 `use_store` / `use_store_selector` 订阅者在渲染循环中轮询；
+    // 没有订阅者时不会推进。
+    pub fn dispatch_async<F, Fut>(&'static self, thunk: F)
+    where
+        F: FnOnce(&'static Self) -> Fut,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.thunks.lock().unwrap().push(thunk(self).boxed());
+        if let Some(waker) = self.waker.lock().unwrap().take() {
+            waker.wake();
+        }
+    }
+
+    pub(crate) fn log_atom(&self) -> &Atom<ActionLog> {
+        &self.log
+    }
+
+    pub(crate) fn atom(&self) -> &Atom<S> {
+        &self.state
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<S, A> Store<S, A>
+where
+    S: serde::Serialize + serde::de::DeserializeOwned + Send + Sync + 'static,
+    A: 'static,
+{
+    // 声明一个持久化 store：状态以 `Atom::persisted` 承载，首次访问时从 `<key>.json` 恢复，
+    // 之后每个动作造成的变更防抖落盘。
+    pub const fn persisted(
+        key: &'static str,
+        version: u32,
+        init: fn() -> S,
+        reducer: fn(&mut S, A),
+    ) -> Self {
+        Self::with_state(Atom::persisted(key, version, init), reducer)
+    }
+}
+
+// 供订阅者 hook 轮询 thunk 的类型擦除入口。
+pub(crate) trait ThunkQueue: Send + Sync {
+    fn poll_thunks(&self, cx: &mut Context<'_>);
+}
+
+impl<S, A> ThunkQueue for Store<S, A>
+where
+    S: Send + Sync + 'static,
+    A: 'static,
+{
+    fn poll_thunks(&self, cx: &mut Context<'_>) {
+        *self.waker.lock().unwrap() = Some(cx.waker().clone());
+        // 轮询期间不持锁：thunk 可能派发新的 thunk。
+        let mut thunks = std::mem::take(&mut *self.thunks.lock().unwrap());
+        thunks.retain_mut(|thunk| thunk.poll_unpin(cx).is_pending());
+        if !thunks.is_empty() {
+            let mut queue = self.thunks.lock().unwrap();
+            thunks.append(&mut queue);
+            *queue = thunks;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::{channel::oneshot, task::noop_waker};
+
+    #[derive(Debug)]
+    enum Action {
+        Add(i32),
+        Reset,
+    }
+
+    fn counter(state: &mut i32, action: Action) {
+        match action {
+            Action::Add(n) => *state += n,
+            Action::Reset => *state = 0,
+        }
+    }
+
+    #[test]
+    fn middleware_wraps_the_reducer_in_order() {
+        static STORE: Store<i32, Action> = Store::new(|| 0, counter);
+        static LOG: Mutex<Vec<String>> = Mutex::new(Vec::new());
+        STORE.add_middleware(
+            |store: &'static Store<i32, Action>, action: Action, next: &dyn Fn(Action)| {
+                let before = store.state().get();
+                next(action);
+                LOG.lock()
+                    .unwrap()
+                    .push(format!("{before} -> {}", store.state().get()));
+            },
+        );
+        STORE.add_middleware(
+            |_: &'static Store<i32, Action>, action, next: &dyn Fn(Action)| {
+                if !matches!(action, Action::Reset) {
+                    next(action);
+                }
+            },
+        );
+
+        STORE.dispatch(Action::Add(2));
+        STORE.dispatch(Action::Reset);
+        STORE.dispatch(Action::Add(3));
+
+        assert_eq!(STORE.state().get(), 5);
+        assert_eq!(*LOG.lock().unwrap(), ["0 -> 2", "2 -> 2", "2 -> 5"]);
+    }
+
+    #[test]
+    fn middleware_dispatch_is_queued_after_the_current_action() {
+        static STORE: Store<i32, Action> = Store::new(|| 0, counter);
+        STORE.record_actions(10);
+        STORE.add_middleware(
+            |store: &'static Store<i32, Action>, action: Action, next: &dyn Fn(Action)| {
+                // 清零之后补一个初始值：后续动作在 `Reset` 归约完成后才处理。
+                if matches!(action, Action::Reset) {
+                    store.dispatch(Action::Add(1));
+                }
+                next(action);
+            },
+        );
+
+        STORE.dispatch(Action::Add(5));
+        STORE.dispatch(Action::Reset);
+        assert_eq!(STORE.state().get(), 1);
+        assert_eq!(
+            STORE.action_log().entries(),
+            ["@@init", "Add(5)", "Reset", "Add(1)"]
+        );
+    }
+
+    #[test]
+    fn jump_to_restores_snapshots_and_drops_the_skipped_branch() {
+        static STORE: Store<i32, Action> = Store::new(|| 1, counter);
+        STORE.record_actions(10);
+        STORE.dispatch(Action::Add(2));
+        STORE.dispatch(Action::Add(3));
+        assert_eq!(STORE.action_log().entries(), ["@@init", "Add(2)", "Add(3)"]);
+
+        assert!(STORE.jump_to(1));
+        assert_eq!(STORE.state().get(), 3);
+        assert_eq!(STORE.action_log().cursor(), 1);
+        assert!(!STORE.jump_to(5));
+
+        STORE.dispatch(Action::Reset);
+        let log = STORE.action_log();
+        assert_eq!(log.entries(), ["@@init", "Add(2)", "Reset"]);
+        assert_eq!(log.cursor(), 2);
+        assert_eq!(STORE.state().get(), 0);
+    }
+
+    #[test]
+    fn action_log_is_capped() {
+        static STORE: Store<i32, Action> = Store::new(|| 0, counter);
+        STORE.record_actions(2);
+        STORE.dispatch(Action::Add(1));
+        STORE.dispatch(Action::Add(2));
+        assert_eq!(STORE.action_log().entries(), ["Add(1)", "Add(2)"]);
+        assert!(STORE.jump_to(0));
+        assert_eq!(STORE.state().get(), 1);
+    }
+
+    #[test]
+    fn thunks_are_polled_by_subscribers() {
+        static STORE: Store<i32, Action> = Store::new(|| 0, counter);
+        let (tx, rx) = oneshot::channel::<i32>();
+        STORE.dispatch_async(|store| async move {
+            if let Ok(n) = rx.await {
+                store.dispatch(Action::Add(n));
+            }
+        });
+        let waker = noop_waker();
+        let mut cx = Context::from_waker(&waker);
+
+        STORE.poll_thunks(&mut cx);
+        assert_eq!(STORE.state().get(), 0);
+        tx.send(4).unwrap();
+        STORE.poll_thunks(&mut cx);
+        assert_eq!(STORE.state().get(), 4);
+        assert!(STORE.thunks.lock().unwrap().is_empty());
+    }
+}
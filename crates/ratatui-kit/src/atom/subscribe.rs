@@ -0,0 +1,143 @@
+// 组件树之外订阅原子：后台线程、tokio 任务等非 UI 代码经 `Atom::subscribe` 拿到变更流，
+// 与组件共用 `WakerMap` 登记，每个订阅占一个独立的 key。
+
+use std::{
+    pin::Pin,
+    sync::atomic::{AtomicU64, Ordering},
+    task::{Context, Poll},
+};
+
+use futures::{Stream, executor::block_on_stream};
+
+use crate::{Atom, AtomState, ElementKey, reactive_handle::Source};
+
+// 订阅者 key 的类型标记，保证不与组件的 `ElementKey` 冲突。
+#[derive(PartialEq, Eq, Hash)]
+struct Subscriber;
+
+static NEXT_SUBSCRIBER: AtomicU64 = AtomicU64::new(0);
+
+// `Atom::subscribe` 返回的变更流：每次原子被（带通知地）写入后产出写入后的值。
+// 消费慢于写入时合并为最新值，不会逐次排队。drop 时退订。
+pub struct AtomSubscription<T>
+where
+    T: Send + Sync + 'static,
+{
+    atom: &'static Atom<T>,
+    state: AtomState<T>,
+    key: ElementKey,
+    // 已产出的版本。
+    seen: u64,
+}
+
+// 只持有句柄与 key，值在 generational-box 中，对任意 `T` 都可移动。
+impl<T> Unpin for AtomSubscription<T> where T: Send + Sync + 'static {}
+
+impl<T> AtomSubscription<T>
+where
+    T: Clone + Send + Sync + 'static,
+{
+    pub(crate) fn new(atom: &'static Atom<T>) -> Self {
+        let state = atom.state();
+        Self {
+            atom,
+            state,
+            key: ElementKey::user((Subscriber, NEXT_SUBSCRIBER.fetch_add(1, Ordering::Relaxed))),
+            seen: state.version().unwrap_or_default(),
+        }
+    }
+
+    // 阻塞式迭代：每次 `next` 阻塞当前线程直到下一次变更。适合普通线程里的同步 worker。
+    pub fn into_blocking_iter(self) -> impl Iterator<Item = T> {
+        block_on_stream(self)
+    }
+}
+
+impl<T> Stream for AtomSubscription<T>
+where
+    T: Clone + Send + Sync + 'static,
+{
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        let this = self.get_mut();
+        // 派生原子先在此重新计算，读到的才是最新值。
+        this.atom.state();
+        this.state
+            .poll_version(&this.key, &mut this.seen, cx, T::clone)
+    }
+}
+
+impl<T> Drop for AtomSubscription<T>
+where
+    T: Send + Sync + 'static,
+{
+    fn drop(&mut self) {
+        self.state.remove_waker(&self.key);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::StreamExt;
+    use std::{thread, time::Duration};
+
+    #[test]
+    fn stream_yields_latest_value_after_each_change() {
+        static COUNT: Atom<i32> = Atom::new(|| 0);
+        let mut changes = COUNT.subscribe();
+
+        COUNT.set(1);
+        COUNT.set(2);
+        assert_eq!(
+            futures::executor::block_on(changes.next()),
+            Some(2),
+            "合并为最新值"
+        );
+
+        let waker = futures::task::noop_waker();
+        let mut cx = Context::from_waker(&waker);
+        assert!(changes.poll_next_unpin(&mut cx).is_pending());
+        assert!(COUNT.state().has_waker(&changes.key));
+
+        let key = changes.key.clone();
+        drop(changes);
+        assert!(!COUNT.state().has_waker(&key), "drop 时退订");
+    }
+
+    #[test]
+    fn blocking_iter_wakes_on_writes_from_another_thread() {
+        static NAME: Atom<String> = Atom::new(String::new);
+        static DOUBLE: Atom<usize> = Atom::derived(|atoms| atoms.get(&NAME).len() * 2);
+        let mut names = NAME.subscribe().into_blocking_iter();
+        let mut doubled = DOUBLE.subscribe().into_blocking_iter();
+
+        let writer = thread::spawn(|| {
+            thread::sleep(Duration::from_millis(20));
+            NAME.set("abc".to_string());
+        });
+        assert_eq!(names.next().as_deref(), Some("abc"));
+        assert_eq!(doubled.next(), Some(6));
+        writer.join().unwrap();
+    }
+
+    #[test]
+    fn component_polls_do_not_drop_the_subscription() {
+        static FLAG: Atom<bool> = Atom::new(|| false);
+        let mut changes = FLAG.subscribe();
+        let waker = futures::task::noop_waker();
+        let mut cx = Context::from_waker(&waker);
+        assert!(changes.poll_next_unpin(&mut cx).is_pending());
+
+        // 组件消费变更后，订阅仍然登记着。
+        FLAG.set(true);
+        let component = ElementKey::decl(1);
+        assert!(
+            FLAG.state()
+                .poll_change(Some(&component), &mut cx)
+                .is_ready()
+        );
+        assert!(FLAG.state().has_waker(&changes.key));
+    }
+}
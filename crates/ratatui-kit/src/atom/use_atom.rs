@@ -14,6 +14,16 @@ pub trait UseAtom: private::Sealed {
         T: Unpin + Send + Sync + 'static;
 }
 
+pub trait UseAtomSelector: private::Sealed {
+    // 订阅原子的一部分：返回 `selector` 作用于当前值的结果。原子变更时重新选取，
+    // 仅当结果与上次不同（`PartialEq`）时才重渲本组件。
+    fn use_atom_selector<T, U, F>(&mut self, atom: &'static Atom<T>, selector: F) -> U
+    where
+        T: Unpin + Send + Sync + 'static,
+        U: PartialEq + Clone + Unpin + 'static,
+        F: Fn(&T) -> U + 'static;
+}
+
 impl UseAtom for crate::Hooks<'_, '_> {
     fn use_atom<T>(&mut self, atom: &'static Atom<T>) -> AtomState<T>
     where
@@ -75,6 +85,17 @@ where
     }
 }
 
+impl UseAtomSelector for crate::Hooks<'_, '_> {
+    fn use_atom_selector<T, U, F>(&mut self, atom: &'static Atom<T>, selector: F) -> U
+    where
+        T: Unpin + Send + Sync + 'static,
+        U: PartialEq + Clone + Unpin + 'static,
+        F: Fn(&T) -> U + 'static,
+    {
+        crate::hooks::use_handle_selector(self, atom.state(), selector)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
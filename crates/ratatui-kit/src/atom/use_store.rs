@@ -0,0 +1,74 @@
+use std::task::Poll;
+
+use crate::{ActionLog, AtomState, Hook, Store, UseAtom, UseAtomSelector, atom::store::ThunkQueue};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::Hooks<'_, '_> {}
+}
+
+pub trait UseStore: private::Sealed {
+    // 订阅整个 store 状态，每次动作改变状态都重渲本组件。返回的句柄用于读取，
+    // 修改请经 `store.dispatch`。
+    fn use_store<S, A>(&mut self, store: &'static Store<S, A>) -> AtomState<S>
+    where
+        S: Unpin + Send + Sync + 'static,
+        A: 'static;
+
+    // 订阅 store 状态的一部分，仅当 `selector` 的结果变化时重渲（同 `use_atom_selector`）。
+    fn use_store_selector<S, A, U, F>(&mut self, store: &'static Store<S, A>, selector: F) -> U
+    where
+        S: Unpin + Send + Sync + 'static,
+        A: 'static,
+        U: PartialEq + Clone + Unpin + 'static,
+        F: Fn(&S) -> U + 'static;
+
+    // 订阅 store 的动作日志（需先 `record_actions`），用于在应用内展示并时间旅行。
+    fn use_action_log<S, A>(&mut self, store: &'static Store<S, A>) -> ActionLog
+    where
+        S: Unpin + Send + Sync + 'static,
+        A: 'static;
+}
+
+// 在渲染循环中轮询 store 的异步 thunk。
+struct UseStoreImpl {
+    store: &'static dyn ThunkQueue,
+}
+
+impl Hook for UseStoreImpl {
+    fn poll_change(&mut self, cx: &mut std::task::Context) -> Poll<()> {
+        // thunk 的效果经 `dispatch` 写入状态，由状态订阅唤醒，这里不请求重渲。
+        self.store.poll_thunks(cx);
+        Poll::Pending
+    }
+}
+
+impl UseStore for crate::Hooks<'_, '_> {
+    fn use_store<S, A>(&mut self, store: &'static Store<S, A>) -> AtomState<S>
+    where
+        S: Unpin + Send + Sync + 'static,
+        A: 'static,
+    {
+        self.use_hook(|| UseStoreImpl { store }).store = store;
+        self.use_atom(store.atom())
+    }
+
+    fn use_store_selector<S, A, U, F>(&mut self, store: &'static Store<S, A>, selector: F) -> U
+    where
+        S: Unpin + Send + Sync + 'static,
+        A: 'static,
+        U: PartialEq + Clone + Unpin + 'static,
+        F: Fn(&S) -> U + 'static,
+    {
+        self.use_hook(|| UseStoreImpl { store }).store = store;
+        self.use_atom_selector(store.atom(), selector)
+    }
+
+    fn use_action_log<S, A>(&mut self, store: &'static Store<S, A>) -> ActionLog
+    where
+        S: Unpin + Send + Sync + 'static,
+        A: 'static,
+    {
+        self.use_atom(store.log_atom()).read().clone()
+    }
+}
@@ -5,6 +5,7 @@ use crate::{
     hooks::{AnyHook, Hook, Hooks},
     multimap::RemoveOnlyMultimap,
     props::AnyProps,
+    reactive_handle::BatchGuard,
     render::{ComponentDrawer, ComponentUpdater, layout_style::LayoutStyle},
     terminal::UpdaterTerminal,
 };
@@ -165,6 +166,8 @@ impl InstantiatedComponent {
     }
 
     pub(crate) fn poll_change(&mut self, cx: &mut Context) -> Poll<()> {
+        // hook 持有的 future 在轮询中可能连续写多个状态：整棵子树的轮询作为一个批次，只唤醒一次。
+        let _batch = BatchGuard::enter();
         // 三路必须全部 poll,即使前一路已 Ready 也不能短路;否则 Pending 的路
         // 无法在本轮注册 waker,后续变更会丢唤醒。
         let component_status = self.component.poll_change(cx);
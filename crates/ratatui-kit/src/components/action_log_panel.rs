@@ -0,0 +1,88 @@
+// ActionLogPanel 组件：在应用内展示 `Store` 的动作日志，选中一条即回到该条之后的状态。
+//
+// 日志来自 `use_action_log`，`on_jump` 收到条目下标，通常直接转给 `Store::jump_to`。
+// 列表复用 `Select` 的键位与主题：j/k 移动，Enter / 双击跳转；当前状态所在的条目默认选中。
+//
+// ## 示例
+// ```rust
+// let log = hooks.use_action_log(&TODOS);
+// element!(ActionLogPanel(
+//     log: log,
+//     on_jump: move |index| { TODOS.jump_to(index); },
+//     width: Constraint::Length(32),
+// ))
+// ```
+
+use ratatui::{layout::Constraint, text::Line, widgets::ListState};
+use ratatui_kit_macros::{Props, component, element, with_layout_style};
+
+use crate::{
+    ActionLog, AnyElement, Handler, Hooks, UseState,
+    components::{Select, TextParagraph},
+};
+
+#[with_layout_style(margin, offset, width, height)]
+#[derive(Props)]
+// ActionLogPanel 组件属性。
+pub struct ActionLogPanelProps {
+    // 要展示的动作日志。
+    pub log: ActionLog,
+    // 选中条目时回调，参数为条目下标。
+    pub on_jump: Handler<'static, usize>,
+    // 标题，默认 " actions "。
+    pub top_title: Option<Line<'static>>,
+    // 是否响应键盘。
+    pub active: bool,
+}
+
+impl Default for ActionLogPanelProps {
+    fn default() -> Self {
+        Self {
+            log: ActionLog::default(),
+            on_jump: Handler::default(),
+            top_title: None,
+            active: true,
+            margin: Default::default(),
+            offset: Default::default(),
+            width: Default::default(),
+            height: Default::default(),
+        }
+    }
+}
+
+#[component]
+pub fn ActionLogPanel(
+    props: &mut ActionLogPanelProps,
+    mut hooks: Hooks,
+) -> impl Into<AnyElement<'static>> {
+    let list = hooks.use_state(ListState::default);
+    let items: Vec<String> = props
+        .log
+        .entries()
+        .iter()
+        .enumerate()
+        .map(|(index, entry)| format!("{index:>3} {entry}"))
+        .collect();
+    let cursor = (!props.log.is_empty()).then_some(props.log.cursor());
+    let mut on_jump = props.on_jump.take();
+
+    element!(Select<String>(
+        items: items,
+        state: list,
+        default_index: cursor,
+        active: props.active,
+        highlight_symbol: "> ",
+        top_title: props.top_title.clone().unwrap_or_else(|| Line::from(" actions ")),
+        empty_message: TextParagraph::from("Action recording is off"),
+        empty_width: Constraint::Percentage(80),
+        margin: props.margin,
+        offset: props.offset,
+        width: props.width,
+        height: props.height,
+        on_select: move |_entry: String| {
+            if let Some(index) = list.read().selected() {
+                on_jump(index);
+            }
+        },
+    ))
+}
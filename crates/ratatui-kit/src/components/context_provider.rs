@@ -9,6 +9,8 @@
 // })
 // ```
 // 子组件可通过 `hooks.use_context::<MyData>()` 获取注入的数据。
+// 需要在提供者之外写入并通知消费者时，改用 `create_context` 声明的响应式上下文，
+// 以 `value: CONTEXT.provide(state)` 注入，详见 `use_context_selector`。
 
 use crate::{AnyElement, Component, Context};
 use ratatui_kit_macros::Props;
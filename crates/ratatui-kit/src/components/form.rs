@@ -0,0 +1,188 @@
+// Form / FormField 组件：配合 `use_form` 组织多字段表单。
+//
+// `Form` 是带布局的容器，在当前输入层处理 Tab / Shift+Tab，在字段间移动焦点；
+// `FormField` 为一个字段渲染标签、子内容与错误文案（字段 touched 或提交过后才展示）。
+// 输入类组件经 `field` 属性绑定字段，焦点所在的字段接收键盘输入。
+//
+// ## 示例
+// ```rust
+// let form = hooks.use_form(|form| {
+//     form.field("name", String::new()).validate(|name: &String| {
+//         if name.is_empty() { Err("Name is required".to_string()) } else { Ok(()) }
+//     });
+// });
+// let name = form.field::<String>("name");
+//
+// element!(Form(form: form, flex_direction: Direction::Vertical) {
+//     FormField(field: name, label: "Name", height: Constraint::Length(5)) {
+//         SearchInput(field: name)
+//     }
+// })
+// ```
+
+use crossterm::event::{Event, KeyCode, KeyEventKind};
+use ratatui::{
+    layout::{Constraint, Direction},
+    style::{Modifier, Style},
+    text::Line,
+};
+use ratatui_kit_macros::{Props, element, with_layout_style};
+
+use crate::{
+    AnyElement, Component, ComponentTheme, FieldRef, FormHandle, Palette, UseEventHandler,
+    components::theme::resolve_style,
+    components::{Text, View},
+    input::{EventPriority, EventResult, EventScope},
+};
+
+#[with_layout_style]
+#[derive(Default, Props)]
+// Form 组件属性。
+pub struct FormProps<'a> {
+    // `use_form` 返回的表单句柄；`None` 时只作布局容器。
+    pub form: Option<FormHandle>,
+    // 子元素列表。
+    pub children: Vec<AnyElement<'a>>,
+}
+
+// Form 组件实现。
+pub struct Form;
+
+impl Component for Form {
+    type Props<'a> = FormProps<'a>;
+
+    fn new(_props: &Self::Props<'_>) -> Self {
+        Self
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        mut hooks: crate::Hooks,
+        updater: &mut crate::ComponentUpdater,
+    ) {
+        // 事件 handler 依赖上下文中的输入运行时。
+        let mut hooks = hooks.with_context_stack(updater.component_context_stack());
+        let form = props.form;
+        hooks.use_event_handler(EventScope::Current, EventPriority::Normal, move |event| {
+            let (Some(form), Event::Key(key)) = (form, event) else {
+                return EventResult::Ignored;
+            };
+            if key.kind != KeyEventKind::Press {
+                return EventResult::Ignored;
+            }
+            match key.code {
+                KeyCode::Tab => form.focus_next(),
+                KeyCode::BackTab => form.focus_prev(),
+                _ => return EventResult::Ignored,
+            }
+            EventResult::Consumed
+        });
+        updater.set_layout_style(props.layout_style());
+        updater.update_children(&mut props.children, None);
+    }
+}
+
+/// FormField 组件的主题 slot。
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FormFieldTheme {
+    /// 标签样式。
+    pub label_style: Style,
+    /// 字段获得焦点时的标签样式。
+    pub focused_label_style: Style,
+    /// 错误文案样式。
+    pub error_style: Style,
+}
+
+impl ComponentTheme for FormFieldTheme {
+    fn from_palette(palette: &Palette) -> Self {
+        Self {
+            label_style: Style::new().fg(palette.fg_dim),
+            focused_label_style: Style::new().fg(palette.accent).add_modifier(Modifier::BOLD),
+            error_style: Style::new().fg(palette.error),
+        }
+    }
+}
+
+impl Default for FormFieldTheme {
+    fn default() -> Self {
+        Self::from_palette(&Palette::default())
+    }
+}
+
+#[with_layout_style(margin, offset, width, height)]
+#[derive(Default, Props)]
+// FormField 组件属性。
+pub struct FormFieldProps<'a> {
+    // 展示其状态的字段，可直接传 `Field<T>`。
+    pub field: Option<FieldRef>,
+    // 标签，空时不占行。
+    pub label: Line<'static>,
+    // 子元素列表，通常是一个绑定了同一字段的输入组件。
+    pub children: Vec<AnyElement<'a>>,
+    // 以下样式覆盖:`None` 用 `FormFieldTheme`,`Some(s)` 以 `theme.patch(s)` 覆盖。
+    pub label_style: Option<Style>,
+    pub focused_label_style: Option<Style>,
+    pub error_style: Option<Style>,
+}
+
+// FormField 组件实现。
+pub struct FormField;
+
+impl Component for FormField {
+    type Props<'a> = FormFieldProps<'a>;
+
+    fn new(_props: &Self::Props<'_>) -> Self {
+        Self
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        _hooks: crate::Hooks,
+        updater: &mut crate::ComponentUpdater,
+    ) {
+        let theme = updater.use_component_theme::<FormFieldTheme>();
+        let focused = props.field.is_some_and(|field| field.is_focused());
+        let label_style = if focused {
+            resolve_style(theme.focused_label_style, props.focused_label_style)
+        } else {
+            resolve_style(theme.label_style, props.label_style)
+        };
+        let error_style = resolve_style(theme.error_style, props.error_style);
+        let error = props
+            .field
+            .and_then(|field| field.display_error())
+            .unwrap_or_default();
+        let has_label = props.label.width() > 0;
+        let label = props.label.clone();
+
+        updater.set_transparent_layout(true);
+        updater.update_children(
+            [element!(
+                View(
+                    flex_direction: Direction::Vertical,
+                    margin: props.margin,
+                    offset: props.offset,
+                    width: props.width,
+                    height: props.height,
+                ) {
+                    if has_label {
+                        View(height: Constraint::Length(1)) {
+                            Text(text: label, style: label_style)
+                        }
+                    }
+                    View(height: Constraint::Fill(1)) {
+                        { props.children.iter_mut() }
+                    }
+                    // 错误行始终占位，出现错误时布局不跳动。
+                    View(height: Constraint::Length(1)) {
+                        Text(text: error, style: error_style)
+                    }
+                }
+            )],
+            None,
+        );
+    }
+}
@@ -1,10 +1,15 @@
 use crate::{
-    AnyElement, ComponentTheme, Hooks, Palette, UsePreviousSize, UseTheme,
+    AnyElement, ComponentTheme, Field, Hooks, Palette, UseEventHandler, UsePreviousSize, UseState,
+    UseTheme,
     components::theme::resolve_style,
+    input::{EventPriority, EventResult, EventScope},
     prelude::{Fragment, Positioned, Text},
 };
+use crossterm::event::{Event, KeyCode, KeyEventKind};
 use ratatui::{style::Style, text::Span};
 use ratatui_kit_macros::{Props, component, element};
+use std::borrow::Cow;
+use tui_input::backend::crossterm::to_input_request;
 
 /// Input 组件的主题 slot。
 #[non_exhaustive]
@@ -34,6 +39,62 @@ impl Default for InputTheme {
     }
 }
 
+// 粘贴文本中换行的处理方式。单行输入里的换行既无法显示，也不应像 `Enter` 那样触发提交。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PasteNewlines {
+    // 每处换行（`\n` / `\r\n` / `\r`）替换为一个空格。
+    #[default]
+    Space,
+    // 删除换行，前后文本直接相连。
+    Strip,
+    // 只保留第一行。
+    FirstLine,
+    // 原样保留。
+    Keep,
+}
+
+impl PasteNewlines {
+    // 按本策略规整粘贴文本；无换行时不分配。末尾换行（复制整行时常带）直接丢弃。
+    // 不少终端在粘贴时把 `\n` 转成 `\r`，故三种换行一视同仁。
+    pub fn apply(self, text: &str) -> Cow<'_, str> {
+        if self == Self::Keep || !text.contains(['\n', '\r']) {
+            return Cow::Borrowed(text);
+        }
+        let mut lines = text
+            .trim_end_matches(['\n', '\r'])
+            .split("\r\n")
+            .flat_map(|line| line.split(['\n', '\r']));
+        match self {
+            Self::Space => Cow::Owned(lines.collect::<Vec<_>>().join(" ")),
+            Self::Strip => Cow::Owned(lines.collect()),
+            Self::FirstLine => Cow::Borrowed(lines.next().unwrap_or_default()),
+            Self::Keep => unreachable!("`Keep` returns the text unchanged above"),
+        }
+    }
+}
+
+// 把一次粘贴（`Event::Paste`）整体插入光标处：一次写入、光标移到插入文本之后。
+// `tui_input` 的 `handle_event` 不处理粘贴，受控 `Input` 的页面 handler 用它转发。
+// 返回值是否改变。
+pub fn paste_into(input: &mut tui_input::Input, text: &str, newlines: PasteNewlines) -> bool {
+    let text = newlines.apply(text);
+    if text.is_empty() {
+        return false;
+    }
+    let cursor = input.cursor();
+    let at = input
+        .value()
+        .char_indices()
+        .nth(cursor)
+        .map_or(input.value().len(), |(index, _)| index);
+    let mut value = input.value().to_string();
+    value.insert_str(at, &text);
+    *input = std::mem::take(input)
+        .with_value(value)
+        .with_cursor(cursor + text.chars().count());
+    true
+}
+
 #[derive(Debug, Clone, Props, Default)]
 pub struct InputProps {
     pub input: tui_input::Input,
@@ -45,6 +106,11 @@ pub struct InputProps {
     // 输入文本样式覆盖。`None` 用主题,`Some(s)` 以 `theme.patch(s)` 覆盖。
     pub style: Option<Style>,
     pub hide_cursor: bool,
+    // 绑定的表单字段。绑定后显示字段值、忽略 `input`，字段获得焦点时自行处理键入与粘贴，
+    // `Esc` 移除焦点；Tab / Enter 留给 `Form` 与页面。
+    pub field: Option<Field<String>>,
+    // 绑定字段时粘贴文本中换行的处理方式，默认替换为空格。
+    pub paste_newlines: PasteNewlines,
 }
 
 #[component]
@@ -55,10 +121,51 @@ pub fn Input(props: &InputProps, mut hooks: Hooks) -> impl Into<AnyElement<'stat
     let placeholder_style = resolve_style(theme.placeholder_style, props.placeholder_style);
     let style = resolve_style(theme.style, props.style);
 
+    // 绑定字段时的编辑态（光标位置）。字段被外部改写（如重置）时以新值重建，光标移到末尾。
+    let editor = hooks.use_state(tui_input::Input::default);
+    let field = props.field;
+    if let Some(field) = field {
+        let value = field.get();
+        if editor.read().value() != value {
+            *editor.write_no_update() = tui_input::Input::new(value);
+        }
+    }
+    let paste_newlines = props.paste_newlines;
+    hooks.use_event_handler(EventScope::Current, EventPriority::Normal, move |event| {
+        let Some(field) = field.filter(|field| field.is_focused()) else {
+            return EventResult::Ignored;
+        };
+        let changed = match &event {
+            Event::Key(key) if key.kind == KeyEventKind::Press && key.code == KeyCode::Esc => {
+                field.blur();
+                return EventResult::Consumed;
+            }
+            Event::Key(key) if key.kind == KeyEventKind::Press => {
+                let Some(request) = to_input_request(&event) else {
+                    return EventResult::Ignored;
+                };
+                editor
+                    .write()
+                    .handle(request)
+                    .is_some_and(|changed| changed.value)
+            }
+            Event::Paste(text) => paste_into(&mut editor.write(), text, paste_newlines),
+            _ => return EventResult::Ignored,
+        };
+        if changed {
+            field.set(editor.read().value().to_string());
+        }
+        EventResult::Consumed
+    });
+
     let size = hooks.use_previous_size();
-    let input = &props.input;
+    let editor = editor.read();
+    let (input, hide_cursor) = match field {
+        Some(field) => (&*editor, props.hide_cursor || !field.is_focused()),
+        None => (&props.input, props.hide_cursor),
+    };
     let input_width = size.width.saturating_sub(1) as usize;
-    let scroll = if props.hide_cursor || input_width == 0 {
+    let scroll = if hide_cursor || input_width == 0 {
         0
     } else {
         input.visual_scroll(input_width)
@@ -83,7 +190,7 @@ pub fn Input(props: &InputProps, mut hooks: Hooks) -> impl Into<AnyElement<'stat
             },
             scroll:(0, scroll as u16),
         )
-        if !props.hide_cursor {
+        if !hide_cursor {
             Positioned(
                 x: position.0.min(size.x + size.width.saturating_sub(1)),
                 y: position.1.min(size.y + size.height),
@@ -95,3 +202,28 @@ pub fn Input(props: &InputProps, mut hooks: Hooks) -> impl Into<AnyElement<'stat
         }
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn newline_modes_normalize_pasted_text() {
+        let text = "a\r\nb\nc\rd\n";
+        assert_eq!(PasteNewlines::Space.apply(text), "a b c d");
+        assert_eq!(PasteNewlines::Strip.apply(text), "abcd");
+        assert_eq!(PasteNewlines::FirstLine.apply(text), "a");
+        assert_eq!(PasteNewlines::Keep.apply(text), text);
+    }
+
+    #[test]
+    fn paste_inserts_at_cursor_in_one_step() {
+        let mut input = tui_input::Input::new("héllo".to_string()).with_cursor(2);
+        assert!(paste_into(&mut input, "XY\nZ", PasteNewlines::Space));
+        assert_eq!(input.value(), "héXY Zllo");
+        assert_eq!(input.cursor(), 6);
+
+        assert!(!paste_into(&mut input, "\n", PasteNewlines::Strip));
+        assert_eq!(input.value(), "héXY Zllo");
+    }
+}
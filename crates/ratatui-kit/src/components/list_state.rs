@@ -1,4 +1,7 @@
-use ratatui::widgets::ListState;
+use ratatui::{
+    layout::{Margin, Offset, Position, Rect},
+    widgets::{Block, ListState},
+};
 
 pub(crate) fn sync_default_selection(
     state: &mut ListState,
@@ -19,6 +22,36 @@ pub(crate) fn sync_default_selection(
     }
 }
 
+// 列表内容区:组件区域先按 offset/margin 收缩(与外层 `Border` 的 layout_style 一致),再去掉四周边框。
+pub(crate) fn list_content_area(area: Rect, margin: Margin, offset: Offset) -> Rect {
+    Block::bordered().inner(area.offset(offset).inner(margin))
+}
+
+// 指针所在位置对应的列表项下标。`offset` 是上一帧渲染后的首个可见项,`heights` 为每项行高。
+pub(crate) fn list_index_at(
+    area: Rect,
+    offset: usize,
+    heights: &[u16],
+    position: Position,
+) -> Option<usize> {
+    if !area.contains(position) {
+        return None;
+    }
+
+    let mut top = area.y;
+    for (index, height) in heights.iter().enumerate().skip(offset) {
+        let bottom = top.saturating_add(*height);
+        if position.y < bottom {
+            return Some(index);
+        }
+        if bottom >= area.bottom() {
+            break;
+        }
+        top = bottom;
+    }
+    None
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -54,4 +87,45 @@ mod tests {
         sync_default_selection(&mut state, &mut last_default, Some(1), 5);
         assert_eq!(state.selected(), Some(1));
     }
+
+    #[test]
+    fn list_index_at_accounts_for_offset_and_item_heights() {
+        let area = Rect::new(1, 1, 10, 4);
+        let heights = [1, 2, 1, 1, 1];
+
+        assert_eq!(
+            list_index_at(area, 0, &heights, Position::new(2, 1)),
+            Some(0)
+        );
+        assert_eq!(
+            list_index_at(area, 0, &heights, Position::new(2, 3)),
+            Some(1)
+        );
+        assert_eq!(
+            list_index_at(area, 0, &heights, Position::new(2, 4)),
+            Some(2)
+        );
+        assert_eq!(
+            list_index_at(area, 3, &heights, Position::new(2, 1)),
+            Some(3)
+        );
+    }
+
+    #[test]
+    fn list_index_at_misses_outside_area_and_below_last_item() {
+        let area = Rect::new(1, 1, 10, 4);
+        let heights = [1, 1];
+
+        assert_eq!(list_index_at(area, 0, &heights, Position::new(0, 1)), None);
+        assert_eq!(list_index_at(area, 0, &heights, Position::new(2, 3)), None);
+    }
+
+    #[test]
+    fn list_content_area_strips_margin_and_border() {
+        let area = Rect::new(0, 0, 10, 6);
+        assert_eq!(
+            list_content_area(area, Margin::new(1, 0), Offset::default()),
+            Rect::new(2, 1, 6, 4)
+        );
+    }
 }
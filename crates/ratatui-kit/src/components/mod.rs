@@ -29,6 +29,14 @@ pub use select::*;
 // 多选列表组件，封装多选状态与键盘选择事件。
 pub mod multi_select;
 pub use multi_select::*;
+// 表单容器与字段组件，配合 `use_form` 处理焦点切换、标签与错误展示。
+pub mod form;
+pub use form::*;
+// 动作日志面板，展示 `Store` 的动作日志并支持时间旅行。
+#[cfg(feature = "atom")]
+pub mod action_log_panel;
+#[cfg(feature = "atom")]
+pub use action_log_panel::*;
 // 表格组件，支持自绘 grid、换行、响应式列等高级表格能力。
 #[cfg(feature = "table")]
 pub mod table;
@@ -1,23 +1,26 @@
-// MultiSelect 组件：带键盘事件处理的多选列表。
+// MultiSelect 组件：带键盘 / 鼠标事件处理的多选列表。
+//
+// 经 `field` 绑定表单字段（值为已勾选项下标集合）时，勾选与字段双向同步，`selected` 不生效；
+// 只在字段获得焦点时响应键盘，单击列表会先让字段获得焦点。
 
-use std::collections::HashSet;
+use std::{cell::RefCell, collections::HashSet, rc::Rc};
 
-use crossterm::event::{Event, KeyCode, KeyEventKind};
+use crossterm::event::{Event, KeyCode, KeyEventKind, MouseButton, MouseEventKind};
 use ratatui::{
     layout::{Alignment, Constraint},
-    style::Style,
+    style::{Modifier, Style},
     text::Line,
     widgets::{List, ListItem, ListState},
 };
 use ratatui_kit_macros::{Props, component, element, with_layout_style};
 
-use super::list_state::sync_default_selection;
+use super::list_state::{list_content_area, list_index_at, sync_default_selection};
 use crate::{
-    AnyElement, ComponentTheme, Handler, Hooks, Palette, State, UseEffect, UseEventHandler,
-    UseState, UseTheme,
+    AnyElement, ComponentTheme, Field, Handler, Hooks, Palette, State, UseClick, UseEffect,
+    UseEventHandler, UseHover, UseState, UseTheme,
     components::theme::resolve_style,
     components::{Border, Center, Text, TextParagraph},
-    input::{EventPriority, EventResult, EventScope},
+    input::{EventOptions, EventPriority, EventResult, EventScope},
 };
 
 /// MultiSelect 组件的主题 slot。高亮为「`on_accent` 前景 + `selection` 底」;已勾选项取 `accent`。
@@ -30,6 +33,8 @@ pub struct MultiSelectTheme {
     pub border_style: Style,
     /// 光标所在项高亮样式。
     pub highlight_style: Style,
+    /// 鼠标悬停项样式。
+    pub hover_style: Style,
     /// 已勾选项样式。
     pub selected_item_style: Style,
     /// 空态提示样式。
@@ -42,6 +47,9 @@ impl ComponentTheme for MultiSelectTheme {
             style: Style::new().fg(palette.fg),
             border_style: Style::new().fg(palette.border),
             highlight_style: Style::new().fg(palette.on_accent).bg(palette.selection),
+            hover_style: Style::new()
+                .bg(palette.surface)
+                .add_modifier(Modifier::BOLD),
             selected_item_style: Style::new().fg(palette.accent),
             empty_style: Style::new().fg(palette.warning),
         }
@@ -75,10 +83,13 @@ where
     pub style: Option<Style>,
     pub border_style: Option<Style>,
     pub highlight_style: Option<Style>,
+    pub hover_style: Option<Style>,
     pub selected_item_style: Option<Style>,
     pub empty_style: Option<Style>,
     pub empty_width: Constraint,
     pub empty_height: Constraint,
+    // 绑定的表单字段，值为已勾选项下标集合。
+    pub field: Option<Field<HashSet<usize>>>,
 }
 
 impl<T> Default for MultiSelectProps<T>
@@ -101,10 +112,12 @@ where
             style: None,
             border_style: None,
             highlight_style: None,
+            hover_style: None,
             selected_item_style: None,
             empty_style: None,
             empty_width: Constraint::Percentage(50),
             empty_height: Constraint::Length(5),
+            field: None,
             margin: Default::default(),
             offset: Default::default(),
             width: Default::default(),
@@ -160,82 +173,153 @@ where
         (selected_index, item_count),
     );
 
-    let active = props.active;
+    // 字段 → 勾选集合：字段值是勾选的来源，勾选变化由 `toggle_selected` 写回。
+    let field = props.field;
+    if let Some(field) = field {
+        field.with(|value| {
+            if *selected.read() != *value {
+                *selected.write_no_update() = value.clone();
+            }
+        });
+    }
+
+    let enabled = props.active;
+    let active = enabled && field.is_none_or(|field| field.is_focused());
     let items = props.items.clone();
-    let mut on_change = props.on_change.take();
+    let list_items: Vec<ListItem<'static>> = props.items.iter().cloned().map(Into::into).collect();
+    let heights: Vec<u16> = list_items.iter().map(|item| item.height() as u16).collect();
+    let (margin, offset) = (props.margin, props.offset);
+    // 键盘空格与鼠标双击共用同一个 on_change。
+    let on_change = Rc::new(RefCell::new(props.on_change.take()));
     let mut on_select = props.on_select.take();
 
-    hooks.use_event_handler(EventScope::Current, EventPriority::Normal, move |event| {
-        if !active || item_count == 0 {
-            return EventResult::Ignored;
-        }
-
-        let Event::Key(key) = event else {
-            return EventResult::Ignored;
-        };
-        if key.kind != KeyEventKind::Press {
-            return EventResult::Ignored;
-        }
+    // 键盘导航 + 区域内滚轮。`hit_test` 只过滤鼠标事件,键盘不受影响。
+    hooks.use_event_handler_with_options(
+        EventScope::Current,
+        EventPriority::Normal,
+        EventOptions { hit_test: true },
+        {
+            let items = items.clone();
+            let on_change = on_change.clone();
+            move |event| {
+                if !active || item_count == 0 {
+                    return EventResult::Ignored;
+                }
 
-        match key.code {
-            KeyCode::Char('j') | KeyCode::Down => {
-                state.write().select_next();
-                EventResult::Consumed
-            }
-            KeyCode::Char('k') | KeyCode::Up => {
-                state.write().select_previous();
-                EventResult::Consumed
+                match event {
+                    Event::Key(key) if key.kind == KeyEventKind::Press => match key.code {
+                        KeyCode::Char('j') | KeyCode::Down => {
+                            state.write().select_next();
+                            EventResult::Consumed
+                        }
+                        KeyCode::Char('k') | KeyCode::Up => {
+                            state.write().select_previous();
+                            EventResult::Consumed
+                        }
+                        KeyCode::Home => {
+                            state.write().select_first();
+                            EventResult::Consumed
+                        }
+                        KeyCode::End => {
+                            state.write().select_last();
+                            EventResult::Consumed
+                        }
+                        KeyCode::Char(' ') => {
+                            if let Some(index) = state.read().selected() {
+                                toggle_selected(selected, field, index);
+                                let changed_items = selected_items(&items, &selected.read());
+                                (on_change.borrow_mut())(changed_items);
+                            }
+                            EventResult::Consumed
+                        }
+                        KeyCode::Enter => {
+                            let chosen_items = selected_items(&items, &selected.read());
+                            on_select(chosen_items);
+                            EventResult::Consumed
+                        }
+                        _ => EventResult::Ignored,
+                    },
+                    Event::Mouse(mouse) => match mouse.kind {
+                        MouseEventKind::ScrollDown => {
+                            state.write().select_next();
+                            EventResult::Consumed
+                        }
+                        MouseEventKind::ScrollUp => {
+                            state.write().select_previous();
+                            EventResult::Consumed
+                        }
+                        _ => EventResult::Ignored,
+                    },
+                    _ => EventResult::Ignored,
+                }
             }
-            KeyCode::Home => {
-                state.write().select_first();
-                EventResult::Consumed
+        },
+    );
+
+    // 单击移动光标,双击切换勾选(等同空格)。
+    hooks.use_click({
+        let heights = heights.clone();
+        move |click| {
+            if !enabled || click.button != MouseButton::Left {
+                return EventResult::Ignored;
             }
-            KeyCode::End => {
-                state.write().select_last();
-                EventResult::Consumed
+            if !active {
+                let Some(field) = field else {
+                    return EventResult::Ignored;
+                };
+                field.focus();
             }
-            KeyCode::Char(' ') => {
-                if let Some(index) = state.read().selected() {
-                    let mut selected_set = selected.write();
-                    if !selected_set.insert(index) {
-                        selected_set.remove(&index);
-                    }
-                    drop(selected_set);
-                    let changed_items = selected_items(&items, &selected.read());
-                    on_change(changed_items);
-                }
-                EventResult::Consumed
-            }
-            KeyCode::Enter => {
-                let chosen_items = selected_items(&items, &selected.read());
-                on_select(chosen_items);
-                EventResult::Consumed
+            let content = list_content_area(click.area, margin, offset);
+            let list_offset = state.read().offset();
+            let Some(index) = list_index_at(content, list_offset, &heights, click.position) else {
+                return EventResult::Ignored;
+            };
+
+            state.write().select(Some(index));
+            if click.is_double() {
+                toggle_selected(selected, field, index);
+                let changed_items = selected_items(&items, &selected.read());
+                (on_change.borrow_mut())(changed_items);
             }
-            _ => EventResult::Ignored,
+            EventResult::Consumed
         }
     });
 
+    let hovered_index = hooks.use_hover().filter(|_| active).and_then(|hover| {
+        list_index_at(
+            list_content_area(hover.area, margin, offset),
+            state.read().offset(),
+            &heights,
+            hover.position,
+        )
+    });
+
     // 主题解析:每个 slot 铺底,对应 props 的 Option<Style> 在上 patch(None → 用主题)。
     let theme = hooks.use_component_theme::<MultiSelectTheme>();
     let style = resolve_style(theme.style, props.style);
     let border_style = resolve_style(theme.border_style, props.border_style);
     let highlight_style = resolve_style(theme.highlight_style, props.highlight_style);
+    let hover_style = resolve_style(theme.hover_style, props.hover_style);
     let selected_item_style = resolve_style(theme.selected_item_style, props.selected_item_style);
     let empty_style = resolve_style(theme.empty_style, props.empty_style);
 
     let is_empty = props.items.is_empty();
     let selected_snapshot = selected.read().clone();
-    let list_items: Vec<ListItem<'static>> = props
-        .items
-        .clone()
+    let list_items: Vec<ListItem<'static>> = list_items
         .into_iter()
         .enumerate()
         .map(|(index, item)| {
-            let item: ListItem<'static> = item.into();
+            let mut item_style = Style::default();
             if selected_snapshot.contains(&index) {
-                item.style(selected_item_style)
-            } else {
+                item_style = item_style.patch(selected_item_style);
+            }
+            if hovered_index == Some(index) {
+                item_style = item_style.patch(hover_style);
+            }
+            if item_style == Style::default() {
                 item
+            } else {
+                item.style(item_style)
             }
         })
         .collect();
@@ -275,6 +359,20 @@ where
     })
 }
 
+fn toggle_selected(
+    selected: State<HashSet<usize>>,
+    field: Option<Field<HashSet<usize>>>,
+    index: usize,
+) {
+    let mut selected_set = selected.write();
+    if !selected_set.insert(index) {
+        selected_set.remove(&index);
+    }
+    if let Some(field) = field {
+        field.set(selected_set.clone());
+    }
+}
+
 fn selected_items<T>(items: &[T], selected: &HashSet<usize>) -> Vec<T>
 where
     T: Clone,
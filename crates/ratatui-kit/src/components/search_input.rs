@@ -2,6 +2,10 @@
 //
 // 组件内部维护编辑态：默认按 `s` 进入输入层，输入层打开时会截断更低层 handler，
 // `Enter` 提交、`Esc` 取消，避免背景列表/页面同时响应键盘事件。
+// 编辑态下的粘贴（`Event::Paste`）整体插入，换行按 `paste_newlines` 规整，不会触发提交。
+//
+// 经 `field` 绑定表单字段时，值与校验都来自字段：编辑态即字段是否获得焦点，`Enter` / Tab 把焦点
+// 移到下一个字段，Shift+Tab 移到上一个，`Esc` 移除焦点；`value` 与 `validate` 不再生效。
 
 use crossterm::event::{Event, KeyCode, KeyEventKind};
 use ratatui::{layout::Constraint, style::Style, text::Line};
@@ -9,10 +13,10 @@ use ratatui_kit_macros::{Props, component, element, with_layout_style};
 use tui_input::backend::crossterm::EventHandler;
 
 use crate::{
-    AnyElement, ComponentTheme, Handler, Hooks, Palette, UseEffect, UseEventHandler, UseInputLayer,
-    UseState, UseTheme,
+    AnyElement, ComponentTheme, Field, Handler, Hooks, Palette, UseEffect, UseEventHandler,
+    UseInputLayer, UseState, UseTheme,
     components::theme::resolve_style,
-    components::{Border, Input},
+    components::{Border, Input, PasteNewlines, paste_into},
     input::{EventPriority, EventResult, EventScope},
 };
 
@@ -86,6 +90,10 @@ pub struct SearchInputProps {
     pub clear_on_submit: bool,
     // `Esc` 取消时是否清空输入。
     pub clear_on_escape: bool,
+    // 粘贴文本中换行的处理方式，默认替换为空格。
+    pub paste_newlines: PasteNewlines,
+    // 绑定的表单字段，取代 `value` 与 `validate`。
+    pub field: Option<Field<String>>,
     // 以下样式覆盖:`None` 用 `SearchInputTheme`,`Some(s)` 以 `theme.patch(s)` 覆盖。
     pub border_style: Option<Style>,
     pub active_border_style: Option<Style>,
@@ -111,6 +119,8 @@ impl Default for SearchInputProps {
             validate: Handler::default(),
             clear_on_submit: false,
             clear_on_escape: false,
+            paste_newlines: PasteNewlines::default(),
+            field: None,
             border_style: None,
             active_border_style: None,
             success_border_style: None,
@@ -151,6 +161,15 @@ pub fn SearchInput(
         resolve_style(theme.success_status_style, props.success_status_style);
     let error_status_style = resolve_style(theme.error_status_style, props.error_status_style);
 
+    // 绑定字段时，编辑态跟随字段焦点（Tab 切换、提交失败聚焦出错字段等都由表单驱动）。
+    let field = props.field;
+    if let Some(field) = field {
+        let focused = props.is_editing && field.is_focused();
+        if editing.get() != focused {
+            editing.set_no_update(focused);
+        }
+    }
+
     let is_enabled = props.is_editing;
     hooks.use_effect(
         move || {
@@ -161,14 +180,17 @@ pub fn SearchInput(
         is_enabled,
     );
 
-    let value = props.value.clone();
+    let value = field.map_or_else(|| props.value.clone(), |field| field.get());
     hooks.use_effect(
-        move || {
-            if input.read().value() != value {
-                *input.write() = tui_input::Input::new(value);
+        {
+            let value = value.clone();
+            move || {
+                if input.read().value() != value {
+                    *input.write() = tui_input::Input::new(value);
+                }
             }
         },
-        props.value.clone(),
+        value,
     );
 
     let activate_key = props.activate_key;
@@ -181,6 +203,9 @@ pub fn SearchInput(
             && key.kind == KeyEventKind::Press
             && key.code == activate_key
         {
+            if let Some(field) = field {
+                field.focus();
+            }
             editing.set(true);
             return EventResult::Consumed;
         }
@@ -195,6 +220,7 @@ pub fn SearchInput(
     let mut validate = props.validate.take();
     let clear_on_submit = props.clear_on_submit;
     let clear_on_escape = props.clear_on_escape;
+    let paste_newlines = props.paste_newlines;
     let is_enabled = props.is_editing;
 
     hooks.use_event_handler(
@@ -205,13 +231,51 @@ pub fn SearchInput(
                 return EventResult::Ignored;
             }
 
-            let Event::Key(key) = event else {
-                return EventResult::Consumed;
+            // 值变更后统一通知 on_change 并重跑校验。键入与粘贴共用。
+            let mut changed = || {
+                let next_value = input.read().value().to_string();
+                on_change(next_value.clone());
+
+                if let Some(field) = field {
+                    field.set(next_value);
+                } else if validate.is_default() {
+                    valid.set(None);
+                    status.set(String::new());
+                } else {
+                    let (next_valid, message) = validate(next_value);
+                    valid.set(Some(next_valid));
+                    status.set(message);
+                }
+            };
+
+            let key = match event {
+                Event::Key(key) => key,
+                Event::Paste(text) => {
+                    if paste_into(&mut input.write(), &text, paste_newlines) {
+                        changed();
+                    }
+                    return EventResult::Consumed;
+                }
+                _ => return EventResult::Consumed,
             };
             if key.kind != KeyEventKind::Press {
                 return EventResult::Consumed;
             }
 
+            // 输入层会截断 `Form` 的 handler，绑定字段时在这里切换焦点。
+            if let Some(field) = field
+                && matches!(key.code, KeyCode::Tab | KeyCode::BackTab)
+            {
+                let form = field.form();
+                if key.code == KeyCode::Tab {
+                    form.focus_next();
+                } else {
+                    form.focus_prev();
+                }
+                editing.set(false);
+                return EventResult::Consumed;
+            }
+
             match key.code {
                 KeyCode::Esc => {
                     if clear_on_escape {
@@ -221,6 +285,12 @@ pub fn SearchInput(
                         on_change(String::new());
                         on_clear(());
                     }
+                    if let Some(field) = field {
+                        if clear_on_escape {
+                            field.set(String::new());
+                        }
+                        field.blur();
+                    }
                     editing.set(false);
                     EventResult::Consumed
                 }
@@ -239,6 +309,14 @@ pub fn SearchInput(
                             status.set(String::new());
                             on_change(String::new());
                             on_clear(());
+                            if let Some(field) = field {
+                                field.set(String::new());
+                            }
+                        }
+                        // 绑定字段时提交即移到下一个字段；表单只有这一个字段时移除焦点。
+                        if let Some(field) = field {
+                            field.form().focus_next();
+                            field.blur();
                         }
                         editing.set(false);
                     }
@@ -247,38 +325,36 @@ pub fn SearchInput(
                 }
                 _ => {
                     input.write().handle_event(&Event::Key(key));
-                    let next_value = input.read().value().to_string();
-                    on_change(next_value.clone());
-
-                    if validate.is_default() {
-                        valid.set(None);
-                        status.set(String::new());
-                    } else {
-                        let (next_valid, message) = validate(next_value);
-                        valid.set(Some(next_valid));
-                        status.set(message);
-                    }
-
+                    changed();
                     EventResult::Consumed
                 }
             }
         },
     );
 
+    // 绑定字段时校验结论来自字段：展示中的错误为失败，改动过且已通过（含异步校验）为成功。
+    let (valid, status) = match field {
+        Some(field) => match field.display_error() {
+            Some(error) => (Some(false), error),
+            None if field.is_dirty() && field.is_valid() => (Some(true), String::new()),
+            None => (None, String::new()),
+        },
+        None => (valid.get(), status.read().to_string()),
+    };
     let is_active = props.is_editing && editing.get();
-    let status_title = if is_active && !status.read().is_empty() {
-        let style = if valid.get() == Some(false) {
+    let status_title = if is_active && !status.is_empty() {
+        let style = if valid == Some(false) {
             error_status_style
         } else {
             success_status_style
         };
-        Some(Line::styled(status.read().to_string(), style))
+        Some(Line::styled(status, style))
     } else {
         None
     };
 
     let border_style = if is_active {
-        match valid.get() {
+        match valid {
             Some(true) => success_border_style,
             Some(false) => error_border_style,
             None => active_border_style,
@@ -1,21 +1,26 @@
-// Select 组件：带键盘事件处理的单选列表。
+// Select 组件：带键盘 / 鼠标事件处理的单选列表。
+//
+// 经 `field` 绑定表单字段（值为选中项下标）时，选中项与字段双向同步，`default_index` 不生效；
+// 只在字段获得焦点时响应键盘，单击列表会先让字段获得焦点。
 
-use crossterm::event::{Event, KeyCode, KeyEventKind};
+use std::{cell::RefCell, rc::Rc};
+
+use crossterm::event::{Event, KeyCode, KeyEventKind, MouseButton, MouseEventKind};
 use ratatui::{
     layout::{Alignment, Constraint},
-    style::Style,
+    style::{Modifier, Style},
     text::Line,
     widgets::{List, ListItem, ListState},
 };
 use ratatui_kit_macros::{Props, component, element, with_layout_style};
 
-use super::list_state::sync_default_selection;
+use super::list_state::{list_content_area, list_index_at, sync_default_selection};
 use crate::{
-    AnyElement, ComponentTheme, Handler, Hooks, Palette, State, UseEffect, UseEventHandler,
-    UseState, UseTheme,
+    AnyElement, ComponentTheme, Field, Handler, Hooks, Palette, State, UseClick, UseEffect,
+    UseEventHandler, UseHover, UseState, UseTheme,
     components::theme::resolve_style,
     components::{Border, Center, Text, TextParagraph},
-    input::{EventPriority, EventResult, EventScope},
+    input::{EventOptions, EventPriority, EventResult, EventScope},
 };
 
 /// Select 组件的主题 slot。高亮为「`on_accent` 前景 + `selection` 底」的配对。
@@ -28,6 +33,8 @@ pub struct SelectTheme {
     pub border_style: Style,
     /// 选中项高亮样式。
     pub highlight_style: Style,
+    /// 鼠标悬停项样式。
+    pub hover_style: Style,
     /// 空态提示样式。
     pub empty_style: Style,
 }
@@ -38,6 +45,9 @@ impl ComponentTheme for SelectTheme {
             style: Style::new().fg(palette.fg),
             border_style: Style::new().fg(palette.border),
             highlight_style: Style::new().fg(palette.on_accent).bg(palette.selection),
+            hover_style: Style::new()
+                .bg(palette.surface)
+                .add_modifier(Modifier::BOLD),
             empty_style: Style::new().fg(palette.warning),
         }
     }
@@ -68,9 +78,12 @@ where
     pub style: Option<Style>,
     pub border_style: Option<Style>,
     pub highlight_style: Option<Style>,
+    pub hover_style: Option<Style>,
     pub empty_style: Option<Style>,
     pub empty_width: Constraint,
     pub empty_height: Constraint,
+    // 绑定的表单字段，值为选中项下标。
+    pub field: Option<Field<Option<usize>>>,
 }
 
 impl<T> Default for SelectProps<T>
@@ -91,9 +104,11 @@ where
             style: None,
             border_style: None,
             highlight_style: None,
+            hover_style: None,
             empty_style: None,
             empty_width: Constraint::Percentage(50),
             empty_height: Constraint::Length(5),
+            field: None,
             margin: Default::default(),
             offset: Default::default(),
             width: Default::default(),
@@ -110,7 +125,8 @@ where
     let state = hooks.use_state(ListState::default);
     let state = props.state.unwrap_or(state);
 
-    let default_index = props.default_index;
+    let field = props.field;
+    let default_index = props.default_index.filter(|_| field.is_none());
     let item_count = props.items.len();
     let mut last_default_index = hooks.use_state(|| None::<Option<usize>>);
     hooks.use_effect(
@@ -137,61 +153,146 @@ where
         (selected_index, item_count),
     );
 
-    let active = props.active;
-    let items = props.items.clone();
-    let mut on_select = props.on_select.take();
-
-    hooks.use_event_handler(EventScope::Current, EventPriority::Normal, move |event| {
-        if !active || item_count == 0 {
-            return EventResult::Ignored;
+    // 字段 → 列表：字段值是选中项的来源，列表侧的变化由 handler 经 `commit_to_field` 写回。
+    if let Some(field) = field {
+        let value = field.get().filter(|index| *index < item_count);
+        if state.read().selected() != value {
+            state.write_no_update().select(value);
         }
+    }
 
-        let Event::Key(key) = event else {
-            return EventResult::Ignored;
-        };
-        if key.kind != KeyEventKind::Press {
-            return EventResult::Ignored;
-        }
+    let enabled = props.active;
+    let active = enabled && field.is_none_or(|field| field.is_focused());
+    let items = props.items.clone();
+    let list_items: Vec<ListItem<'static>> = props.items.iter().cloned().map(Into::into).collect();
+    let heights: Vec<u16> = list_items.iter().map(|item| item.height() as u16).collect();
+    let (margin, offset) = (props.margin, props.offset);
+    // 键盘 Enter 与鼠标双击共用同一个 on_select。
+    let on_select = Rc::new(RefCell::new(props.on_select.take()));
 
-        match key.code {
-            KeyCode::Char('j') | KeyCode::Down => {
-                state.write().select_next();
-                EventResult::Consumed
-            }
-            KeyCode::Char('k') | KeyCode::Up => {
-                state.write().select_previous();
-                EventResult::Consumed
+    // 键盘导航 + 区域内滚轮。`hit_test` 只过滤鼠标事件,键盘不受影响。
+    hooks.use_event_handler_with_options(
+        EventScope::Current,
+        EventPriority::Normal,
+        EventOptions { hit_test: true },
+        {
+            let items = items.clone();
+            let on_select = on_select.clone();
+            move |event| {
+                if !active || item_count == 0 {
+                    return EventResult::Ignored;
+                }
+
+                let result = match event {
+                    Event::Key(key) if key.kind == KeyEventKind::Press => match key.code {
+                        KeyCode::Char('j') | KeyCode::Down => {
+                            state.write().select_next();
+                            EventResult::Consumed
+                        }
+                        KeyCode::Char('k') | KeyCode::Up => {
+                            state.write().select_previous();
+                            EventResult::Consumed
+                        }
+                        KeyCode::Home => {
+                            state.write().select_first();
+                            EventResult::Consumed
+                        }
+                        KeyCode::End => {
+                            state.write().select_last();
+                            EventResult::Consumed
+                        }
+                        KeyCode::Enter => {
+                            let selected_index = state.read().selected();
+                            if let Some(index) = selected_index
+                                && let Some(item) = items.get(index)
+                            {
+                                (on_select.borrow_mut())(item.clone());
+                            }
+                            EventResult::Consumed
+                        }
+                        _ => EventResult::Ignored,
+                    },
+                    Event::Mouse(mouse) => match mouse.kind {
+                        MouseEventKind::ScrollDown => {
+                            state.write().select_next();
+                            EventResult::Consumed
+                        }
+                        MouseEventKind::ScrollUp => {
+                            state.write().select_previous();
+                            EventResult::Consumed
+                        }
+                        _ => EventResult::Ignored,
+                    },
+                    _ => EventResult::Ignored,
+                };
+                if result == EventResult::Consumed {
+                    commit_to_field(field, state, item_count);
+                }
+                result
             }
-            KeyCode::Home => {
-                state.write().select_first();
-                EventResult::Consumed
+        },
+    );
+
+    // 单击选中,双击选中并触发 on_select。
+    hooks.use_click({
+        let heights = heights.clone();
+        move |click| {
+            if !enabled || click.button != MouseButton::Left {
+                return EventResult::Ignored;
             }
-            KeyCode::End => {
-                state.write().select_last();
-                EventResult::Consumed
+            if !active {
+                let Some(field) = field else {
+                    return EventResult::Ignored;
+                };
+                field.focus();
             }
-            KeyCode::Enter => {
-                let selected_index = state.read().selected();
-                if let Some(index) = selected_index
-                    && let Some(item) = items.get(index)
-                {
-                    on_select(item.clone());
-                }
-                EventResult::Consumed
+            let content = list_content_area(click.area, margin, offset);
+            let list_offset = state.read().offset();
+            let Some(index) = list_index_at(content, list_offset, &heights, click.position) else {
+                return EventResult::Ignored;
+            };
+
+            state.write().select(Some(index));
+            commit_to_field(field, state, item_count);
+            if click.is_double()
+                && let Some(item) = items.get(index)
+            {
+                (on_select.borrow_mut())(item.clone());
             }
-            _ => EventResult::Ignored,
+            EventResult::Consumed
         }
     });
 
+    let hovered_index = hooks.use_hover().filter(|_| active).and_then(|hover| {
+        list_index_at(
+            list_content_area(hover.area, margin, offset),
+            state.read().offset(),
+            &heights,
+            hover.position,
+        )
+    });
+
     // 主题解析:每个 slot 铺底,对应 props 的 Option<Style> 在上 patch(None → 用主题)。
     let theme = hooks.use_component_theme::<SelectTheme>();
     let style = resolve_style(theme.style, props.style);
     let border_style = resolve_style(theme.border_style, props.border_style);
     let highlight_style = resolve_style(theme.highlight_style, props.highlight_style);
+    let hover_style = resolve_style(theme.hover_style, props.hover_style);
     let empty_style = resolve_style(theme.empty_style, props.empty_style);
 
     let is_empty = props.items.is_empty();
-    let mut list = List::new(props.items.clone())
+    let list_items: Vec<ListItem<'static>> = list_items
+        .into_iter()
+        .enumerate()
+        .map(|(index, item)| {
+            if hovered_index == Some(index) {
+                item.style(hover_style)
+            } else {
+                item
+            }
+        })
+        .collect();
+    let mut list = List::new(list_items)
         .style(style)
         .highlight_style(highlight_style);
 
@@ -225,3 +326,15 @@ where
         }
     })
 }
+
+// 绑定字段时把列表的选中项写回字段。`select_next` 可能越过末项（渲染时才收敛），写回前先截断。
+fn commit_to_field(
+    field: Option<Field<Option<usize>>>,
+    state: State<ListState>,
+    item_count: usize,
+) {
+    if let Some(field) = field {
+        let selected = state.read().selected();
+        field.set(selected.map(|index| index.min(item_count.saturating_sub(1))));
+    }
+}
@@ -1,10 +1,16 @@
+use std::{cell::RefCell, rc::Rc};
+
 use crate::components::theme::resolve_style;
-use crossterm::event::{Event, KeyCode, KeyEventKind};
-use ratatui::{layout::Constraint, style::Style, widgets::Block};
+use crossterm::event::{Event, KeyCode, KeyEventKind, MouseButton, MouseEventKind};
+use ratatui::{
+    layout::Constraint,
+    style::{Modifier, Style},
+    widgets::Block,
+};
 use ratatui_kit::{
     Component, ComponentDrawer, ComponentTheme, ComponentUpdater, Handler, Hooks, Palette, Props,
-    State, UseEffect, UseEventHandler, UseState,
-    input::{EventPriority, EventResult, EventScope},
+    State, UseClick, UseEffect, UseEventHandler, UseHover, UseState,
+    input::{EventOptions, EventPriority, EventResult, EventScope, Hover},
     with_layout_style,
 };
 use unicode_width::UnicodeWidthStr;
@@ -32,6 +38,8 @@ pub struct TableTheme {
     pub row_style: Style,
     /// 选中行高亮样式。
     pub highlight_style: Style,
+    /// 鼠标悬停行样式。
+    pub hover_style: Style,
     /// 选中列样式(默认留空)。
     pub column_highlight_style: Style,
     /// 选中行列交叉单元格样式(默认留空)。
@@ -49,6 +57,9 @@ impl ComponentTheme for TableTheme {
             footer_style: Style::new().fg(palette.accent),
             row_style: Style::new().fg(palette.fg),
             highlight_style: Style::new().fg(palette.on_accent).bg(palette.selection),
+            hover_style: Style::new()
+                .bg(palette.surface)
+                .add_modifier(Modifier::BOLD),
             column_highlight_style: Style::new(),
             cell_highlight_style: Style::new(),
             border_style: Style::new().fg(palette.border),
@@ -84,6 +95,8 @@ where
     pub footer_style: Option<Style>,
     pub row_style: Option<Style>,
     pub highlight_style: Option<Style>,
+    /// Patched over the data row under the mouse pointer while the table is active.
+    pub hover_style: Option<Style>,
     /// Applied to every cell of the column referenced by `TableState::selected_column`.
     pub column_highlight_style: Option<Style>,
     /// Applied to the intersection of the selected row and the selected column.
@@ -122,6 +135,7 @@ where
             footer_style: None,
             row_style: None,
             highlight_style: None,
+            hover_style: None,
             column_highlight_style: None,
             cell_highlight_style: None,
             highlight_symbol: Some("▶ "),
@@ -158,6 +172,9 @@ where
     footer_style: Style,
     row_style: Style,
     highlight_style: Style,
+    hover_style: Style,
+    // 本帧指针悬停信息(仅 `active` 时记录),draw 时据此给悬停行打底。
+    hover: Option<Hover>,
     column_highlight_style: Style,
     cell_highlight_style: Style,
     highlight_symbol: Option<&'static str>,
@@ -193,6 +210,8 @@ where
             footer_style: Style::default(),
             row_style: Style::default(),
             highlight_style: Style::default(),
+            hover_style: Style::default(),
+            hover: None,
             column_highlight_style: Style::default(),
             cell_highlight_style: Style::default(),
             highlight_symbol: props.highlight_symbol,
@@ -263,6 +282,7 @@ where
             cells: header_cells,
             style: self.header_style,
             selected: false,
+            index: None,
         });
 
         if self.header_separator {
@@ -286,6 +306,7 @@ where
                     self.row_style
                 },
                 selected: is_selected,
+                index: Some(index),
             });
             if self.row_separator && index + 1 < self.rows.len() {
                 rendered_rows.push(RenderedRow::separator(self.horizontal_line_style));
@@ -303,6 +324,7 @@ where
                 cells: footer_cells,
                 style: self.footer_style,
                 selected: false,
+                index: None,
             });
         }
 
@@ -380,56 +402,94 @@ where
         let active = props.active;
         let column_navigation = props.column_navigation;
         let rows = props.rows.clone();
-        let mut on_select = props.on_select.take();
-        hooks.use_event_handler(EventScope::Current, EventPriority::Normal, move |event| {
-            if !active || row_count == 0 {
+        // 键盘 Enter 与鼠标双击共用同一个 on_select。
+        let on_select = Rc::new(RefCell::new(props.on_select.take()));
+        // 键盘导航 + 区域内滚轮。`hit_test` 只过滤鼠标事件,键盘不受影响。
+        hooks.use_event_handler_with_options(
+            EventScope::Current,
+            EventPriority::Normal,
+            EventOptions { hit_test: true },
+            {
+                let rows = rows.clone();
+                let on_select = on_select.clone();
+                move |event| {
+                    if !active || row_count == 0 {
+                        return EventResult::Ignored;
+                    }
+
+                    match event {
+                        Event::Key(key) if key.kind == KeyEventKind::Press => match key.code {
+                            KeyCode::Char('j') | KeyCode::Down => {
+                                state.write().next(row_count);
+                                EventResult::Consumed
+                            }
+                            KeyCode::Char('k') | KeyCode::Up => {
+                                state.write().previous(row_count);
+                                EventResult::Consumed
+                            }
+                            KeyCode::Char('h') | KeyCode::Left if column_navigation => {
+                                state.write().previous_column(column_count);
+                                EventResult::Consumed
+                            }
+                            KeyCode::Char('l') | KeyCode::Right if column_navigation => {
+                                state.write().next_column(column_count);
+                                EventResult::Consumed
+                            }
+                            KeyCode::Home => {
+                                state.write().select_first(row_count);
+                                EventResult::Consumed
+                            }
+                            KeyCode::End => {
+                                state.write().select_last(row_count);
+                                EventResult::Consumed
+                            }
+                            KeyCode::Enter => {
+                                if let Some(index) = state.read().selected()
+                                    && let Some(row) = rows.get(index)
+                                {
+                                    (on_select.borrow_mut())(row.clone());
+                                }
+                                EventResult::Consumed
+                            }
+                            _ => EventResult::Ignored,
+                        },
+                        Event::Mouse(mouse) => match mouse.kind {
+                            MouseEventKind::ScrollDown => {
+                                state.write().next(row_count);
+                                EventResult::Consumed
+                            }
+                            MouseEventKind::ScrollUp => {
+                                state.write().previous(row_count);
+                                EventResult::Consumed
+                            }
+                            _ => EventResult::Ignored,
+                        },
+                        _ => EventResult::Ignored,
+                    }
+                }
+            },
+        );
+
+        // 单击选中行,双击选中并触发 on_select。命中沿用 `TableState` 记录的上一帧行区域。
+        hooks.use_click(move |click| {
+            if !active || row_count == 0 || click.button != MouseButton::Left {
                 return EventResult::Ignored;
             }
-
-            let Event::Key(key) = event else {
+            let Some(index) = state.read().row_at(click.position) else {
                 return EventResult::Ignored;
             };
-            if key.kind != KeyEventKind::Press {
-                return EventResult::Ignored;
-            }
 
-            match key.code {
-                KeyCode::Char('j') | KeyCode::Down => {
-                    state.write().next(row_count);
-                    EventResult::Consumed
-                }
-                KeyCode::Char('k') | KeyCode::Up => {
-                    state.write().previous(row_count);
-                    EventResult::Consumed
-                }
-                KeyCode::Char('h') | KeyCode::Left if column_navigation => {
-                    state.write().previous_column(column_count);
-                    EventResult::Consumed
-                }
-                KeyCode::Char('l') | KeyCode::Right if column_navigation => {
-                    state.write().next_column(column_count);
-                    EventResult::Consumed
-                }
-                KeyCode::Home => {
-                    state.write().select_first(row_count);
-                    EventResult::Consumed
-                }
-                KeyCode::End => {
-                    state.write().select_last(row_count);
-                    EventResult::Consumed
-                }
-                KeyCode::Enter => {
-                    if let Some(index) = state.read().selected()
-                        && let Some(row) = rows.get(index)
-                    {
-                        on_select(row.clone());
-                    }
-                    EventResult::Consumed
-                }
-                _ => EventResult::Ignored,
+            state.write().select(Some(index));
+            if click.is_double()
+                && let Some(row) = rows.get(index)
+            {
+                (on_select.borrow_mut())(row.clone());
             }
+            EventResult::Consumed
         });
 
+        let hover = hooks.use_hover().filter(|_| active);
+
         updater.set_layout_style(layout_style);
         *self = Self::from_props(props, state);
 
@@ -440,6 +500,8 @@ where
         self.footer_style = resolve_style(theme.footer_style, props.footer_style);
         self.row_style = resolve_style(theme.row_style, props.row_style);
         self.highlight_style = resolve_style(theme.highlight_style, props.highlight_style);
+        self.hover_style = resolve_style(theme.hover_style, props.hover_style);
+        self.hover = hover;
         self.column_highlight_style =
             resolve_style(theme.column_highlight_style, props.column_highlight_style);
         self.cell_highlight_style =
@@ -450,7 +512,7 @@ where
     }
 
     fn draw(&mut self, drawer: &mut ComponentDrawer<'_, '_>) {
-        let Some(state) = &mut self.state else {
+        let Some(state) = self.state else {
             return;
         };
 
@@ -494,7 +556,7 @@ where
             &widths,
         );
 
-        render_table(RenderTable {
+        let row_areas = render_table(RenderTable {
             area,
             buf: drawer.buffer_mut(),
             rows: &rendered_rows,
@@ -506,6 +568,13 @@ where
             highlight_symbol: self.highlight_symbol,
             gutter,
         });
+
+        if let Some(hover) = self.hover
+            && let Some(row_area) = row_areas.iter().find(|row| row.contains(hover.position))
+        {
+            drawer.buffer_mut().set_style(*row_area, self.hover_style);
+        }
+        state.write_no_update().set_row_areas(row_areas);
     }
 }
 
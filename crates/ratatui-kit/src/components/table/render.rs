@@ -16,6 +16,8 @@ pub(super) struct RenderedRow {
     pub(super) cells: Vec<RenderedCell>,
     pub(super) style: Style,
     pub(super) selected: bool,
+    /// Index into the table's data rows. `None` for header, footer and separators.
+    pub(super) index: Option<usize>,
 }
 
 impl RenderedRow {
@@ -24,6 +26,7 @@ impl RenderedRow {
             cells: Vec::new(),
             style,
             selected: false,
+            index: None,
         }
     }
 
@@ -70,8 +73,12 @@ pub(super) struct RenderTable<'a> {
     pub(super) gutter: u16,
 }
 
-pub(super) fn render_table(mut table: RenderTable<'_>) {
+/// Draws the table and returns the on-screen area of every data row that was
+/// (at least partly) drawn, indexed by data row. Rows clipped off the bottom are
+/// omitted, so the result is always a prefix of the data rows.
+pub(super) fn render_table(mut table: RenderTable<'_>) -> Vec<Rect> {
     let mut y = table.area.y;
+    let mut row_areas = Vec::new();
 
     if matches!(
         table.border_mode,
@@ -96,6 +103,10 @@ pub(super) fn render_table(mut table: RenderTable<'_>) {
         }
 
         let height = row.render_height(table.border_mode);
+        if row.index.is_some() {
+            let visible = height.min(table.area.bottom() - y);
+            row_areas.push(Rect::new(table.area.x, y, table.area.width, visible));
+        }
 
         for line_index in 0..height {
             if y >= table.area.bottom() {
@@ -115,6 +126,8 @@ pub(super) fn render_table(mut table: RenderTable<'_>) {
         let style = table.border_style;
         render_border_line(&mut table, y, '└', '┴', '┘', style);
     }
+
+    row_areas
 }
 
 fn render_border_line(
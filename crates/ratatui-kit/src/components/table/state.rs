@@ -1,7 +1,11 @@
+use ratatui::layout::{Position, Rect};
+
 #[derive(Debug, Clone, Default)]
 pub struct TableState {
     selected: Option<usize>,
     selected_column: Option<usize>,
+    // 每个已绘制数据行的屏幕区域(按数据行下标),由 Table 每帧记录,供鼠标命中。
+    row_areas: Vec<Rect>,
 }
 
 impl TableState {
@@ -89,6 +93,23 @@ impl TableState {
         });
     }
 
+    /// The on-screen area of the data row at `index`, as drawn on the last render.
+    /// `None` before the first render or when the row was clipped off the table.
+    pub fn row_area(&self, index: usize) -> Option<Rect> {
+        self.row_areas.get(index).copied()
+    }
+
+    /// The data row drawn at `position` on the last render, if any.
+    pub fn row_at(&self, position: Position) -> Option<usize> {
+        self.row_areas
+            .iter()
+            .position(|area| area.contains(position))
+    }
+
+    pub(super) fn set_row_areas(&mut self, row_areas: Vec<Rect>) {
+        self.row_areas = row_areas;
+    }
+
     pub fn clamp(&mut self, len: usize) {
         self.selected = self.selected.and_then(|index| {
             if len == 0 {
@@ -192,4 +213,16 @@ mod tests {
         sync_default_selection(&mut state, &mut last_default, Some(2), 3);
         assert_eq!(state.selected(), Some(2));
     }
+
+    #[test]
+    fn row_at_maps_positions_to_recorded_row_areas() {
+        let mut state = TableState::default();
+        assert_eq!(state.row_at(Position::new(1, 1)), None);
+
+        state.set_row_areas(vec![Rect::new(0, 2, 10, 1), Rect::new(0, 3, 10, 2)]);
+        assert_eq!(state.row_at(Position::new(4, 2)), Some(0));
+        assert_eq!(state.row_at(Position::new(4, 4)), Some(1));
+        assert_eq!(state.row_at(Position::new(4, 5)), None);
+        assert_eq!(state.row_area(1), Some(Rect::new(0, 3, 10, 2)));
+    }
 }
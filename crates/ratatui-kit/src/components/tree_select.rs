@@ -1,16 +1,17 @@
 use crate::components::theme::resolve_style;
-use crossterm::event::{Event, KeyCode, KeyEventKind};
+use crossterm::event::{Event, KeyCode, KeyEventKind, MouseButton, MouseEventKind};
 use ratatui::{
-    style::Style,
+    layout::Rect,
+    style::{Modifier, Style},
     widgets::{Block, Scrollbar},
 };
 use ratatui_kit::{
-    Component, ComponentTheme, Handler, Palette, Props, State, UseEffect, UseEventHandler,
-    UseState,
-    input::{EventPriority, EventResult, EventScope},
+    Component, ComponentTheme, Handler, Palette, Props, State, UseClick, UseEffect,
+    UseEventHandler, UseHover, UseState,
+    input::{EventOptions, EventPriority, EventResult, EventScope, Hover},
     with_layout_style,
 };
-use std::hash::Hash;
+use std::{cell::RefCell, hash::Hash, rc::Rc};
 use tui_tree_widget::{TreeItem, TreeState};
 
 /// TreeSelect 组件的主题 slot。默认提供可见选中态(`on_accent` 前景 + `selection` 底)。
@@ -21,6 +22,8 @@ pub struct TreeSelectTheme {
     pub style: Style,
     /// 选中项高亮样式。
     pub highlight_style: Style,
+    /// 鼠标悬停行样式。
+    pub hover_style: Style,
 }
 
 impl ComponentTheme for TreeSelectTheme {
@@ -28,6 +31,9 @@ impl ComponentTheme for TreeSelectTheme {
         Self {
             style: Style::new().fg(palette.fg),
             highlight_style: Style::new().fg(palette.on_accent).bg(palette.selection),
+            hover_style: Style::new()
+                .bg(palette.surface)
+                .add_modifier(Modifier::BOLD),
         }
     }
 }
@@ -64,6 +70,8 @@ where
 
     // 选中项样式覆盖。`None` 用 `TreeSelectTheme`(默认可见),`Some(s)` 以 `theme.patch(s)` 覆盖。
     pub highlight_style: Option<Style>,
+    // 鼠标悬停行样式覆盖。`None` 用 `TreeSelectTheme`,`Some(s)` 以 `theme.patch(s)` 覆盖。
+    pub hover_style: Option<Style>,
     // 显示在选中项前面的符号（会将所有项右移）
     pub highlight_symbol: &'static str,
 
@@ -91,6 +99,7 @@ where
             scrollbar: None,
             style: None,
             highlight_style: None,
+            hover_style: None,
             highlight_symbol: "",
             node_closed_symbol: "\u{25b6} ", // 向右箭头
             node_open_symbol: "\u{25bc} ",   // 向下箭头
@@ -151,6 +160,9 @@ where
     scrollbar: Option<Scrollbar<'static>>,
     style: Style,
     highlight_style: Style,
+    hover_style: Style,
+    // 本帧指针悬停信息(仅 `active` 时记录),draw 时据此给悬停行打底。
+    hover: Option<Hover>,
     highlight_symbol: &'static str,
     node_closed_symbol: &'static str,
     node_open_symbol: &'static str,
@@ -171,6 +183,8 @@ where
             // 样式待 update 经主题解析后写入。
             style: Style::default(),
             highlight_style: Style::default(),
+            hover_style: Style::default(),
+            hover: None,
             highlight_symbol: props.highlight_symbol,
             node_closed_symbol: props.node_closed_symbol,
             node_open_symbol: props.node_open_symbol,
@@ -223,51 +237,92 @@ where
 
         let active = props.active;
         let has_items = !props.items.is_empty();
-        let mut on_select = props.on_select.take();
-        hooks.use_event_handler(EventScope::Current, EventPriority::Normal, move |event| {
-            if !active || !has_items {
-                return EventResult::Ignored;
-            }
-
-            let Event::Key(key) = event else {
-                return EventResult::Ignored;
-            };
-            if key.kind != KeyEventKind::Press {
-                return EventResult::Ignored;
-            }
+        // 键盘 Enter 与鼠标双击共用同一个 on_select。
+        let on_select = Rc::new(RefCell::new(props.on_select.take()));
+        // 键盘导航 + 区域内滚轮。`hit_test` 只过滤鼠标事件,键盘不受影响。
+        hooks.use_event_handler_with_options(
+            EventScope::Current,
+            EventPriority::Normal,
+            EventOptions { hit_test: true },
+            {
+                let on_select = on_select.clone();
+                move |event| {
+                    if !active || !has_items {
+                        return EventResult::Ignored;
+                    }
 
-            match key.code {
-                KeyCode::Char('h') | KeyCode::Left => {
-                    state.write().key_left();
-                    EventResult::Consumed
-                }
-                KeyCode::Char('j') | KeyCode::Down => {
-                    state.write().key_down();
-                    EventResult::Consumed
-                }
-                KeyCode::Char('k') | KeyCode::Up => {
-                    state.write().key_up();
-                    EventResult::Consumed
-                }
-                KeyCode::Char('l') | KeyCode::Right => {
-                    state.write().key_right();
-                    EventResult::Consumed
-                }
-                KeyCode::Char(' ') => {
-                    state.write().toggle_selected();
-                    EventResult::Consumed
-                }
-                KeyCode::Enter => {
-                    let selected = state.read().selected().last().cloned();
-                    if let Some(selected) = selected {
-                        on_select(selected);
+                    match event {
+                        Event::Key(key) if key.kind == KeyEventKind::Press => match key.code {
+                            KeyCode::Char('h') | KeyCode::Left => {
+                                state.write().key_left();
+                                EventResult::Consumed
+                            }
+                            KeyCode::Char('j') | KeyCode::Down => {
+                                state.write().key_down();
+                                EventResult::Consumed
+                            }
+                            KeyCode::Char('k') | KeyCode::Up => {
+                                state.write().key_up();
+                                EventResult::Consumed
+                            }
+                            KeyCode::Char('l') | KeyCode::Right => {
+                                state.write().key_right();
+                                EventResult::Consumed
+                            }
+                            KeyCode::Char(' ') => {
+                                state.write().toggle_selected();
+                                EventResult::Consumed
+                            }
+                            KeyCode::Enter => {
+                                let selected = state.read().selected().last().cloned();
+                                if let Some(selected) = selected {
+                                    (on_select.borrow_mut())(selected);
+                                }
+                                EventResult::Consumed
+                            }
+                            _ => EventResult::Ignored,
+                        },
+                        Event::Mouse(mouse) => match mouse.kind {
+                            MouseEventKind::ScrollDown => {
+                                state.write().scroll_down(1);
+                                EventResult::Consumed
+                            }
+                            MouseEventKind::ScrollUp => {
+                                state.write().scroll_up(1);
+                                EventResult::Consumed
+                            }
+                            _ => EventResult::Ignored,
+                        },
+                        _ => EventResult::Ignored,
                     }
-                    EventResult::Consumed
                 }
-                _ => EventResult::Ignored,
+            },
+        );
+
+        // 单击选中(再次单击已选中项则展开/折叠),双击触发 on_select。
+        hooks.use_click(move |click| {
+            if !active || !has_items || click.button != MouseButton::Left {
+                return EventResult::Ignored;
+            }
+            if click.is_double() {
+                let clicked = state
+                    .read()
+                    .rendered_at(click.position)
+                    .and_then(|identifier| identifier.last().cloned());
+                let Some(clicked) = clicked else {
+                    return EventResult::Ignored;
+                };
+                (on_select.borrow_mut())(clicked);
+                EventResult::Consumed
+            } else if state.write().click_at(click.position) {
+                EventResult::Consumed
+            } else {
+                EventResult::Ignored
             }
         });
 
+        let hover = hooks.use_hover().filter(|_| active);
+
         updater.set_layout_style(layout_style);
 
         // 主题解析:theme slot 铺底,props 的 Option<Style> 在上 patch(None → 用主题)。
@@ -275,11 +330,14 @@ where
         let theme = updater.use_component_theme::<TreeSelectTheme>();
         let style = resolve_style(theme.style, props.style);
         let highlight_style = resolve_style(theme.highlight_style, props.highlight_style);
+        let hover_style = resolve_style(theme.hover_style, props.hover_style);
 
         *self = Self {
             state: Some(state),
             style,
             highlight_style,
+            hover_style,
+            hover,
             ..Self::from_props(props)
         };
     }
@@ -313,6 +371,18 @@ where
         if let Some(state) = &mut self.state {
             // 渲染有状态的树形组件
             drawer.render_stateful_widget(tree, drawer.area, &mut state.write_no_update());
+
+            // 悬停行打底:树每项恰占一行,命中即补丁整行(限定在内容区内)。
+            if let Some(hover) = self.hover
+                && state.read().rendered_at(hover.position).is_some()
+            {
+                let inner = self
+                    .block
+                    .as_ref()
+                    .map_or(drawer.area, |block| block.inner(drawer.area));
+                let row = Rect::new(inner.x, hover.position.y, inner.width, 1).intersection(inner);
+                drawer.buffer_mut().set_style(row, self.hover_style);
+            }
         } else {
             // 渲染无状态的树形组件
             drawer.render_widget(tree, drawer.area);
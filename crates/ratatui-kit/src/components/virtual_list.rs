@@ -1,36 +1,45 @@
 // VirtualList 组件：基于 `tui-widget-list` 的虚拟列表。
 
+use std::{cell::RefCell, rc::Rc};
+
 use crate::components::theme::resolve_style;
-use crossterm::event::{Event, KeyCode, KeyEventKind};
+use crossterm::event::{Event, KeyCode, KeyEventKind, MouseButton, MouseEventKind};
 use ratatui::{
-    style::Style,
+    layout::{Position, Rect},
+    style::{Modifier, Style},
     widgets::{Block, Widget},
 };
 use ratatui_kit::{
-    Component, ComponentTheme, Handler, Palette, Props, State, UseEffect, UseEventHandler,
-    UseState,
-    input::{EventPriority, EventResult, EventScope},
+    Component, ComponentTheme, Handler, Palette, Props, State, UseClick, UseEffect,
+    UseEventHandler, UseHover, UseState,
+    input::{EventOptions, EventPriority, EventResult, EventScope, Hover},
     with_layout_style,
 };
 use tui_widget_list::{
     ListBuildContext, ListBuilder, ListState, ListView as TuiListView, ScrollAxis, ScrollDirection,
+    hit_test::Hit,
 };
 
 /// VirtualList 组件的主题 slot。
 ///
 /// 注:虚拟列表的**选中态由 `render_item` 闭包按 `ListBuildContext::is_selected` 自绘**,
-/// 不由 widget 统一着色,故本 slot 只提供列表基础样式。
+/// 不由 widget 统一着色,故本 slot 只提供列表基础样式与鼠标悬停打底。
 #[non_exhaustive]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct VirtualListTheme {
     /// 列表基础样式。
     pub style: Style,
+    /// 鼠标悬停项样式,渲染后补丁到该项占据的区域。
+    pub hover_style: Style,
 }
 
 impl ComponentTheme for VirtualListTheme {
     fn from_palette(palette: &Palette) -> Self {
         Self {
             style: Style::new().fg(palette.fg),
+            hover_style: Style::new()
+                .bg(palette.surface)
+                .add_modifier(Modifier::BOLD),
         }
     }
 }
@@ -93,6 +102,8 @@ where
     pub scroll_direction: ScrollDirection,
     // 基础样式覆盖。`None` 用 `VirtualListTheme`,`Some(s)` 以 `theme.patch(s)` 覆盖。
     pub style: Option<Style>,
+    // 悬停样式覆盖。`None` 用 `VirtualListTheme`,`Some(s)` 以 `theme.patch(s)` 覆盖。
+    pub hover_style: Option<Style>,
     pub block: Option<Block<'static>>,
     pub scroll_padding: u16,
     pub infinite_scrolling: bool,
@@ -113,6 +124,7 @@ where
             scroll_axis: ScrollAxis::Vertical,
             scroll_direction: ScrollDirection::Forward,
             style: None,
+            hover_style: None,
             block: None,
             scroll_padding: 0,
             infinite_scrolling: true,
@@ -154,6 +166,9 @@ where
     scroll_axis: ScrollAxis,
     scroll_direction: ScrollDirection,
     style: Style,
+    hover_style: Style,
+    // 本帧指针悬停信息(仅 `active` 时记录),draw 时据此给悬停项打底。
+    hover: Option<Hover>,
     block: Option<Block<'static>>,
     scroll_padding: u16,
     infinite_scrolling: bool,
@@ -172,6 +187,8 @@ where
             scroll_direction: props.scroll_direction,
             // 样式待 update 经主题解析后写入。
             style: Style::default(),
+            hover_style: Style::default(),
+            hover: None,
             block: props.block.clone(),
             scroll_padding: props.scroll_padding,
             infinite_scrolling: props.infinite_scrolling,
@@ -197,6 +214,8 @@ where
             scroll_direction: props.scroll_direction,
             // 样式待 update 经主题解析后写入。
             style: Style::default(),
+            hover_style: Style::default(),
+            hover: None,
             block: props.block.clone(),
             scroll_padding: props.scroll_padding,
             infinite_scrolling: props.infinite_scrolling,
@@ -242,57 +261,96 @@ where
         );
 
         let active = props.active;
-        let mut on_select = props.on_select.take();
-        hooks.use_event_handler(EventScope::Current, EventPriority::Normal, move |event| {
-            if !active || item_count == 0 {
+        // 键盘 Enter 与鼠标双击共用同一个 on_select。
+        let on_select = Rc::new(RefCell::new(props.on_select.take()));
+        // 键盘导航 + 区域内滚轮。`hit_test` 只过滤鼠标事件,键盘不受影响。
+        hooks.use_event_handler_with_options(
+            EventScope::Current,
+            EventPriority::Normal,
+            EventOptions { hit_test: true },
+            {
+                let on_select = on_select.clone();
+                move |event| {
+                    if !active || item_count == 0 {
+                        return EventResult::Ignored;
+                    }
+
+                    match event {
+                        Event::Key(key) if key.kind == KeyEventKind::Press => match key.code {
+                            KeyCode::Char('j') | KeyCode::Down => {
+                                state.write().next();
+                                EventResult::Consumed
+                            }
+                            KeyCode::Char('k') | KeyCode::Up => {
+                                state.write().previous();
+                                EventResult::Consumed
+                            }
+                            KeyCode::Home => {
+                                state.write().select(Some(0));
+                                EventResult::Consumed
+                            }
+                            KeyCode::End => {
+                                state.write().select(item_count.checked_sub(1));
+                                EventResult::Consumed
+                            }
+                            KeyCode::Enter => {
+                                let selected = state.read().selected;
+                                if let Some(index) = selected {
+                                    (on_select.borrow_mut())(index);
+                                }
+                                EventResult::Consumed
+                            }
+                            _ => EventResult::Ignored,
+                        },
+                        Event::Mouse(mouse) => match mouse.kind {
+                            MouseEventKind::ScrollDown => {
+                                state.write().next();
+                                EventResult::Consumed
+                            }
+                            MouseEventKind::ScrollUp => {
+                                state.write().previous();
+                                EventResult::Consumed
+                            }
+                            _ => EventResult::Ignored,
+                        },
+                        _ => EventResult::Ignored,
+                    }
+                }
+            },
+        );
+
+        // 单击选中,双击选中并触发 on_select。命中沿用 `tui-widget-list` 记录的上一帧布局。
+        hooks.use_click(move |click| {
+            if !active || item_count == 0 || click.button != MouseButton::Left {
                 return EventResult::Ignored;
             }
-
-            let Event::Key(key) = event else {
+            let hit = state.read().hit_test(click.position.x, click.position.y);
+            let Some(Hit::Item(index)) = hit else {
                 return EventResult::Ignored;
             };
-            if key.kind != KeyEventKind::Press {
-                return EventResult::Ignored;
-            }
 
-            match key.code {
-                KeyCode::Char('j') | KeyCode::Down => {
-                    state.write().next();
-                    EventResult::Consumed
-                }
-                KeyCode::Char('k') | KeyCode::Up => {
-                    state.write().previous();
-                    EventResult::Consumed
-                }
-                KeyCode::Home => {
-                    state.write().select(Some(0));
-                    EventResult::Consumed
-                }
-                KeyCode::End => {
-                    state.write().select(item_count.checked_sub(1));
-                    EventResult::Consumed
-                }
-                KeyCode::Enter => {
-                    let selected = state.read().selected;
-                    if let Some(index) = selected {
-                        on_select(index);
-                    }
-                    EventResult::Consumed
-                }
-                _ => EventResult::Ignored,
+            state.write().select(Some(index));
+            if click.is_double() {
+                (on_select.borrow_mut())(index);
             }
+            EventResult::Consumed
         });
 
+        let hover = hooks.use_hover().filter(|_| active);
+
         updater.set_layout_style(layout_style);
 
         // 主题解析:theme slot 铺底,props 的 Option<Style> 在上 patch(None → 用主题)。
         // use_component_theme 返回 owned 值、读后即弃守卫,不与 &mut updater 冲突。
         let theme = updater.use_component_theme::<VirtualListTheme>();
         let style = resolve_style(theme.style, props.style);
+        let hover_style = resolve_style(theme.hover_style, props.hover_style);
 
         *self = Self {
             state: Some(state),
             style,
+            hover_style,
+            hover,
             ..Self::from_props(props)
         };
     }
@@ -321,10 +379,32 @@ where
 
         if let Some(state) = &mut self.state {
             drawer.render_stateful_widget(list, drawer.area, &mut state.write_no_update());
+
+            if let Some(hover) = self.hover
+                && let Some(rect) = hovered_item_rect(&state.read(), drawer.area, hover.position)
+            {
+                drawer.buffer_mut().set_style(rect, self.hover_style);
+            }
         }
     }
 }
 
+// 悬停项在上一帧渲染中占据的矩形。项在主轴上连续、在交叉轴上铺满,
+// 故沿两条过悬停点的直线各扫一次命中结果即可还原。
+fn hovered_item_rect(state: &ListState, area: Rect, position: Position) -> Option<Rect> {
+    let Some(Hit::Item(index)) = state.hit_test(position.x, position.y) else {
+        return None;
+    };
+    let hits = |x: u16, y: u16| state.hit_test(x, y) == Some(Hit::Item(index));
+
+    let columns = (area.left()..area.right()).filter(|&x| hits(x, position.y));
+    let (left, right) = columns.fold((u16::MAX, 0), |(lo, hi), x| (lo.min(x), hi.max(x)));
+    let rows = (area.top()..area.bottom()).filter(|&y| hits(position.x, y));
+    let (top, bottom) = rows.fold((u16::MAX, 0), |(lo, hi), y| (lo.min(y), hi.max(y)));
+
+    Some(Rect::new(left, top, right - left + 1, bottom - top + 1))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -4,12 +4,16 @@
 // - [`Context`]：通用上下文枚举，支持所有权、不可变/可变引用三种模式。
 // - [`ContextStack`]：上下文栈，支持嵌套作用域和动态查找。
 // - [`SystemContext`]：系统级上下文，控制全局退出等。
+// - [`ReactiveContext`]：响应式上下文，提供者持有可写的值，消费者按选取结果订阅变更。
 
 use std::{
     any::{Any, TypeId},
     cell::{Ref, RefCell, RefMut},
+    marker::PhantomData,
 };
 
+use crate::{ReactiveHandle, WakerMap};
+
 // 通用上下文类型，支持所有权、不可变引用、可变引用三种模式。
 pub enum Context<'a> {
     Ref(&'a dyn Any),
@@ -120,6 +124,14 @@ impl<'a> ContextStack<'a> {
     }
 
     pub(crate) fn get_context<T: Any>(&'_ self) -> ContextLookup<Ref<'_, T>> {
+        self.find_context(|_: &T| true)
+    }
+
+    // 同 `get_context`，但跳过 `matches` 返回 `false` 的同类型上下文，取最近的匹配项。
+    pub(crate) fn find_context<T: Any>(
+        &'_ self,
+        matches: impl Fn(&T) -> bool,
+    ) -> ContextLookup<Ref<'_, T>> {
         let expected_type_id = TypeId::of::<T>();
         for entry in self.stack.iter().rev() {
             if entry.type_id != expected_type_id {
@@ -130,7 +142,11 @@ impl<'a> ContextStack<'a> {
                 return ContextLookup::AlreadyBorrowed;
             };
 
-            if let Ok(res) = Ref::filter_map(context, |context| context.downcast_ref::<T>()) {
+            if let Ok(res) = Ref::filter_map(context, |context| {
+                context
+                    .downcast_ref::<T>()
+                    .filter(|context| matches(context))
+            }) {
                 return ContextLookup::Found(res);
             }
         }
@@ -156,23 +172,99 @@ impl<'a> ContextStack<'a> {
     }
 }
 
+// 响应式上下文持有的值。与 `AtomState` 相同，写入只唤醒登记了自己的消费者。
+pub type ContextState<T> = ReactiveHandle<T, WakerMap>;
+
+// 响应式上下文的类型标记，由 [`create_context`] 创建，可声明为 `static`。
+//
+// 提供者经 `use_context_state` 持有值，并以 `ContextProvider(value: CTX.provide(state))` 注入；
+// 后代经 `use_reactive_context` / `use_context_selector` 订阅。值在提供者组件之外写入
+// （事件 handler、后台任务）也会唤醒消费者，而不必等父组件重渲。查找按标记本身进行
+// （以 `static` 的地址为身份），同一 `T` 的两个标记互不干扰，各取最近的祖先提供者。
+// 标记须声明为 `static`：`const` 每处使用都是新的副本，身份对不上。
+pub struct ReactiveContext<T> {
+    // 非零大小，保证不同的 `static` 地址不同。
+    _identity: u8,
+    _marker: PhantomData<fn() -> T>,
+}
+
+// 创建响应式上下文标记：`static SETTINGS: ReactiveContext<Settings> = create_context();`
+pub const fn create_context<T>() -> ReactiveContext<T> {
+    ReactiveContext {
+        _identity: 0,
+        _marker: PhantomData,
+    }
+}
+
+impl<T> ReactiveContext<T>
+where
+    T: Send + Sync + 'static,
+{
+    // 把提供者持有的值包装成注入给 `ContextProvider` 的上下文。
+    pub fn provide(&self, state: ContextState<T>) -> Context<'static> {
+        Context::owned(ProvidedContext {
+            id: self.id(),
+            state,
+        })
+    }
+
+    pub(crate) fn id(&self) -> usize {
+        self as *const Self as usize
+    }
+}
+
+// 上下文栈中响应式上下文的实际类型，与直接注入的 `T` 互不干扰。`id` 为提供它的标记。
+pub(crate) struct ProvidedContext<T>
+where
+    T: Send + Sync + 'static,
+{
+    pub(crate) id: usize,
+    pub(crate) state: ContextState<T>,
+}
+
 pub struct SystemContext {
     should_exit: bool,
     auto_quit_on_ctrl_c: bool,
     // 中央输入事件运行时。组件经 `get_context_mut::<SystemContext>().input` 登记层/handler,
     // 渲染循环经 `system_context.input.dispatch(event)` 分发。运行时单线程,无需 Send + Sync。
     pub(crate) input: crate::input::InputRuntime,
+    // 计时 hook（`use_interval` 等）共用的时间轮。
+    pub(crate) timers: crate::time::Timers,
+    // 当前帧开始的时刻，每帧 update 前由渲染循环刷新。
+    frame_time: std::time::Instant,
+    // 组件树默认的查询缓存，未经 context 注入独立 `QueryClient` 的 `use_query` 共用它。
+    #[cfg(feature = "query")]
+    pub(crate) query: crate::query::QueryClient,
 }
 
 impl SystemContext {
     pub(crate) fn new() -> Self {
+        let clock = crate::time::Clock::current();
         Self {
             should_exit: false,
             auto_quit_on_ctrl_c: true,
             input: crate::input::InputRuntime::default(),
+            frame_time: clock.now(),
+            timers: crate::time::Timers::new(clock.clone()),
+            #[cfg(feature = "query")]
+            query: crate::query::QueryClient::with_clock(clock.clone()),
         }
     }
 
+    // 组件树的时钟。需要「当前时间」的组件与 hook 应读它而不是 `Instant::now()`，测试才能接管时间。
+    pub fn clock(&self) -> &crate::time::Clock {
+        self.timers.clock()
+    }
+
+    // 当前帧开始的时刻（取自 `clock()`）。同一帧内的动画都在这一时刻采样，彼此保持同步。
+    pub fn frame_time(&self) -> std::time::Instant {
+        self.frame_time
+    }
+
+    pub(crate) fn begin_frame(&mut self) {
+        self.frame_time = self.clock().now();
+    }
+
     pub(crate) fn should_exit(&self) -> bool {
         self.should_exit
     }
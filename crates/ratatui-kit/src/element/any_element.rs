@@ -10,6 +10,14 @@ pub struct AnyElement<'a> {
     helper: Box<dyn ComponentHelperExt>,
 }
 
+impl<'a> AnyElement<'a> {
+    // 取出 props 交给组件树作根：测试 harness 挂载后不必再保留元素本身。
+    #[cfg(test)]
+    pub(crate) fn into_props(self) -> AnyProps<'a> {
+        self.props
+    }
+}
+
 impl<'a, T> From<Element<'a, T>> for AnyElement<'a>
 where
     T: Component,
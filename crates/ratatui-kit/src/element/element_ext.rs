@@ -5,7 +5,7 @@ use crate::{
     component::ComponentHelperExt,
     props::AnyProps,
     render::tree::render_loop,
-    terminal::{CrossTerminal, Terminal},
+    terminal::{CrossTerminal, Recorder, Recording, Terminal},
 };
 use std::{future::Future, io};
 
@@ -60,7 +60,7 @@ pub trait ElementExt: ElementRepr {
     fn render_loop(&mut self, options: TerminalOptions) -> impl Future<Output = io::Result<()>> {
         async move {
             let terminal = Terminal::new(CrossTerminal::with_options(options)?)?;
-            render_loop(self, terminal).await?;
+            render_loop(self, terminal, None).await?;
             Ok(())
         }
     }
@@ -69,7 +69,26 @@ pub trait ElementExt: ElementRepr {
     fn fullscreen(&mut self) -> impl Future<Output = io::Result<()>> {
         async move {
             let terminal = Terminal::new(CrossTerminal::new()?)?;
-            render_loop(self, terminal).await?;
+            render_loop(self, terminal, None).await?;
+            Ok(())
+        }
+    }
+
+    // 全屏运行并把收到的每个 raw 事件录入 `recorder`，退出后用 `recorder.recording()` 取出，
+    // 或（`serde`）用 `recorder.save(path)` 落盘。
+    fn fullscreen_recorded(&mut self, recorder: &Recorder) -> impl Future<Output = io::Result<()>> {
+        async move {
+            let terminal = Terminal::new(CrossTerminal::new()?)?;
+            render_loop(self, terminal, Some(recorder)).await?;
+            Ok(())
+        }
+    }
+
+    // 全屏回放一次录制：按原时间间隔重放事件，放完后交回实时输入（如按 Ctrl+C 退出）。
+    fn replay(&mut self, recording: Recording) -> impl Future<Output = io::Result<()>> {
+        async move {
+            let terminal = Terminal::with_replay(CrossTerminal::new()?, recording)?;
+            render_loop(self, terminal, None).await?;
             Ok(())
         }
     }
@@ -36,18 +36,44 @@ use std::{
 };
 mod use_context;
 pub use use_context::*;
+mod use_context_selector;
+pub use use_context_selector::*;
+mod use_selector;
+pub(crate) use use_selector::*;
 mod use_input;
 pub use use_input::*;
 mod use_future;
 pub use use_future::*;
 mod use_state;
 pub use use_state::*;
+mod use_reducer;
+pub use use_reducer::*;
 mod use_memo;
 pub use use_memo::*;
+mod use_computed;
+pub use use_computed::*;
+mod use_ref;
+pub use use_ref::*;
+mod use_form;
+pub use use_form::*;
+mod use_history_state;
+pub use use_history_state::*;
+#[cfg(feature = "serde")]
+mod use_persisted_state;
+#[cfg(feature = "serde")]
+pub use use_persisted_state::*;
 mod use_effect;
 pub use use_effect::*;
 mod use_async_state;
 pub use use_async_state::*;
+mod use_task;
+pub use use_task::*;
+mod use_stream;
+pub use use_stream::*;
+mod use_timer;
+pub use use_timer::*;
+mod use_animation;
+pub use use_animation::*;
 mod use_insert_before;
 pub use use_insert_before::*;
 mod use_size;
@@ -56,6 +82,12 @@ mod use_exit;
 pub use use_exit::*;
 mod use_on_drop;
 pub use use_on_drop::*;
+mod use_mouse;
+pub use use_mouse::*;
+mod use_drag;
+pub use use_drag::*;
+mod use_event_bus;
+pub use use_event_bus::*;
 
 #[cfg(feature = "router")]
 mod use_router;
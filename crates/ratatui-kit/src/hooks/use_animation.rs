@@ -0,0 +1,127 @@
+use std::{
+    task::Poll,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    Hook, Hooks, SystemContext, UseContext,
+    animation::{Animatable, Easing, FRAME_INTERVAL, Progress, Transition, Tween},
+    time::Timer,
+};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::Hooks<'_, '_> {}
+}
+
+// `use_animation` 在本帧的采样结果。
+#[derive(Clone, Debug, PartialEq)]
+pub struct Animated<T> {
+    value: T,
+    animating: bool,
+}
+
+impl<T> Animated<T> {
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    pub fn into_value(self) -> T {
+        self.value
+    }
+
+    // 过渡是否仍在进行（进行中的组件每帧间隔重渲一次）。
+    pub fn is_animating(&self) -> bool {
+        self.animating
+    }
+}
+
+pub trait UseAnimation: private::Sealed {
+    // 让返回值平滑地跟随 `target`：首帧直接取 `target`，之后 `target` 每次变化都从当前值
+    // 按 `transition` 过渡过去（进行中改变目标时从半途的值重新出发）。
+    fn use_animation<T>(&mut self, target: T, transition: impl Into<Transition>) -> Animated<T>
+    where
+        T: Animatable + Unpin + 'static;
+
+    // 按时长与缓动曲线过渡的简写，直接返回当前值。
+    fn use_tween<T>(&mut self, target: T, duration: Duration, easing: Easing) -> T
+    where
+        T: Animatable + Unpin + 'static;
+}
+
+struct UseAnimationImpl<T> {
+    timer: Timer,
+    from: T,
+    to: T,
+    value: T,
+    // `None` 表示静止。
+    progress: Option<Progress>,
+    // 进行中时请求下一帧的时刻。
+    next_frame: Option<Instant>,
+}
+
+impl<T: Unpin> Hook for UseAnimationImpl<T> {
+    fn poll_change(&mut self, cx: &mut std::task::Context) -> Poll<()> {
+        // 动画进行中，到下一帧的时刻就请求重渲；静止时不登记定时器。
+        if let Some(next_frame) = self.next_frame
+            && self.timer.poll_deadline(next_frame, cx)
+        {
+            self.next_frame = None;
+            return Poll::Ready(());
+        }
+        Poll::Pending
+    }
+}
+
+impl UseAnimation for Hooks<'_, '_> {
+    fn use_animation<T>(&mut self, target: T, transition: impl Into<Transition>) -> Animated<T>
+    where
+        T: Animatable + Unpin + 'static,
+    {
+        let transition = transition.into();
+        let (now, timers) = {
+            let system = self.use_context::<SystemContext>();
+            (system.frame_time(), system.timers.handle())
+        };
+        let hook = self.use_hook(|| UseAnimationImpl {
+            timer: Timer::new(timers),
+            from: target.clone(),
+            to: target.clone(),
+            value: target.clone(),
+            progress: None,
+            next_frame: None,
+        });
+
+        if hook.to != target {
+            hook.from = hook.value.clone();
+            hook.to = target;
+            hook.progress = Some(Progress::start(now));
+        }
+
+        if let Some(progress) = hook.progress.as_mut() {
+            let (t, done) = progress.sample(&transition, now);
+            if done {
+                hook.value = hook.to.clone();
+                hook.progress = None;
+                hook.next_frame = None;
+                hook.timer.cancel();
+            } else {
+                hook.value = hook.from.interpolate(&hook.to, t);
+                hook.next_frame = Some(now + FRAME_INTERVAL);
+            }
+        }
+
+        Animated {
+            value: hook.value.clone(),
+            animating: hook.progress.is_some(),
+        }
+    }
+
+    fn use_tween<T>(&mut self, target: T, duration: Duration, easing: Easing) -> T
+    where
+        T: Animatable + Unpin + 'static,
+    {
+        self.use_animation(target, Tween { duration, easing })
+            .into_value()
+    }
+}
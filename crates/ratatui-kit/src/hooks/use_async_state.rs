@@ -1,70 +1,330 @@
-use std::future::Future;
+use std::{future::Future, time::Duration};
 
-use crate::{Hooks, State, UseEffect, UseState};
+use futures::{FutureExt, future::LocalBoxFuture};
+
+use crate::{Hook, Hooks, State, StateRef, SystemContext, UseContext, UseState};
 
 mod private {
     pub trait Sealed {}
     impl Sealed for crate::Hooks<'_, '_> {}
 }
 
-// 异步数据加载的三态结果。
+// 异步请求的当前阶段。
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum AsyncStatus {
+    // 尚未发起，或进行中的请求被 `cancel`。
+    #[default]
+    Idle,
+    Loading,
+    Success,
+    Error,
+}
+
+// 失败重试策略。第 `n` 次重试前等待 `backoff * 2^n`，不超过 `max_backoff`。
+// 默认不重试。
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RetryPolicy {
+    // 首次失败后最多再尝试的次数。
+    pub max_retries: u32,
+    // 第一次重试前的等待时间。
+    pub backoff: Duration,
+    // 单次等待上限。
+    pub max_backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 0,
+            backoff: Duration::from_millis(200),
+            max_backoff: Duration::from_secs(10),
+        }
+    }
+}
+
+impl RetryPolicy {
+    // 第 `retry` 次（从 0 计）重试前的等待时间。
+    pub fn delay(&self, retry: u32) -> Duration {
+        self.backoff
+            .saturating_mul(2u32.saturating_pow(retry))
+            .min(self.max_backoff)
+    }
+}
+
+// 异步数据的快照：阶段 + 最近一次成功的数据 + 最近一次失败的错误。
+//
+// 重新加载期间保留旧 `data`，便于列表、详情等界面继续显示旧内容；新请求开始时清空 `error`。
+pub struct AsyncValue<T, E> {
+    status: AsyncStatus,
+    data: Option<T>,
+    error: Option<E>,
+    retries: u32,
+    // 当前有效请求的代号。每次发起 / 取消都会递增，结果只在代号一致时写回，过期请求被忽略。
+    generation: u64,
+    // `refetch` 请求计数，hook 与自己见过的值比较决定是否重新发起。
+    refetch: u64,
+}
+
+impl<T, E> Default for AsyncValue<T, E> {
+    fn default() -> Self {
+        Self {
+            status: AsyncStatus::Idle,
+            data: None,
+            error: None,
+            retries: 0,
+            generation: 0,
+            refetch: 0,
+        }
+    }
+}
+
+impl<T, E> AsyncValue<T, E> {
+    pub fn status(&self) -> AsyncStatus {
+        self.status
+    }
+
+    pub fn data(&self) -> Option<&T> {
+        self.data.as_ref()
+    }
+
+    pub fn error(&self) -> Option<&E> {
+        self.error.as_ref()
+    }
+
+    // 当前请求已经重试的次数。
+    pub fn retries(&self) -> u32 {
+        self.retries
+    }
+
+    pub fn is_loading(&self) -> bool {
+        self.status == AsyncStatus::Loading
+    }
+}
+
+// `use_async_state` 返回的句柄，`Copy`，可移入事件 handler 调用 `refetch` / `cancel`。
 pub struct AsyncState<T, E>
 where
     T: Unpin + Send + Sync + 'static,
     E: Unpin + Send + Sync + 'static,
 {
-    pub data: State<Option<T>>,
-    pub loading: State<bool>,
-    pub error: State<Option<E>>,
+    state: State<AsyncValue<T, E>>,
+}
+
+impl<T, E> Clone for AsyncState<T, E>
+where
+    T: Unpin + Send + Sync + 'static,
+    E: Unpin + Send + Sync + 'static,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, E> Copy for AsyncState<T, E>
+where
+    T: Unpin + Send + Sync + 'static,
+    E: Unpin + Send + Sync + 'static,
+{
+}
+
+impl<T, E> AsyncState<T, E>
+where
+    T: Unpin + Send + Sync + 'static,
+    E: Unpin + Send + Sync + 'static,
+{
+    // 读取当前快照。
+    pub fn read(&self) -> StateRef<'_, AsyncValue<T, E>> {
+        self.state.read()
+    }
+
+    pub fn status(&self) -> AsyncStatus {
+        self.state.read().status
+    }
+
+    pub fn is_loading(&self) -> bool {
+        self.state.read().is_loading()
+    }
+
+    // 以当前依赖重新发起请求；进行中的请求被取代，其结果会被忽略。
+    pub fn refetch(&self) {
+        self.state.write().refetch += 1;
+    }
+
+    // 取消进行中的请求：状态回到 `Idle`，保留旧 `data` / `error`。未在加载时无操作。
+    pub fn cancel(&self) {
+        if !self.state.read().is_loading() {
+            return;
+        }
+        let mut value = self.state.write();
+        value.generation += 1;
+        value.status = AsyncStatus::Idle;
+        value.retries = 0;
+    }
 }
 
 pub trait UseAsyncState: private::Sealed {
-    // 依赖变化时运行异步任务，并维护 data/loading/error 三态。
+    // 依赖变化（或 `refetch`）时运行异步任务，并维护 [`AsyncValue`]。
     fn use_async_state<F, Fut, D, T, E>(&mut self, f: F, deps: D) -> AsyncState<T, E>
     where
-        F: FnOnce() -> Fut + 'static,
+        F: FnMut() -> Fut + 'static,
+        Fut: Future<Output = Result<T, E>> + 'static,
+        D: PartialEq + Unpin + 'static,
+        T: Unpin + Send + Sync + 'static,
+        E: Unpin + Send + Sync + 'static;
+
+    // 带失败重试的 `use_async_state`。每次重试都重新调用 `f`。
+    fn use_async_state_with_retry<F, Fut, D, T, E>(
+        &mut self,
+        retry: RetryPolicy,
+        f: F,
+        deps: D,
+    ) -> AsyncState<T, E>
+    where
+        F: FnMut() -> Fut + 'static,
         Fut: Future<Output = Result<T, E>> + 'static,
         D: PartialEq + Unpin + 'static,
         T: Unpin + Send + Sync + 'static,
         E: Unpin + Send + Sync + 'static;
 }
 
+struct UseAsyncStateImpl<D> {
+    deps: Option<D>,
+    // 见过的 `refetch` 计数。
+    refetch: u64,
+    // 进行中请求的代号，与 `AsyncValue::generation` 不一致说明已被取消。
+    generation: u64,
+    future: Option<LocalBoxFuture<'static, ()>>,
+}
+
+impl<D> Default for UseAsyncStateImpl<D> {
+    fn default() -> Self {
+        Self {
+            deps: None,
+            refetch: 0,
+            generation: 0,
+            future: None,
+        }
+    }
+}
+
+impl<D: Unpin> Hook for UseAsyncStateImpl<D> {
+    fn poll_change(&mut self, cx: &mut std::task::Context) -> std::task::Poll<()> {
+        if let Some(future) = self.future.as_mut()
+            && future.as_mut().poll(cx).is_ready()
+        {
+            self.future = None;
+        }
+        // 结果经 `State` 写回并由其唤醒，这里无需额外触发重渲。
+        std::task::Poll::Pending
+    }
+}
+
 impl UseAsyncState for Hooks<'_, '_> {
     fn use_async_state<F, Fut, D, T, E>(&mut self, f: F, deps: D) -> AsyncState<T, E>
     where
-        F: FnOnce() -> Fut + 'static,
+        F: FnMut() -> Fut + 'static,
         Fut: Future<Output = Result<T, E>> + 'static,
         D: PartialEq + Unpin + 'static,
         T: Unpin + Send + Sync + 'static,
         E: Unpin + Send + Sync + 'static,
     {
-        let mut data = self.use_state(|| None::<T>);
-        let mut loading = self.use_state(|| false);
-        let mut error = self.use_state(|| None::<E>);
+        self.use_async_state_with_retry(RetryPolicy::default(), f, deps)
+    }
 
-        self.use_async_effect(
-            async move {
-                loading.set(true);
-                error.set(None);
+    fn use_async_state_with_retry<F, Fut, D, T, E>(
+        &mut self,
+        retry: RetryPolicy,
+        mut f: F,
+        deps: D,
+    ) -> AsyncState<T, E>
+    where
+        F: FnMut() -> Fut + 'static,
+        Fut: Future<Output = Result<T, E>> + 'static,
+        D: PartialEq + Unpin + 'static,
+        T: Unpin + Send + Sync + 'static,
+        E: Unpin + Send + Sync + 'static,
+    {
+        let state = self.use_state(AsyncValue::<T, E>::default);
+        // 退避等待走组件树的时间轮，测试装入的手动时钟同样控制重试。
+        let timers = self.use_context::<SystemContext>().timers.handle();
+        let hook = self.use_hook(UseAsyncStateImpl::<D>::default);
 
-                match f().await {
-                    Ok(value) => {
-                        data.set(Some(value));
-                    }
-                    Err(err) => {
-                        error.set(Some(err));
+        // 被 `cancel` 的请求直接丢弃 future。
+        if hook.future.is_some() && state.read().generation != hook.generation {
+            hook.future = None;
+        }
+
+        let refetch = state.read().refetch;
+        if hook.deps.as_ref() == Some(&deps) && hook.refetch == refetch {
+            return AsyncState { state };
+        }
+        hook.deps = Some(deps);
+        hook.refetch = refetch;
+
+        // 同帧即可读到 Loading，不必再唤醒一次。
+        let generation = {
+            let mut value = state.write_no_update();
+            value.generation += 1;
+            value.status = AsyncStatus::Loading;
+            value.error = None;
+            value.retries = 0;
+            value.generation
+        };
+        hook.generation = generation;
+
+        // 仅当请求仍是最新一次时写回。
+        let is_current = move || state.read().generation == generation;
+
+        hook.future = Some(
+            async move {
+                let mut retries = 0;
+                loop {
+                    match f().await {
+                        Ok(data) => {
+                            if is_current() {
+                                let mut value = state.write();
+                                value.status = AsyncStatus::Success;
+                                value.data = Some(data);
+                            }
+                            break;
+                        }
+                        Err(_) if retries < retry.max_retries => {
+                            timers.sleep(retry.delay(retries)).await;
+                            retries += 1;
+                            if is_current() {
+                                state.write().retries = retries;
+                            }
+                        }
+                        Err(err) => {
+                            if is_current() {
+                                let mut value = state.write();
+                                value.status = AsyncStatus::Error;
+                                value.error = Some(err);
+                            }
+                            break;
+                        }
                     }
                 }
-
-                loading.set(false);
-            },
-            deps,
+            }
+            .boxed_local(),
         );
 
-        AsyncState {
-            data,
-            loading,
-            error,
-        }
+        AsyncState { state }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn retry_delay_doubles_up_to_cap() {
+        let retry = RetryPolicy {
+            max_retries: 5,
+            backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_millis(500),
+        };
+        let delays: Vec<_> = (0..5).map(|n| retry.delay(n).as_millis()).collect();
+        assert_eq!(delays, [100, 200, 400, 500, 500]);
     }
 }
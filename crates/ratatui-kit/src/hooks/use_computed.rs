@@ -0,0 +1,168 @@
+// 计算值：从多个响应式句柄派生的值，自动追踪求值时读取了哪些 `State` / `AtomState`，
+// 只有其中之一变更后才重新计算，无需像 `use_memo` 那样手写依赖。
+//
+// 只追踪经通知的写入（`set`、`write`、运算符）；`set_no_update` / `write_no_update` 不会触发重算。
+// 闭包捕获的普通值（如 props）不在追踪范围内，它们变化时不会重算。
+
+use std::{cell::RefCell, rc::Rc, task::Poll};
+
+use crate::{
+    Hook, Hooks,
+    reactive_handle::{Dependency, record, track},
+};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::Hooks<'_, '_> {}
+}
+
+struct ComputedInner<T> {
+    compute: Box<dyn Fn() -> T>,
+    // 缓存值及求值时读到的依赖。
+    cache: RefCell<Option<(T, Vec<Dependency>)>>,
+}
+
+// 惰性求值并缓存的派生值，由 `Computed::new` 或 `ReactiveHandle::map` 创建。
+// `clone` 共享同一缓存；在另一个计算值或 `use_computed` 中读取时，其依赖一并被追踪。
+pub struct Computed<T> {
+    inner: Rc<ComputedInner<T>>,
+}
+
+impl<T> Clone for Computed<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<T: Clone + 'static> Computed<T> {
+    pub fn new(f: impl Fn() -> T + 'static) -> Self {
+        Self {
+            inner: Rc::new(ComputedInner {
+                compute: Box::new(f),
+                cache: RefCell::new(None),
+            }),
+        }
+    }
+
+    // 读取当前值：首次读取或依赖变更后重新计算，否则返回缓存。
+    pub fn get(&self) -> T {
+        let mut cache = self
+            .inner
+            .cache
+            .try_borrow_mut()
+            .expect("computed value read itself during evaluation");
+        let stale = cache
+            .as_ref()
+            .is_none_or(|(_, dependencies)| dependencies.iter().any(Dependency::is_stale));
+        if stale {
+            *cache = Some(track(&self.inner.compute));
+        }
+        let (value, dependencies) = cache.as_ref().expect("computed value is cached");
+        record(dependencies.iter().cloned());
+        value.clone()
+    }
+}
+
+pub trait UseComputed: private::Sealed {
+    // 返回 `f` 的结果并缓存。`f` 读取的响应式句柄变更时唤醒组件，下一帧重新计算；
+    // 其余重渲直接返回缓存值。
+    fn use_computed<T, F>(&mut self, f: F) -> T
+    where
+        F: FnOnce() -> T,
+        T: Clone + Unpin + 'static;
+}
+
+struct UseComputedImpl<T> {
+    value: Option<T>,
+    dependencies: Vec<Dependency>,
+}
+
+impl<T> UseComputedImpl<T> {
+    fn is_stale(&self) -> bool {
+        self.value.is_none() || self.dependencies.iter().any(Dependency::is_stale)
+    }
+}
+
+impl<T: Unpin> Hook for UseComputedImpl<T> {
+    fn poll_change(&mut self, cx: &mut std::task::Context) -> Poll<()> {
+        if self.value.is_some() && self.is_stale() {
+            return Poll::Ready(());
+        }
+        for dependency in &self.dependencies {
+            dependency.observe(cx.waker());
+        }
+        Poll::Pending
+    }
+}
+
+impl UseComputed for Hooks<'_, '_> {
+    fn use_computed<T, F>(&mut self, f: F) -> T
+    where
+        F: FnOnce() -> T,
+        T: Clone + Unpin + 'static,
+    {
+        let hook = self.use_hook(|| UseComputedImpl {
+            value: None,
+            dependencies: Vec::new(),
+        });
+        if hook.is_stale() {
+            let (value, dependencies) = track(f);
+            hook.value = Some(value);
+            hook.dependencies = dependencies;
+        }
+        hook.value.clone().expect("computed value is set")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ReactiveHandle, SingleWaker};
+    use generational_box::{Owner, SyncStorage};
+    use std::cell::Cell;
+
+    #[test]
+    fn recomputes_only_after_a_read_handle_changes() {
+        let owner = Owner::<SyncStorage>::default();
+        let mut a = ReactiveHandle::<i32, SingleWaker>::new_in(&owner, 1);
+        let mut b = ReactiveHandle::<i32, SingleWaker>::new_in(&owner, 2);
+        let mut unread = ReactiveHandle::<i32, SingleWaker>::new_in(&owner, 0);
+        let runs = Rc::new(Cell::new(0));
+        let sum = {
+            let runs = runs.clone();
+            Computed::new(move || {
+                runs.set(runs.get() + 1);
+                a.get() + b.get()
+            })
+        };
+
+        assert_eq!(sum.get(), 3);
+        assert_eq!(sum.get(), 3);
+        unread.set(5);
+        assert_eq!(sum.get(), 3);
+        assert_eq!(runs.get(), 1);
+
+        b.set(10);
+        assert_eq!(sum.get(), 11);
+        a.set_no_update(100);
+        assert_eq!(sum.get(), 11, "写入不通知时不重算");
+        assert_eq!(runs.get(), 2);
+    }
+
+    #[test]
+    fn map_and_nested_computed_track_through() {
+        let owner = Owner::<SyncStorage>::default();
+        let mut items = ReactiveHandle::<Vec<i32>, SingleWaker>::new_in(&owner, vec![1, 2]);
+        let len = items.map(Vec::len);
+        let label = {
+            let len = len.clone();
+            Computed::new(move || format!("{} items", len.get()))
+        };
+
+        assert_eq!(label.get(), "2 items");
+        items.set(vec![1, 2, 3]);
+        assert_eq!(label.get(), "3 items");
+    }
+}
@@ -0,0 +1,251 @@
+// 响应式上下文 Hook。
+//
+// `use_context` 返回注入时的借用，提供的值在父组件重渲之外变化时消费者不会得知。响应式上下文
+// 由提供者经 `use_context_state` 持有一个 `ContextState<T>`，后代组件经 `use_reactive_context`
+// 订阅整个值，或经 `use_context_selector` 只订阅其中一部分——与 `use_atom_selector` 相同，
+// 仅当选取结果变化时才请求重渲。
+//
+// ## 示例
+// ```rust
+// static SETTINGS: ReactiveContext<Settings> = create_context();
+//
+// #[component]
+// fn App(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+//     let settings = hooks.use_context_state(&SETTINGS, Settings::default);
+//     element!(ContextProvider(value: SETTINGS.provide(settings)) {
+//         StatusBar()
+//     })
+// }
+//
+// #[component]
+// fn StatusBar(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+//     let theme = hooks.use_context_selector(&SETTINGS, |settings| settings.theme.clone());
+//     ...
+// }
+// ```
+
+use std::{any::type_name, task::Poll};
+
+use generational_box::{Owner, SyncStorage};
+
+use super::{Hook, Hooks};
+use crate::{
+    ContextState, ElementKey, ReactiveContext,
+    context::{ContextLookup, ProvidedContext},
+};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::Hooks<'_, '_> {}
+}
+
+pub trait UseReactiveContext: private::Sealed {
+    // 在提供者组件内创建上下文持有的值，随组件卸载释放。写入只唤醒消费者，提供者本身不重渲。
+    fn use_context_state<T, F>(&mut self, context: &ReactiveContext<T>, init: F) -> ContextState<T>
+    where
+        F: FnOnce() -> T,
+        T: Unpin + Send + Sync + 'static;
+
+    // 订阅最近的祖先提供者的值：每次写入都请求重渲本组件。找不到提供者时 panic。
+    fn use_reactive_context<T>(&mut self, context: &ReactiveContext<T>) -> ContextState<T>
+    where
+        T: Unpin + Send + Sync + 'static;
+
+    // 同 `use_reactive_context`，找不到提供者时返回 `None`。
+    fn try_use_reactive_context<T>(
+        &mut self,
+        context: &ReactiveContext<T>,
+    ) -> Option<ContextState<T>>
+    where
+        T: Unpin + Send + Sync + 'static;
+}
+
+pub trait UseContextSelector: private::Sealed {
+    // 订阅上下文值的一部分：返回 `selector` 作用于当前值的结果。值变更时重新选取，
+    // 仅当结果与上次不同（`PartialEq`）时才重渲本组件。找不到提供者时 panic。
+    fn use_context_selector<T, U, F>(&mut self, context: &ReactiveContext<T>, selector: F) -> U
+    where
+        T: Unpin + Send + Sync + 'static,
+        U: PartialEq + Clone + Unpin + 'static,
+        F: Fn(&T) -> U + 'static;
+}
+
+// 在上下文栈中查找 `context` 最近的提供者；同一 `T` 的其它标记提供的值被跳过。
+fn find_provided<T>(hooks: &Hooks, context: &ReactiveContext<T>) -> Option<ContextState<T>>
+where
+    T: Send + Sync + 'static,
+{
+    let id = context.id();
+    match hooks
+        .context?
+        .find_context(|provided: &ProvidedContext<T>| provided.id == id)
+    {
+        ContextLookup::Found(provided) => Some(provided.state),
+        _ => None,
+    }
+}
+
+fn expect_provided<T>(hooks: &Hooks, context: &ReactiveContext<T>, hook: &str) -> ContextState<T>
+where
+    T: Send + Sync + 'static,
+{
+    find_provided(hooks, context).unwrap_or_else(|| {
+        let ty = type_name::<T>();
+        panic!(
+            "reactive context `{ty}` not found: `{hook}` only searches ancestor \
+             `ContextProvider`s. Provide it with `ContextProvider(value: CONTEXT.provide(state))` \
+             around this component, where `state` comes from `use_context_state`."
+        )
+    })
+}
+
+struct UseContextStateImpl<T>
+where
+    T: Unpin + Send + Sync + 'static,
+{
+    state: ContextState<T>,
+    _storage: Owner<SyncStorage>,
+}
+
+impl<T> Hook for UseContextStateImpl<T> where T: Unpin + Send + Sync + 'static {}
+
+// 消费者订阅：提供者换了（重新挂载）时退订旧值。
+struct UseReactiveContextImpl<T>
+where
+    T: Unpin + Send + Sync + 'static,
+{
+    state: Option<ContextState<T>>,
+    key: Option<ElementKey>,
+}
+
+impl<T> UseReactiveContextImpl<T>
+where
+    T: Unpin + Send + Sync + 'static,
+{
+    fn set_state(&mut self, state: Option<ContextState<T>>) {
+        let same = match (&self.state, &state) {
+            (Some(old), Some(new)) => old.same_storage(new),
+            (None, None) => true,
+            _ => false,
+        };
+        if same {
+            return;
+        }
+        if let (Some(old), Some(key)) = (&self.state, &self.key) {
+            old.remove_waker(key);
+        }
+        self.state = state;
+    }
+}
+
+impl<T> Hook for UseReactiveContextImpl<T>
+where
+    T: Unpin + Send + Sync + 'static,
+{
+    fn poll_change(&mut self, cx: &mut std::task::Context) -> Poll<()> {
+        let (Some(state), Some(key)) = (&self.state, &self.key) else {
+            return Poll::Pending;
+        };
+        state.poll_change(Some(key), cx)
+    }
+
+    fn post_component_update(&mut self, updater: &mut crate::ComponentUpdater) {
+        if self.key.is_none() {
+            self.key = Some(updater.key().clone());
+        }
+    }
+
+    fn on_drop(&mut self) {
+        if let (Some(state), Some(key)) = (&self.state, &self.key) {
+            state.remove_waker(key);
+        }
+    }
+}
+
+impl UseReactiveContext for Hooks<'_, '_> {
+    fn use_context_state<T, F>(&mut self, _context: &ReactiveContext<T>, init: F) -> ContextState<T>
+    where
+        F: FnOnce() -> T,
+        T: Unpin + Send + Sync + 'static,
+    {
+        self.use_hook(move || {
+            let storage = Owner::default();
+            UseContextStateImpl {
+                state: ContextState::new_in(&storage, init()),
+                _storage: storage,
+            }
+        })
+        .state
+    }
+
+    fn use_reactive_context<T>(&mut self, context: &ReactiveContext<T>) -> ContextState<T>
+    where
+        T: Unpin + Send + Sync + 'static,
+    {
+        let state = expect_provided(self, context, "use_reactive_context");
+        subscribe(self, Some(state));
+        state
+    }
+
+    fn try_use_reactive_context<T>(
+        &mut self,
+        context: &ReactiveContext<T>,
+    ) -> Option<ContextState<T>>
+    where
+        T: Unpin + Send + Sync + 'static,
+    {
+        let state = find_provided(self, context);
+        subscribe(self, state);
+        state
+    }
+}
+
+fn subscribe<T>(hooks: &mut Hooks, state: Option<ContextState<T>>)
+where
+    T: Unpin + Send + Sync + 'static,
+{
+    hooks
+        .use_hook(|| UseReactiveContextImpl { state, key: None })
+        .set_state(state);
+}
+
+impl UseContextSelector for Hooks<'_, '_> {
+    fn use_context_selector<T, U, F>(&mut self, context: &ReactiveContext<T>, selector: F) -> U
+    where
+        T: Unpin + Send + Sync + 'static,
+        U: PartialEq + Clone + Unpin + 'static,
+        F: Fn(&T) -> U + 'static,
+    {
+        let state = expect_provided(self, context, "use_context_selector");
+        super::use_handle_selector(self, state, selector)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::task::noop_waker;
+    use std::task::Context;
+
+    #[test]
+    fn replaced_provider_drops_old_subscription() {
+        let storage = Owner::default();
+        let old = ContextState::new_in(&storage, 0);
+        let new = ContextState::new_in(&storage, 0);
+        let key = ElementKey::decl(4);
+        let mut hook = UseReactiveContextImpl {
+            state: Some(old),
+            key: Some(key.clone()),
+        };
+        let waker = noop_waker();
+        let mut cx = Context::from_waker(&waker);
+
+        assert!(hook.poll_change(&mut cx).is_pending());
+        assert!(old.has_waker(&key));
+
+        hook.set_state(Some(new));
+        assert!(!old.has_waker(&key));
+        assert!(hook.poll_change(&mut cx).is_pending());
+        assert!(new.has_waker(&key));
+    }
+}
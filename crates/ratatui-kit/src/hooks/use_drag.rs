@@ -0,0 +1,151 @@
+// 拖放钩子：在指针捕获（`EventResult::Capture`）之上提供拖源 / 放置目标语义。
+//
+// - [`UseDragSource::use_drag_source`]：本组件区域内按下左键开始会话并捕获指针，拖动中返回
+//   `Some(DragInfo)`，松开时把 payload 投递给指针下的 drop target。`Esc` 取消拖动。
+// - [`UseDropTarget::use_drop_target`]：声明本组件区域接收类型为 `T` 的 payload，返回当前是否
+//   有匹配的拖动悬停其上（用于高亮）。
+//
+// payload 按类型匹配：拖源 `T` 只会投递给 `use_drop_target::<T>`。命中区域与 `hit_test` 相同，
+// 是组件**上一帧**的 area。须在 context-aware 的 `Hooks` 上调用。
+//
+// 只需要「按下后持续收到拖动」（拖动分隔条、滚动条滑块）时，直接在 `use_event_handler` 里对
+// `MouseEventKind::Down` 返回 `EventResult::Capture` 即可，无需这里的会话。
+
+use std::{
+    any::{Any, TypeId},
+    cell::{Cell, RefCell},
+    rc::Rc,
+};
+
+use crossterm::event::{Event, KeyCode, KeyEventKind, MouseButton, MouseEventKind};
+use ratatui::layout::{Position, Rect};
+
+use super::{Hook, Hooks};
+use crate::{
+    SystemContext, UseContext, UseEventHandler, UseState,
+    input::{DragInfo, DropHandler, EventOptions, EventPriority, EventResult, EventScope},
+};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::hooks::Hooks<'_, '_> {}
+}
+
+pub trait UseDragSource: private::Sealed {
+    // 把本组件区域声明为拖源。返回本组件发起的、已开始（指针离开按下单元格）的拖动信息。
+    //
+    // 按下左键即消费并捕获指针；原地松开不投递（视作点击）。
+    fn use_drag_source<T>(&mut self, payload: T) -> Option<DragInfo>
+    where
+        T: Clone + 'static;
+}
+
+pub trait UseDropTarget: private::Sealed {
+    // 把本组件区域声明为 `T` 的放置目标，`on_drop` 收到 payload 与松开时的位置。
+    // 多个目标重叠时投递给最晚登记（最靠上）的那个。返回匹配的拖动是否正悬停其上。
+    fn use_drop_target<T, F>(&mut self, on_drop: F) -> bool
+    where
+        T: Clone + 'static,
+        F: FnMut(T, DragInfo) + 'static;
+}
+
+// 跨帧持有区域与投递回调；回调每帧替换为最新闭包，区域在 `pre_component_draw` 回填。
+struct UseDropTargetImpl {
+    area: Rc<Cell<Rect>>,
+    on_drop: Rc<RefCell<DropHandler>>,
+}
+
+impl Hook for UseDropTargetImpl {
+    fn pre_component_draw(&mut self, drawer: &mut crate::ComponentDrawer) {
+        self.area.set(drawer.area);
+    }
+}
+
+impl UseDragSource for Hooks<'_, '_> {
+    fn use_drag_source<T>(&mut self, payload: T) -> Option<DragInfo>
+    where
+        T: Clone + 'static,
+    {
+        let drag = self.use_context::<SystemContext>().input.drag();
+        let mut dragging = self.use_state(|| None::<DragInfo>);
+
+        self.use_event_handler_with_options(
+            EventScope::Current,
+            EventPriority::Normal,
+            EventOptions { hit_test: true },
+            move |event| match event {
+                Event::Mouse(mouse) => {
+                    let position = Position::new(mouse.column, mouse.row);
+                    match mouse.kind {
+                        MouseEventKind::Down(MouseButton::Left) => {
+                            drag.borrow_mut().start(payload.clone(), position);
+                            EventResult::Capture
+                        }
+                        MouseEventKind::Drag(MouseButton::Left) => {
+                            let Some(info) = drag.borrow_mut().move_to(position) else {
+                                return EventResult::Ignored;
+                            };
+                            dragging.set(Some(info));
+                            EventResult::Consumed
+                        }
+                        MouseEventKind::Up(MouseButton::Left) => {
+                            // 先结束会话再调回调：回调里可能再读拖放状态。
+                            let dropped = drag.borrow_mut().finish();
+                            if dragging.read().is_some() {
+                                dragging.set(None);
+                            }
+                            if let Some(dropped) = dropped {
+                                dropped.deliver();
+                            }
+                            EventResult::Consumed
+                        }
+                        _ => EventResult::Ignored,
+                    }
+                }
+                Event::Key(key)
+                    if key.kind == KeyEventKind::Press
+                        && key.code == KeyCode::Esc
+                        && dragging.read().is_some() =>
+                {
+                    drag.borrow_mut().cancel();
+                    dragging.set(None);
+                    EventResult::Consumed
+                }
+                // 运行时已在失焦时作废会话，这里只同步本组件的拖动标记。
+                Event::FocusLost if dragging.read().is_some() => {
+                    dragging.set(None);
+                    EventResult::Ignored
+                }
+                _ => EventResult::Ignored,
+            },
+        );
+
+        dragging.get()
+    }
+}
+
+impl UseDropTarget for Hooks<'_, '_> {
+    fn use_drop_target<T, F>(&mut self, mut on_drop: F) -> bool
+    where
+        T: Clone + 'static,
+        F: FnMut(T, DragInfo) + 'static,
+    {
+        let (area, handler) = {
+            let hook = self.use_hook(|| UseDropTargetImpl {
+                area: Rc::new(Cell::new(Rect::default())),
+                on_drop: Rc::new(RefCell::new(Box::new(|_, _| {}))),
+            });
+            (hook.area.clone(), hook.on_drop.clone())
+        };
+        *handler.borrow_mut() = Box::new(move |payload: Rc<dyn Any>, info| {
+            if let Some(payload) = payload.downcast_ref::<T>() {
+                on_drop(payload.clone(), info);
+            }
+        });
+
+        let drag = self.use_context::<SystemContext>().input.drag();
+        let mut drag = drag.borrow_mut();
+        drag.register_target(TypeId::of::<T>(), area.clone(), handler);
+        drag.is_over(TypeId::of::<T>(), area.get())
+    }
+}
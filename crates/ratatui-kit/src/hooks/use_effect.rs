@@ -15,28 +15,59 @@ pub trait UseEffect: private::Sealed {
         F: FnOnce(),
         D: PartialEq + Unpin + 'static;
 
+    // 带清理的同步副作用：`f` 返回清理函数，在依赖下次变化、重新执行 `f` 之前调用，
+    // 组件销毁时也会调用。适合取消订阅、停止定时器等。
+    fn use_effect_with_cleanup<F, C, D>(&mut self, f: F, deps: D)
+    where
+        F: FnOnce() -> C,
+        C: FnOnce() + 'static,
+        D: PartialEq + Unpin + 'static;
+
     // 注册异步副作用，依赖变化时自动执行，适合异步校验、异步请求等。
     fn use_async_effect<F, D>(&mut self, f: F, deps: D)
     where
         F: Future<Output = ()> + 'static,
         D: PartialEq + Unpin + 'static;
+
+    // 带清理的异步副作用：future 完成时产出清理函数，在依赖下次变化或组件销毁时调用。
+    // future 尚未完成时依赖变化或组件销毁，future 直接被丢弃（即取消），不会产出清理函数。
+    fn use_async_effect_with_cleanup<F, C, D>(&mut self, f: F, deps: D)
+    where
+        F: Future<Output = C> + 'static,
+        C: FnOnce() + 'static,
+        D: PartialEq + Unpin + 'static;
 }
 
+type Cleanup = Box<dyn FnOnce()>;
+
 #[doc(hidden)]
 pub struct UseEffectImpl<D> {
     deps: Option<D>,
+    cleanup: Option<Cleanup>,
 }
 
 impl<D> Default for UseEffectImpl<D> {
     fn default() -> Self {
-        Self { deps: None }
+        Self {
+            deps: None,
+            cleanup: None,
+        }
+    }
+}
+
+impl<D> UseEffectImpl<D> {
+    fn run_cleanup(&mut self) {
+        if let Some(cleanup) = self.cleanup.take() {
+            cleanup();
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct UseAsyncEffectImpl<D> {
-    f: Option<LocalBoxFuture<'static, ()>>,
+    f: Option<LocalBoxFuture<'static, Option<Cleanup>>>,
     deps: Option<D>,
+    cleanup: Option<Cleanup>,
 }
 
 impl<D> Default for UseAsyncEffectImpl<D> {
@@ -44,23 +75,49 @@ impl<D> Default for UseAsyncEffectImpl<D> {
         Self {
             f: None,
             deps: None,
+            cleanup: None,
         }
     }
 }
 
+impl<D> UseAsyncEffectImpl<D> {
+    // 先取消进行中的 future，再清理上一次已完成的副作用。
+    fn cancel_and_cleanup(&mut self) {
+        self.f = None;
+        if let Some(cleanup) = self.cleanup.take() {
+            cleanup();
+        }
+    }
+
+    fn restart(&mut self, f: LocalBoxFuture<'static, Option<Cleanup>>, deps: D) {
+        self.cancel_and_cleanup();
+        self.f = Some(f);
+        self.deps = Some(deps);
+    }
+}
+
 impl<D: Unpin> Hook for UseAsyncEffectImpl<D> {
     fn poll_change(&mut self, cx: &mut std::task::Context) -> std::task::Poll<()> {
         if let Some(future) = self.f.as_mut()
-            && future.as_mut().poll(cx).is_ready()
+            && let Poll::Ready(cleanup) = future.as_mut().poll(cx)
         {
             self.f = None;
+            self.cleanup = cleanup;
             return Poll::Ready(());
         }
         Poll::Pending
     }
+
+    fn on_drop(&mut self) {
+        self.cancel_and_cleanup();
+    }
 }
 
-impl<D: Unpin> Hook for UseEffectImpl<D> {}
+impl<D: Unpin> Hook for UseEffectImpl<D> {
+    fn on_drop(&mut self) {
+        self.run_cleanup();
+    }
+}
 
 impl UseEffect for Hooks<'_, '_> {
     fn use_effect<F, D>(&mut self, f: F, deps: D)
@@ -75,6 +132,20 @@ impl UseEffect for Hooks<'_, '_> {
         }
     }
 
+    fn use_effect_with_cleanup<F, C, D>(&mut self, f: F, deps: D)
+    where
+        F: FnOnce() -> C,
+        C: FnOnce() + 'static,
+        D: PartialEq + Unpin + 'static,
+    {
+        let hook = self.use_hook(UseEffectImpl::<D>::default);
+        if hook.deps.as_ref() != Some(&deps) {
+            hook.run_cleanup();
+            hook.cleanup = Some(Box::new(f()));
+            hook.deps = Some(deps);
+        }
+    }
+
     fn use_async_effect<F, D>(&mut self, f: F, deps: D)
     where
         F: Future<Output = ()> + 'static,
@@ -83,8 +154,24 @@ impl UseEffect for Hooks<'_, '_> {
         let hook = self.use_hook(UseAsyncEffectImpl::<D>::default);
 
         if hook.deps.as_ref() != Some(&deps) {
-            hook.f = Some(f.boxed_local());
-            hook.deps = Some(deps);
+            hook.restart(f.map(|()| None).boxed_local(), deps);
+        }
+    }
+
+    fn use_async_effect_with_cleanup<F, C, D>(&mut self, f: F, deps: D)
+    where
+        F: Future<Output = C> + 'static,
+        C: FnOnce() + 'static,
+        D: PartialEq + Unpin + 'static,
+    {
+        let hook = self.use_hook(UseAsyncEffectImpl::<D>::default);
+
+        if hook.deps.as_ref() != Some(&deps) {
+            hook.restart(
+                f.map(|cleanup| Some(Box::new(cleanup) as Cleanup))
+                    .boxed_local(),
+                deps,
+            );
         }
     }
 }
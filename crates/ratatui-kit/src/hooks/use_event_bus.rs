@@ -0,0 +1,83 @@
+// 应用事件总线钩子：组件间广播类型化事件（「刷新」「文件已保存」），取代拿 `Atom` /
+// `ContextProvider` 充当信号的做法。
+//
+// - [`UseEventBus::use_event_bus`]：取得 `E` 的发布句柄 [`EventBus`]。
+// - [`UseAppEvent::use_app_event`]：订阅 `E`，闭包返回 [`EventResult::Consumed`] 截断后续订阅者。
+//
+// 事件经 `InputRuntime` 投递，作用域 / 优先级 / 消费语义与 `use_event_handler` 完全一致：模态层
+// 打开时背景订阅者同样被截断，需要无视模态的订阅请用 [`EventScope::Global`]。须在 context-aware
+// 的 `Hooks` 上调用。
+
+use super::{Hook, Hooks};
+use crate::{
+    SystemContext, UseContext,
+    input::{EventBus, EventPriority, EventResult, EventScope},
+};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::hooks::Hooks<'_, '_> {}
+}
+
+pub trait UseEventBus: private::Sealed {
+    // 取得 `E` 类型事件的发布句柄。句柄可 `clone`、可跨线程发布。
+    fn use_event_bus<E>(&mut self) -> EventBus<E>
+    where
+        E: Send + 'static;
+}
+
+pub trait UseAppEvent: private::Sealed {
+    // 订阅 `E` 类型事件，归属当前层、`Normal` 优先级。
+    fn use_app_event<E, F>(&mut self, f: F)
+    where
+        E: 'static,
+        F: FnMut(&E) -> EventResult + 'static;
+
+    // 指定作用域与优先级的订阅。
+    fn use_app_event_with<E, F>(&mut self, scope: EventScope, priority: EventPriority, f: F)
+    where
+        E: 'static,
+        F: FnMut(&E) -> EventResult + 'static;
+}
+
+// `use_app_event` 的占位 hook：闭包每帧移交 `InputRuntime`,仅占用稳定的 hook 顺序槽。
+struct UseAppEventImpl;
+impl Hook for UseAppEventImpl {}
+
+impl UseEventBus for Hooks<'_, '_> {
+    fn use_event_bus<E>(&mut self) -> EventBus<E>
+    where
+        E: Send + 'static,
+    {
+        EventBus::new(self.use_context::<SystemContext>().input.app_events())
+    }
+}
+
+impl UseAppEvent for Hooks<'_, '_> {
+    fn use_app_event<E, F>(&mut self, f: F)
+    where
+        E: 'static,
+        F: FnMut(&E) -> EventResult + 'static,
+    {
+        self.use_app_event_with(EventScope::Current, EventPriority::Normal, f);
+    }
+
+    fn use_app_event_with<E, F>(&mut self, scope: EventScope, priority: EventPriority, mut f: F)
+    where
+        E: 'static,
+        F: FnMut(&E) -> EventResult + 'static,
+    {
+        self.use_hook(|| UseAppEventImpl); // 占顺序槽
+        let layer = self.resolve_event_layer(scope);
+
+        let mut sys = self.use_context_mut::<SystemContext>();
+        sys.input.register_app_handler(
+            layer,
+            priority,
+            Box::new(move |event| match event.downcast_ref::<E>() {
+                Some(event) => f(event),
+                None => EventResult::Ignored,
+            }),
+        );
+    }
+}
@@ -0,0 +1,809 @@
+// 表单状态：按字段记录值、touched / dirty、同步与异步校验结果，以及提交状态。
+//
+// - 字段在 `use_form` 的初始化闭包中声明，声明顺序即 Tab 焦点顺序；首个字段初始获得焦点。
+// - 校验：每次写入字段立即重跑同步校验；同步校验通过后再依次跑异步校验，新的写入会取消
+//   进行中的异步校验。错误始终是最新的，`display_error` 只在字段失焦过（touched）或提交过
+//   之后才返回，避免一打开表单就满屏报错。
+// - 提交：`submit` 把所有字段标记为 touched 并重新校验，等异步校验结束后，全部通过则调用
+//   `on_submit`，否则把焦点移到第一个出错的字段。
+//
+// `Input`、`SearchInput`、`Select`、`MultiSelect` 经 `field` 属性绑定字段；`Form` 组件处理
+// Tab / Shift+Tab 焦点切换，`FormField` 组件展示标签与错误文案。
+
+use std::{
+    any::{Any, type_name},
+    fmt,
+    marker::PhantomData,
+    ops::Deref,
+    sync::Mutex,
+    task::Poll,
+};
+
+use futures::{FutureExt, future::BoxFuture};
+
+use crate::{Hook, Hooks, State, UseState};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::Hooks<'_, '_> {}
+}
+
+type Validator<T> = Box<dyn Fn(&T) -> Result<(), String> + Send + Sync>;
+type AsyncValidator<T> = Box<dyn Fn(&T) -> BoxFuture<'static, Result<(), String>> + Send + Sync>;
+type SubmitFn = Box<dyn Fn(FormValues) -> BoxFuture<'static, Result<(), String>> + Send + Sync>;
+
+// 类型擦除后的字段值，使不同类型的字段可以放进同一个表单。
+trait FieldValue: Send + Sync {
+    fn as_any(&self) -> &dyn Any;
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+    fn is_dirty(&self) -> bool;
+    fn validate(&self) -> Result<(), String>;
+    // 依次执行全部异步校验，遇到第一个错误即停止；没有异步校验时返回 `None`。
+    fn validate_async(&self) -> Option<BoxFuture<'static, Result<(), String>>>;
+    fn snapshot(&self) -> Box<dyn Any + Send + Sync>;
+    fn reset(&mut self);
+}
+
+struct TypedField<T> {
+    value: T,
+    initial: T,
+    validators: Vec<Validator<T>>,
+    async_validators: Vec<AsyncValidator<T>>,
+}
+
+impl<T> FieldValue for TypedField<T>
+where
+    T: Clone + PartialEq + Send + Sync + 'static,
+{
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn is_dirty(&self) -> bool {
+        self.value != self.initial
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        self.validators
+            .iter()
+            .try_for_each(|validator| validator(&self.value))
+    }
+
+    fn validate_async(&self) -> Option<BoxFuture<'static, Result<(), String>>> {
+        if self.async_validators.is_empty() {
+            return None;
+        }
+        let checks: Vec<_> = self
+            .async_validators
+            .iter()
+            .map(|validator| validator(&self.value))
+            .collect();
+        Some(
+            async move {
+                for check in checks {
+                    check.await?;
+                }
+                Ok(())
+            }
+            .boxed(),
+        )
+    }
+
+    fn snapshot(&self) -> Box<dyn Any + Send + Sync> {
+        Box::new(self.value.clone())
+    }
+
+    fn reset(&mut self) {
+        self.value = self.initial.clone();
+    }
+}
+
+struct FieldEntry {
+    name: String,
+    touched: bool,
+    error: Option<String>,
+    validating: bool,
+    value: Box<dyn FieldValue>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Task {
+    Validate(usize),
+    Submit,
+}
+
+struct Job {
+    task: Task,
+    // `Mutex` 只为满足 `State` 的 `Sync` 约束；轮询时经 `get_mut` 访问，不会加锁。
+    future: Mutex<BoxFuture<'static, Result<(), String>>>,
+}
+
+#[doc(hidden)]
+pub struct FormData {
+    fields: Vec<FieldEntry>,
+    focused: Option<usize>,
+    submitting: bool,
+    submit_count: usize,
+    submit_error: Option<String>,
+    on_submit: Option<SubmitFn>,
+    jobs: Vec<Job>,
+}
+
+impl FormData {
+    fn index(&self, name: &str) -> Option<usize> {
+        self.fields.iter().position(|field| field.name == name)
+    }
+
+    // 重跑字段的校验：同步校验失败直接记错误，通过后启动异步校验（取代进行中的那次）。
+    fn validate(&mut self, index: usize) {
+        self.jobs.retain(|job| job.task != Task::Validate(index));
+        let field = &mut self.fields[index];
+        field.validating = false;
+        field.error = field.value.validate().err();
+        if field.error.is_none()
+            && let Some(future) = field.value.validate_async()
+        {
+            field.validating = true;
+            self.jobs.push(Job {
+                task: Task::Validate(index),
+                future: Mutex::new(future),
+            });
+        }
+    }
+
+    fn finish(&mut self, task: Task, result: Result<(), String>) {
+        match task {
+            Task::Validate(index) => {
+                let field = &mut self.fields[index];
+                field.validating = false;
+                field.error = result.err();
+                self.advance_submit();
+            }
+            Task::Submit => {
+                self.submitting = false;
+                self.submit_error = result.err();
+            }
+        }
+    }
+
+    // 提交中且校验都已结束时推进提交：有错误则中止并聚焦第一个出错字段，否则调用 `on_submit`。
+    fn advance_submit(&mut self) {
+        if !self.submitting
+            || self.jobs.iter().any(|job| job.task == Task::Submit)
+            || self.fields.iter().any(|field| field.validating)
+        {
+            return;
+        }
+        if let Some(invalid) = self.fields.iter().position(|field| field.error.is_some()) {
+            self.submitting = false;
+            self.focus(Some(invalid));
+            return;
+        }
+        match &self.on_submit {
+            Some(on_submit) => {
+                let future = on_submit(self.values());
+                self.jobs.push(Job {
+                    task: Task::Submit,
+                    future: Mutex::new(future),
+                });
+            }
+            None => self.submitting = false,
+        }
+    }
+
+    // 移动焦点；失去焦点的字段标记为 touched。
+    fn focus(&mut self, index: Option<usize>) {
+        if self.focused == index {
+            return;
+        }
+        if let Some(previous) = self.focused {
+            self.fields[previous].touched = true;
+        }
+        self.focused = index;
+    }
+
+    fn focus_by(&mut self, step: isize) {
+        let count = self.fields.len();
+        if count == 0 {
+            return;
+        }
+        let next = match self.focused {
+            Some(index) => (index as isize + step).rem_euclid(count as isize) as usize,
+            None if step < 0 => count - 1,
+            None => 0,
+        };
+        self.focus(Some(next));
+    }
+
+    fn values(&self) -> FormValues {
+        FormValues {
+            values: self
+                .fields
+                .iter()
+                .map(|field| (field.name.clone(), field.value.snapshot()))
+                .collect(),
+        }
+    }
+}
+
+// 提交时各字段值的快照，按字段名与类型取值。
+pub struct FormValues {
+    values: Vec<(String, Box<dyn Any + Send + Sync>)>,
+}
+
+impl FormValues {
+    // 字段不存在或类型不符时返回 `None`。
+    pub fn try_get<T: 'static>(&self, name: &str) -> Option<&T> {
+        self.values
+            .iter()
+            .find(|(field, _)| field == name)
+            .and_then(|(_, value)| value.downcast_ref())
+    }
+
+    pub fn get<T: Clone + 'static>(&self, name: &str) -> T {
+        self.try_get::<T>(name)
+            .unwrap_or_else(|| panic!("form has no field `{name}` of type `{}`", type_name::<T>()))
+            .clone()
+    }
+}
+
+// `use_form` 初始化闭包中声明字段与提交回调。
+#[derive(Default)]
+pub struct FormBuilder {
+    fields: Vec<FieldEntry>,
+    on_submit: Option<SubmitFn>,
+}
+
+impl FormBuilder {
+    // 声明字段，返回的构建器用于追加校验。字段名在表单内必须唯一。
+    pub fn field<T>(&mut self, name: impl Into<String>, initial: T) -> FieldBuilder<'_, T>
+    where
+        T: Clone + PartialEq + Send + Sync + 'static,
+    {
+        let name = name.into();
+        assert!(
+            self.fields.iter().all(|field| field.name != name),
+            "form field `{name}` is declared twice"
+        );
+        self.fields.push(FieldEntry {
+            name,
+            touched: false,
+            error: None,
+            validating: false,
+            value: Box::new(TypedField {
+                value: initial.clone(),
+                initial,
+                validators: Vec::new(),
+                async_validators: Vec::new(),
+            }),
+        });
+        let field = self.fields.last_mut().expect("field was just pushed");
+        FieldBuilder {
+            field: field
+                .value
+                .as_any_mut()
+                .downcast_mut()
+                .expect("field was just pushed with this type"),
+        }
+    }
+
+    // 校验全部通过后调用，`Err` 记为提交错误（`FormHandle::submit_error`）。
+    pub fn on_submit<F, Fut>(&mut self, f: F) -> &mut Self
+    where
+        F: Fn(FormValues) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), String>> + Send + 'static,
+    {
+        self.on_submit = Some(Box::new(move |values| f(values).boxed()));
+        self
+    }
+
+    fn build(self) -> FormData {
+        let mut data = FormData {
+            focused: (!self.fields.is_empty()).then_some(0),
+            fields: self.fields,
+            submitting: false,
+            submit_count: 0,
+            submit_error: None,
+            on_submit: self.on_submit,
+            jobs: Vec::new(),
+        };
+        // 初始值只跑同步校验；异步校验等到首次写入或提交时再跑。
+        for field in &mut data.fields {
+            field.error = field.value.validate().err();
+        }
+        data
+    }
+}
+
+pub struct FieldBuilder<'a, T> {
+    field: &'a mut TypedField<T>,
+}
+
+impl<T> FieldBuilder<'_, T> {
+    // 追加同步校验，按声明顺序执行，返回第一个错误。
+    pub fn validate<F>(self, f: F) -> Self
+    where
+        F: Fn(&T) -> Result<(), String> + Send + Sync + 'static,
+    {
+        self.field.validators.push(Box::new(f));
+        self
+    }
+
+    // 追加异步校验（如查重），在同步校验全部通过后依次执行。
+    pub fn validate_async<F, Fut>(self, f: F) -> Self
+    where
+        F: Fn(&T) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), String>> + Send + 'static,
+    {
+        self.field
+            .async_validators
+            .push(Box::new(move |value| f(value).boxed()));
+        self
+    }
+}
+
+// `use_form` 返回的表单句柄。`Copy + Send + Sync`，可移入事件 handler 与组件 props。
+#[derive(Clone, Copy)]
+pub struct FormHandle {
+    inner: State<FormData>,
+}
+
+impl fmt::Debug for FormHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FormHandle").finish_non_exhaustive()
+    }
+}
+
+impl FormHandle {
+    // 按名称取类型化的字段句柄；字段不存在或类型不符时 panic。
+    pub fn field<T: 'static>(&self, name: &str) -> Field<T> {
+        let data = self.inner.read();
+        let index = data
+            .index(name)
+            .unwrap_or_else(|| panic!("form has no field named `{name}`"));
+        assert!(
+            data.fields[index].value.as_any().is::<TypedField<T>>(),
+            "form field `{name}` is not a `{}`",
+            type_name::<T>()
+        );
+        Field {
+            field: FieldRef { form: *self, index },
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn values(&self) -> FormValues {
+        self.inner.read().values()
+    }
+
+    // 没有错误且没有进行中的异步校验。
+    pub fn is_valid(&self) -> bool {
+        self.inner
+            .read()
+            .fields
+            .iter()
+            .all(|field| field.error.is_none() && !field.validating)
+    }
+
+    pub fn is_dirty(&self) -> bool {
+        self.inner
+            .read()
+            .fields
+            .iter()
+            .any(|field| field.value.is_dirty())
+    }
+
+    pub fn is_validating(&self) -> bool {
+        self.inner
+            .read()
+            .fields
+            .iter()
+            .any(|field| field.validating)
+    }
+
+    // 从 `submit` 开始，到校验失败或 `on_submit` 结束为止。
+    pub fn is_submitting(&self) -> bool {
+        self.inner.read().submitting
+    }
+
+    pub fn submit_count(&self) -> usize {
+        self.inner.read().submit_count
+    }
+
+    // 最近一次提交 `on_submit` 返回的错误。
+    pub fn submit_error(&self) -> Option<String> {
+        self.inner.read().submit_error.clone()
+    }
+
+    // 提交表单。已在提交中时忽略。
+    pub fn submit(&self) {
+        let mut data = self.inner.write();
+        if data.submitting {
+            return;
+        }
+        data.submitting = true;
+        data.submit_count += 1;
+        data.submit_error = None;
+        for index in 0..data.fields.len() {
+            data.fields[index].touched = true;
+            data.validate(index);
+        }
+        data.advance_submit();
+    }
+
+    // 恢复初始值，清空 touched、提交状态与进行中的校验 / 提交，焦点回到首个字段。
+    pub fn reset(&self) {
+        let mut data = self.inner.write();
+        data.jobs.clear();
+        data.submitting = false;
+        data.submit_count = 0;
+        data.submit_error = None;
+        data.focused = (!data.fields.is_empty()).then_some(0);
+        for field in &mut data.fields {
+            field.value.reset();
+            field.touched = false;
+            field.validating = false;
+            field.error = field.value.validate().err();
+        }
+    }
+
+    // 当前获得焦点的字段名。
+    pub fn focused(&self) -> Option<String> {
+        let data = self.inner.read();
+        data.focused.map(|index| data.fields[index].name.clone())
+    }
+
+    // 焦点移到下一个字段（末尾回到开头）。
+    pub fn focus_next(&self) {
+        self.inner.write().focus_by(1);
+    }
+
+    // 焦点移到上一个字段（开头回到末尾）。
+    pub fn focus_prev(&self) {
+        self.inner.write().focus_by(-1);
+    }
+
+    // 移除焦点，原焦点字段标记为 touched。
+    pub fn blur(&self) {
+        self.inner.write().focus(None);
+    }
+}
+
+// 不区分值类型的字段句柄，`FormField` 用它读取标签状态与错误。
+#[derive(Clone, Copy, Debug)]
+pub struct FieldRef {
+    form: FormHandle,
+    index: usize,
+}
+
+impl FieldRef {
+    fn with<R>(&self, f: impl FnOnce(&FieldEntry) -> R) -> R {
+        f(&self.form.inner.read().fields[self.index])
+    }
+
+    pub fn form(&self) -> FormHandle {
+        self.form
+    }
+
+    pub fn name(&self) -> String {
+        self.with(|field| field.name.clone())
+    }
+
+    // 当前的校验错误，无论是否已展示。
+    pub fn error(&self) -> Option<String> {
+        self.with(|field| field.error.clone())
+    }
+
+    // 应展示给用户的错误：字段 touched 或表单提交过之后才返回。
+    pub fn display_error(&self) -> Option<String> {
+        let data = self.form.inner.read();
+        let field = &data.fields[self.index];
+        (field.touched || data.submit_count > 0)
+            .then(|| field.error.clone())
+            .flatten()
+    }
+
+    pub fn is_touched(&self) -> bool {
+        self.with(|field| field.touched)
+    }
+
+    // 标记为 touched（之后展示错误），不改变焦点。
+    pub fn touch(&self) {
+        self.form.inner.write().fields[self.index].touched = true;
+    }
+
+    pub fn is_dirty(&self) -> bool {
+        self.with(|field| field.value.is_dirty())
+    }
+
+    pub fn is_validating(&self) -> bool {
+        self.with(|field| field.validating)
+    }
+
+    // 没有错误且没有进行中的异步校验。
+    pub fn is_valid(&self) -> bool {
+        self.with(|field| field.error.is_none() && !field.validating)
+    }
+
+    pub fn is_focused(&self) -> bool {
+        self.form.inner.read().focused == Some(self.index)
+    }
+
+    pub fn focus(&self) {
+        self.form.inner.write().focus(Some(self.index));
+    }
+
+    // 本字段有焦点时移除焦点，并标记为 touched。
+    pub fn blur(&self) {
+        let mut data = self.form.inner.write();
+        if data.focused == Some(self.index) {
+            data.focus(None);
+        }
+    }
+}
+
+impl<T> From<Field<T>> for FieldRef {
+    fn from(field: Field<T>) -> Self {
+        field.field
+    }
+}
+
+// 让 `FormField(field: name)` 可以直接传类型化句柄。
+impl<T> From<Field<T>> for Option<FieldRef> {
+    fn from(field: Field<T>) -> Self {
+        Some(field.field)
+    }
+}
+
+// 类型化的字段句柄，经 `FormHandle::field` 获得。`Copy`，解引用为 [`FieldRef`] 读取状态。
+pub struct Field<T> {
+    field: FieldRef,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T> Clone for Field<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Field<T> {}
+
+impl<T> fmt::Debug for Field<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Field")
+            .field("index", &self.index)
+            .field("type", &type_name::<T>())
+            .finish()
+    }
+}
+
+impl<T> Deref for Field<T> {
+    type Target = FieldRef;
+
+    fn deref(&self) -> &FieldRef {
+        &self.field
+    }
+}
+
+impl<T> Field<T>
+where
+    T: Clone + PartialEq + Send + Sync + 'static,
+{
+    // 以只读引用访问当前值。
+    pub fn with<R>(&self, f: impl FnOnce(&T) -> R) -> R {
+        let data = self.form.inner.read();
+        let value = data.fields[self.index]
+            .value
+            .as_any()
+            .downcast_ref::<TypedField<T>>()
+            .expect("field type is checked by `FormHandle::field`");
+        f(&value.value)
+    }
+
+    pub fn get(&self) -> T {
+        self.with(T::clone)
+    }
+
+    // 写入新值并重新校验；与当前值相等时不做任何事。
+    pub fn set(&self, value: T) {
+        if self.with(|current| *current == value) {
+            return;
+        }
+        let mut data = self.form.inner.write();
+        data.fields[self.index]
+            .value
+            .as_any_mut()
+            .downcast_mut::<TypedField<T>>()
+            .expect("field type is checked by `FormHandle::field`")
+            .value = value;
+        data.validate(self.index);
+    }
+
+    pub fn update(&self, f: impl FnOnce(&mut T)) {
+        let mut value = self.get();
+        f(&mut value);
+        self.set(value);
+    }
+}
+
+pub trait UseForm: private::Sealed {
+    // 创建表单，`init` 只在首帧调用，在其中声明字段、校验与提交回调。
+    // 异步校验与 `on_submit` 返回的 future 由本组件轮询，组件卸载时随之丢弃。
+    fn use_form<F>(&mut self, init: F) -> FormHandle
+    where
+        F: FnOnce(&mut FormBuilder);
+}
+
+struct UseFormImpl {
+    form: FormHandle,
+}
+
+impl Hook for UseFormImpl {
+    fn poll_change(&mut self, cx: &mut std::task::Context) -> Poll<()> {
+        // 轮询期间不持有状态锁：future 可能读取表单。
+        let mut jobs = std::mem::take(&mut self.form.inner.write_no_update().jobs);
+        if jobs.is_empty() {
+            return Poll::Pending;
+        }
+        let mut finished = Vec::new();
+        jobs.retain_mut(|job| {
+            let future = job.future.get_mut().unwrap();
+            match future.poll_unpin(cx) {
+                Poll::Ready(result) => {
+                    finished.push((job.task, result));
+                    false
+                }
+                Poll::Pending => true,
+            }
+        });
+
+        if finished.is_empty() {
+            self.form.inner.write_no_update().jobs = jobs;
+            return Poll::Pending;
+        }
+        let mut data = self.form.inner.write();
+        data.jobs = jobs;
+        for (task, result) in finished {
+            data.finish(task, result);
+        }
+        Poll::Ready(())
+    }
+}
+
+impl UseForm for Hooks<'_, '_> {
+    fn use_form<F>(&mut self, init: F) -> FormHandle
+    where
+        F: FnOnce(&mut FormBuilder),
+    {
+        let inner = self.use_state(|| {
+            let mut builder = FormBuilder::default();
+            init(&mut builder);
+            builder.build()
+        });
+        let form = FormHandle { inner };
+        self.use_hook(|| UseFormImpl { form });
+        form
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ReactiveHandle, SingleWaker};
+    use futures::{channel::oneshot, task::noop_waker};
+    use generational_box::{Owner, SyncStorage};
+    use std::task::Context;
+
+    fn form(owner: &Owner<SyncStorage>, init: impl FnOnce(&mut FormBuilder)) -> UseFormImpl {
+        let mut builder = FormBuilder::default();
+        init(&mut builder);
+        UseFormImpl {
+            form: FormHandle {
+                inner: ReactiveHandle::<_, SingleWaker>::new_in(owner, builder.build()),
+            },
+        }
+    }
+
+    fn poll(hook: &mut UseFormImpl) -> Poll<()> {
+        let waker = noop_waker();
+        hook.poll_change(&mut Context::from_waker(&waker))
+    }
+
+    fn not_empty(value: &str) -> Result<(), String> {
+        if value.is_empty() {
+            Err("required".to_string())
+        } else {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn errors_are_shown_after_touch_or_submit() {
+        let owner = Owner::default();
+        let hook = form(&owner, |form| {
+            form.field("name", String::new())
+                .validate(|value: &String| not_empty(value));
+            form.field("age", 0u8);
+        });
+        let form = hook.form;
+        let name = form.field::<String>("name");
+
+        assert_eq!(name.error().as_deref(), Some("required"));
+        assert_eq!(name.display_error(), None, "未 touched 时不展示");
+        assert!(name.is_focused());
+
+        form.focus_next();
+        assert_eq!(form.focused().as_deref(), Some("age"));
+        assert_eq!(name.display_error().as_deref(), Some("required"));
+
+        name.set("Ada".to_string());
+        assert!(name.is_dirty());
+        assert!(form.is_valid());
+        form.reset();
+        assert!(!name.is_touched());
+        assert_eq!(name.get(), "");
+    }
+
+    #[test]
+    fn failed_submit_focuses_the_first_invalid_field() {
+        let owner = Owner::default();
+        let hook = form(&owner, |form| {
+            form.field("title", "ok".to_string())
+                .validate(|value: &String| not_empty(value));
+            form.field("body", String::new())
+                .validate(|value: &String| not_empty(value));
+            form.on_submit(|_| async { Ok(()) });
+        });
+        let form = hook.form;
+        form.focus_prev();
+        form.focus_prev();
+        assert_eq!(form.focused().as_deref(), Some("title"));
+
+        form.submit();
+        assert!(!form.is_submitting());
+        assert_eq!(form.submit_count(), 1);
+        assert_eq!(form.focused().as_deref(), Some("body"));
+        assert!(form.field::<String>("title").is_touched());
+    }
+
+    #[test]
+    fn async_validation_gates_submission() {
+        let owner = Owner::default();
+        let (check_tx, check_rx) = oneshot::channel::<Result<(), String>>();
+        let check_rx = Mutex::new(Some(check_rx));
+        let (submitted_tx, submitted_rx) = std::sync::mpsc::channel();
+        let mut hook = form(&owner, move |form| {
+            form.field("user", "ada".to_string())
+                .validate(|value: &String| not_empty(value))
+                .validate_async(move |_| {
+                    let check = check_rx.lock().unwrap().take().expect("checked once");
+                    async move { check.await.unwrap() }
+                });
+            form.on_submit(move |values| {
+                submitted_tx.send(values.get::<String>("user")).unwrap();
+                async { Err("server down".to_string()) }
+            });
+        });
+        let form = hook.form;
+        let user = form.field::<String>("user");
+
+        form.submit();
+        assert!(user.is_validating());
+        assert!(form.is_submitting());
+        assert_eq!(poll(&mut hook), Poll::Pending);
+
+        check_tx.send(Ok(())).unwrap();
+        assert_eq!(poll(&mut hook), Poll::Ready(()));
+        assert!(!user.is_validating());
+        assert_eq!(submitted_rx.try_recv().as_deref(), Ok("ada"));
+
+        assert_eq!(poll(&mut hook), Poll::Ready(()));
+        assert!(!form.is_submitting());
+        assert_eq!(form.submit_error().as_deref(), Some("server down"));
+    }
+}
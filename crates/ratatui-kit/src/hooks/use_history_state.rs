@@ -0,0 +1,326 @@
+// 带撤销 / 重做的状态：每次 `set` 把旧值压入历史，`undo` / `redo` 在历史中前后移动。
+// 连续的快速编辑可合并为一步（如逐字输入只撤销一次），并可绑定 Ctrl+Z / Ctrl+Y。
+
+use std::{
+    collections::VecDeque,
+    time::{Duration, Instant},
+};
+
+use crossterm::event::{Event, KeyCode, KeyEventKind, KeyModifiers};
+
+use crate::{
+    Clock, EventPriority, EventResult, EventScope, Hooks, State, UseEventHandler, UseState,
+    UseTimer,
+};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::Hooks<'_, '_> {}
+}
+
+// `use_history_state_with` 的选项。
+#[derive(Clone, Debug)]
+pub struct HistoryOptions {
+    // 最多保留的撤销步数，超出时丢弃最早的一步。默认 100。
+    pub limit: usize,
+    // 距上一次 `set` 不超过该时长的编辑合并进同一步。默认 0（不合并）。
+    pub coalesce: Duration,
+    // 在当前输入层绑定 Ctrl+Z 撤销、Ctrl+Y / Ctrl+Shift+Z 重做。默认关闭。
+    pub bind_keys: bool,
+}
+
+impl Default for HistoryOptions {
+    fn default() -> Self {
+        Self {
+            limit: 100,
+            coalesce: Duration::ZERO,
+            bind_keys: false,
+        }
+    }
+}
+
+#[doc(hidden)]
+pub struct History<T> {
+    present: T,
+    past: VecDeque<T>,
+    future: Vec<T>,
+    limit: usize,
+    coalesce: Duration,
+    clock: Clock,
+    // 上一次 `set` 的时刻；撤销 / 重做后清空，之后的编辑另起一步。
+    last_edit: Option<Instant>,
+}
+
+impl<T> History<T> {
+    fn new(present: T, options: &HistoryOptions, clock: Clock) -> Self {
+        Self {
+            present,
+            past: VecDeque::new(),
+            future: Vec::new(),
+            limit: options.limit,
+            coalesce: options.coalesce,
+            clock,
+            last_edit: None,
+        }
+    }
+
+    fn set(&mut self, value: T) {
+        let now = self.clock.now();
+        let coalesce = !self.past.is_empty()
+            && self
+                .last_edit
+                .is_some_and(|last| now.saturating_duration_since(last) <= self.coalesce);
+        let previous = std::mem::replace(&mut self.present, value);
+        if !coalesce {
+            self.past.push_back(previous);
+            while self.past.len() > self.limit {
+                self.past.pop_front();
+            }
+        }
+        self.future.clear();
+        self.last_edit = Some(now);
+    }
+
+    fn undo(&mut self) -> bool {
+        let Some(previous) = self.past.pop_back() else {
+            return false;
+        };
+        self.future
+            .push(std::mem::replace(&mut self.present, previous));
+        self.last_edit = None;
+        true
+    }
+
+    fn redo(&mut self) -> bool {
+        let Some(next) = self.future.pop() else {
+            return false;
+        };
+        self.past
+            .push_back(std::mem::replace(&mut self.present, next));
+        self.last_edit = None;
+        true
+    }
+}
+
+// `use_history_state` 返回的句柄。`Copy`，可移入事件 handler；写入与撤销都会触发重渲。
+pub struct HistoryState<T>
+where
+    T: Send + Sync + 'static,
+{
+    inner: State<History<T>>,
+}
+
+impl<T> Clone for HistoryState<T>
+where
+    T: Send + Sync + 'static,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for HistoryState<T> where T: Send + Sync + 'static {}
+
+impl<T> HistoryState<T>
+where
+    T: Send + Sync + 'static,
+{
+    // 以只读引用访问当前值。
+    pub fn with<R>(&self, f: impl FnOnce(&T) -> R) -> R {
+        f(&self.inner.read().present)
+    }
+
+    // 写入新值，旧值进入撤销历史，重做历史清空。
+    pub fn set(&self, value: T) {
+        self.inner.write().set(value);
+    }
+
+    // 撤销一步；没有可撤销的历史时返回 `false`。
+    pub fn undo(&self) -> bool {
+        self.can_undo() && self.inner.write().undo()
+    }
+
+    // 重做一步；没有可重做的历史时返回 `false`。
+    pub fn redo(&self) -> bool {
+        self.can_redo() && self.inner.write().redo()
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.inner.read().past.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.inner.read().future.is_empty()
+    }
+
+    // 清空撤销与重做历史，保留当前值。
+    pub fn clear_history(&self) {
+        let mut history = self.inner.write();
+        history.past.clear();
+        history.future.clear();
+        history.last_edit = None;
+    }
+}
+
+impl<T> HistoryState<T>
+where
+    T: Clone + Send + Sync + 'static,
+{
+    pub fn get(&self) -> T {
+        self.with(T::clone)
+    }
+
+    // 基于当前值修改，作为一次 `set` 记入历史。
+    pub fn update(&self, f: impl FnOnce(&mut T)) {
+        let mut value = self.get();
+        f(&mut value);
+        self.set(value);
+    }
+}
+
+pub trait UseHistoryState: private::Sealed {
+    // 带撤销 / 重做的状态，使用默认选项（保留 100 步、不合并、不绑定按键）。
+    fn use_history_state<T, F>(&mut self, init: F) -> HistoryState<T>
+    where
+        F: FnOnce() -> T,
+        T: Unpin + Send + Sync + 'static;
+
+    // 同 `use_history_state`，可配置历史上限、编辑合并窗口与 Ctrl+Z / Ctrl+Y 绑定。
+    // `limit` 与 `coalesce` 只在首帧读取；`bind_keys` 每帧生效。
+    fn use_history_state_with<T, F>(&mut self, init: F, options: HistoryOptions) -> HistoryState<T>
+    where
+        F: FnOnce() -> T,
+        T: Unpin + Send + Sync + 'static;
+}
+
+impl UseHistoryState for Hooks<'_, '_> {
+    fn use_history_state<T, F>(&mut self, init: F) -> HistoryState<T>
+    where
+        F: FnOnce() -> T,
+        T: Unpin + Send + Sync + 'static,
+    {
+        self.use_history_state_with(init, HistoryOptions::default())
+    }
+
+    fn use_history_state_with<T, F>(&mut self, init: F, options: HistoryOptions) -> HistoryState<T>
+    where
+        F: FnOnce() -> T,
+        T: Unpin + Send + Sync + 'static,
+    {
+        let clock = self.use_clock();
+        let history = HistoryState {
+            inner: self.use_state(|| History::new(init(), &options, clock)),
+        };
+        // 无论是否绑定都登记 handler，保持 hook 调用顺序稳定。
+        let bind_keys = options.bind_keys;
+        self.use_event_handler(
+            EventScope::Current,
+            EventPriority::Normal,
+            move |event| match event {
+                Event::Key(key)
+                    if bind_keys
+                        && key.kind == KeyEventKind::Press
+                        && key.modifiers.contains(KeyModifiers::CONTROL) =>
+                {
+                    let shift = key.modifiers.contains(KeyModifiers::SHIFT);
+                    match key.code {
+                        KeyCode::Char('z') if !shift => history.undo(),
+                        KeyCode::Char('y') | KeyCode::Char('Z') => history.redo(),
+                        KeyCode::Char('z') => history.redo(),
+                        _ => return EventResult::Ignored,
+                    };
+                    EventResult::Consumed
+                }
+                _ => EventResult::Ignored,
+            },
+        );
+        history
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ReactiveHandle, SingleWaker};
+    use generational_box::{Owner, SyncStorage};
+
+    fn history<T: Send + Sync + 'static>(
+        owner: &Owner<SyncStorage>,
+        value: T,
+        options: HistoryOptions,
+        clock: Clock,
+    ) -> HistoryState<T> {
+        HistoryState {
+            inner: ReactiveHandle::<_, SingleWaker>::new_in(
+                owner,
+                History::new(value, &options, clock),
+            ),
+        }
+    }
+
+    #[test]
+    fn undo_and_redo_walk_the_history() {
+        let owner = Owner::default();
+        let text = history(&owner, "a", HistoryOptions::default(), Clock::system());
+        text.set("ab");
+        text.set("abc");
+        assert!(text.undo());
+        assert!(text.undo());
+        assert_eq!(text.get(), "a");
+        assert!(!text.undo());
+        assert!(text.redo());
+        assert_eq!(text.get(), "ab");
+
+        // 新的编辑清空重做历史。
+        text.set("x");
+        assert!(!text.can_redo());
+        assert!(text.undo());
+        assert_eq!(text.get(), "ab");
+    }
+
+    #[test]
+    fn limit_drops_the_oldest_steps() {
+        let owner = Owner::default();
+        let options = HistoryOptions {
+            limit: 2,
+            ..HistoryOptions::default()
+        };
+        let count = history(&owner, 0, options, Clock::system());
+        for n in 1..=4 {
+            count.set(n);
+        }
+        assert!(count.undo());
+        assert!(count.undo());
+        assert!(!count.can_undo());
+        assert_eq!(count.get(), 2);
+    }
+
+    #[test]
+    fn rapid_edits_coalesce_into_one_step() {
+        let owner = Owner::default();
+        let clock = Clock::manual(Instant::now());
+        let options = HistoryOptions {
+            coalesce: Duration::from_millis(500),
+            ..HistoryOptions::default()
+        };
+        let text = history(&owner, String::new(), options, clock.clone());
+        text.set("h".into());
+        clock.advance(Duration::from_millis(100));
+        text.set("he".into());
+        clock.advance(Duration::from_millis(100));
+        text.set("hey".into());
+        clock.advance(Duration::from_secs(1));
+        text.set("hey!".into());
+
+        assert!(text.undo());
+        assert_eq!(text.get(), "hey");
+        assert!(text.undo());
+        assert_eq!(text.get(), "", "连续输入合并为一步");
+        assert!(!text.can_undo());
+
+        // 撤销后的编辑另起一步，不与之前的合并。
+        text.set("x".into());
+        assert!(text.undo());
+        assert_eq!(text.get(), "");
+    }
+}
@@ -16,7 +16,10 @@ use ratatui::layout::Rect;
 use super::{Hook, Hooks};
 use crate::{
     SystemContext, UseContext,
-    input::{CurrentLayer, EventOptions, EventPriority, EventResult, EventScope, InputLayer},
+    input::{
+        CurrentLayer, EventOptions, EventPriority, EventResult, EventScope, HandlerKey, InputLayer,
+        LayerId,
+    },
 };
 
 mod private {
@@ -34,7 +37,8 @@ pub trait UseInputLayer: private::Sealed {
 
 pub trait UseEventHandler: private::Sealed {
     // 注册一个事件 handler。`scope` 决定归属层、`priority` 决定同层投递顺序;闭包返回
-    // [`EventResult::Consumed`] 截断后续 handler。
+    // [`EventResult::Consumed`] 截断后续 handler,对鼠标按下返回 [`EventResult::Capture`]
+    // 则捕获指针直到松开。
     fn use_event_handler<F>(&mut self, scope: EventScope, priority: EventPriority, f: F)
     where
         F: FnMut(Event) -> EventResult + 'static;
@@ -64,8 +68,10 @@ impl UseInputLayer for Hooks<'_, '_> {
 }
 
 // `use_event_handler` 的 hook：跨帧持有 `Rc<Cell<Rect>>`,在 `pre_component_draw` 回填**上一帧** area
-// 供鼠标 `hit_test`。闭包本身不跨帧保存（每帧经 `register_handler` 移交 `InputRuntime`,下帧重建)。
+// 供鼠标 `hit_test`;`key` 供指针捕获跨帧找回本 handler。闭包本身不跨帧保存
+// （每帧经 `register_handler` 移交 `InputRuntime`,下帧重建)。
 struct UseEventHandlerImpl {
+    key: HandlerKey,
     area: Rc<Cell<Rect>>,
 }
 
@@ -93,15 +99,27 @@ impl UseEventHandler for Hooks<'_, '_> {
         F: FnMut(Event) -> EventResult + 'static,
     {
         // area 共享句柄:跨帧持有(use_hook),交给本帧的 HandlerEntry,pre_component_draw 回填。
-        let area = {
+        let (key, area) = {
             let hook = self.use_hook(|| UseEventHandlerImpl {
+                key: HandlerKey::next(),
                 area: Rc::new(Cell::new(Rect::default())),
             });
-            hook.area.clone()
+            (hook.key, hook.area.clone())
         };
 
-        // 归属解析:Global → 无层;Layer(h) → 显式层;Current → context 最近 CurrentLayer,无则 root 层。
-        let layer = match scope {
+        let layer = self.resolve_event_layer(scope);
+
+        // 当帧登记 handler,守卫即用即弃。
+        let mut sys = self.use_context_mut::<SystemContext>();
+        sys.input
+            .register_handler(layer, priority, options, key, area, Box::new(f));
+    }
+}
+
+impl Hooks<'_, '_> {
+    // 归属解析:Global → 无层;Layer(h) → 显式层;Current → context 最近 CurrentLayer,无则 root 层。
+    pub(super) fn resolve_event_layer(&self, scope: EventScope) -> Option<LayerId> {
+        match scope {
             EventScope::Global => None,
             EventScope::Layer(h) => Some(h.id),
             EventScope::Current => {
@@ -111,11 +129,6 @@ impl UseEventHandler for Hooks<'_, '_> {
                     .unwrap_or_else(|| self.use_context::<SystemContext>().input.root_layer());
                 Some(id)
             }
-        };
-
-        // 当帧登记 handler,守卫即用即弃。
-        let mut sys = self.use_context_mut::<SystemContext>();
-        sys.input
-            .register_handler(layer, priority, options, area, Box::new(f));
+        }
     }
 }
@@ -0,0 +1,136 @@
+// 鼠标交互钩子：在 `use_event_handler` 之上提供悬停与点击语义，供自定义组件复用。
+//
+// - [`UseHover::use_hover`]：指针位于本组件区域内时返回 `Some(Hover)`，移出即 `None`。
+// - [`UseClick::use_click`]：本组件区域内按下鼠标时回调 [`MouseClick`]，区分单击 / 双击。
+//
+// 命中区域是本组件**上一帧**的 area（`pre_component_draw` 回填），与 `EventOptions::hit_test`
+// 一致。与 `use_event_handler` 相同，须在 context-aware 的 `Hooks` 上调用。
+
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+};
+
+use crossterm::event::{Event, MouseEventKind};
+use ratatui::layout::{Position, Rect};
+
+use super::{Hook, Hooks};
+use crate::{
+    SystemContext, UseContext, UseEventHandler, UseState,
+    input::{
+        ClickTracker, EventOptions, EventPriority, EventResult, EventScope, Hover, MouseClick,
+    },
+};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::hooks::Hooks<'_, '_> {}
+}
+
+pub trait UseHover: private::Sealed {
+    // 返回指针当前在本组件区域内的悬停信息；区域外、失焦或尚未有鼠标事件时为 `None`。
+    //
+    // 悬停变化会触发重渲。handler 以 `High` 优先级旁观、永远 `Ignored`，不影响其它 handler。
+    fn use_hover(&mut self) -> Option<Hover>;
+}
+
+pub trait UseClick: private::Sealed {
+    // 注册点击回调：鼠标在本组件区域内按下时调用，双击由 [`MouseClick::kind`] 区分。
+    // 闭包返回 [`EventResult::Consumed`] 截断后续 handler。
+    fn use_click<F>(&mut self, f: F)
+    where
+        F: FnMut(MouseClick) -> EventResult + 'static;
+}
+
+// 跨帧持有组件 area,供 handler 在分发时换算命中。
+struct UseHoverImpl {
+    area: Rc<Cell<Rect>>,
+}
+
+impl Hook for UseHoverImpl {
+    fn pre_component_draw(&mut self, drawer: &mut crate::ComponentDrawer) {
+        self.area.set(drawer.area);
+    }
+}
+
+// 跨帧持有组件 area 与双击识别状态。
+struct UseClickImpl {
+    area: Rc<Cell<Rect>>,
+    tracker: Rc<RefCell<ClickTracker>>,
+}
+
+impl Hook for UseClickImpl {
+    fn pre_component_draw(&mut self, drawer: &mut crate::ComponentDrawer) {
+        self.area.set(drawer.area);
+    }
+}
+
+impl UseHover for Hooks<'_, '_> {
+    fn use_hover(&mut self) -> Option<Hover> {
+        let area = self
+            .use_hook(|| UseHoverImpl {
+                area: Rc::new(Cell::new(Rect::default())),
+            })
+            .area
+            .clone();
+        let mut hover = self.use_state(|| None::<Hover>);
+
+        self.use_event_handler(EventScope::Current, EventPriority::High, move |event| {
+            let next = match event {
+                Event::Mouse(mouse) => {
+                    let area = area.get();
+                    let position = Position::new(mouse.column, mouse.row);
+                    area.contains(position).then_some(Hover { position, area })
+                }
+                Event::FocusLost => None,
+                _ => return EventResult::Ignored,
+            };
+            // 仅在变化时写入,避免指针静止时的无谓重渲。
+            if *hover.read() != next {
+                hover.set(next);
+            }
+            EventResult::Ignored
+        });
+
+        hover.get()
+    }
+}
+
+impl UseClick for Hooks<'_, '_> {
+    fn use_click<F>(&mut self, mut f: F)
+    where
+        F: FnMut(MouseClick) -> EventResult + 'static,
+    {
+        let (area, tracker) = {
+            let hook = self.use_hook(|| UseClickImpl {
+                area: Rc::new(Cell::new(Rect::default())),
+                tracker: Rc::default(),
+            });
+            (hook.area.clone(), hook.tracker.clone())
+        };
+        let clock = self.use_context::<SystemContext>().clock().clone();
+
+        self.use_event_handler_with_options(
+            EventScope::Current,
+            EventPriority::Normal,
+            EventOptions { hit_test: true },
+            move |event| {
+                let Event::Mouse(mouse) = event else {
+                    return EventResult::Ignored;
+                };
+                let MouseEventKind::Down(button) = mouse.kind else {
+                    return EventResult::Ignored;
+                };
+                let position = Position::new(mouse.column, mouse.row);
+                let kind = tracker.borrow_mut().press(position, button, clock.now());
+                f(MouseClick {
+                    position,
+                    area: area.get(),
+                    button,
+                    modifiers: mouse.modifiers,
+                    kind,
+                })
+            },
+        );
+    }
+}
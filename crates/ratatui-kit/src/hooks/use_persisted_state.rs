@@ -0,0 +1,58 @@
+// 持久化状态（`serde` 特性）：与 `use_state` 相同，但值以 JSON 保存在持久化目录下的
+// `<key>.json`，下次启动时恢复。读写规则见 `persist` 模块。
+
+use std::sync::Arc;
+
+use serde::{Serialize, de::DeserializeOwned};
+
+use crate::{
+    Hook, Hooks, SingleWaker, State, UseState,
+    persist::{Persisted, load},
+};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::Hooks<'_, '_> {}
+}
+
+// 文件格式版本。hook 没有版本参数：结构不兼容的旧文件反序列化失败，同样回退到初始值。
+const VERSION: u32 = 0;
+
+pub trait UsePersistedState: private::Sealed {
+    // 首帧从 `key` 对应的文件恢复，文件缺失或无法解析时用 `init()`；之后的写入防抖落盘，
+    // 组件卸载时立即写回。`key` 只在首帧读取。
+    fn use_persisted_state<T, F>(&mut self, key: &str, init: F) -> State<T>
+    where
+        F: FnOnce() -> T,
+        T: Serialize + DeserializeOwned + Unpin + Send + Sync + 'static;
+}
+
+struct UsePersistedStateImpl<T>
+where
+    T: Serialize + Send + Sync + 'static,
+{
+    persisted: Arc<Persisted<T, SingleWaker>>,
+}
+
+impl<T> Hook for UsePersistedStateImpl<T>
+where
+    T: Serialize + Send + Sync + 'static,
+{
+    fn on_drop(&mut self) {
+        let _ = self.persisted.save_now();
+    }
+}
+
+impl UsePersistedState for Hooks<'_, '_> {
+    fn use_persisted_state<T, F>(&mut self, key: &str, init: F) -> State<T>
+    where
+        F: FnOnce() -> T,
+        T: Serialize + DeserializeOwned + Unpin + Send + Sync + 'static,
+    {
+        let state = self.use_state(|| load(key, VERSION).unwrap_or_else(init));
+        self.use_hook(|| UsePersistedStateImpl {
+            persisted: Persisted::attach(key.to_owned(), VERSION, state),
+        });
+        state
+    }
+}
@@ -0,0 +1,347 @@
+// 归约器状态 hook：把屏幕级的多个相互依赖的状态收拢为一个状态 + 类型化的动作。
+//
+// `dispatch(action)` 依次经过中间件（日志、撤销快照等），最终由归约器就地修改状态；
+// 修改经 `State` 的写守卫完成，与 `use_state` 一样唤醒组件重渲。
+
+use std::{any::Any, cell::RefCell, collections::VecDeque, sync::Arc};
+
+use generational_box::{GenerationalBox, Owner, SyncStorage};
+
+use super::{Hook, Hooks};
+use crate::State;
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::hooks::Hooks<'_, '_> {}
+}
+
+// 中间件：包在归约器外层，决定是否、以及用什么动作调用 `next`（可调用零次或多次）。
+// 可在 `next` 前后读取 `state`，例如记录日志或保存撤销快照。闭包
+// `Fn(State<S>, A, &dyn Fn(A))` 自动实现本 trait。
+pub trait Middleware<S, A>: Send + Sync + 'static
+where
+    S: Send + Sync + 'static,
+{
+    fn handle(&self, state: State<S>, action: A, next: &dyn Fn(A));
+}
+
+impl<S, A, F> Middleware<S, A> for F
+where
+    S: Send + Sync + 'static,
+    F: Fn(State<S>, A, &dyn Fn(A)) + Send + Sync + 'static,
+{
+    fn handle(&self, state: State<S>, action: A, next: &dyn Fn(A)) {
+        self(state, action, next)
+    }
+}
+
+type DispatchFn<A> = Arc<dyn Fn(A) + Send + Sync>;
+type ReducerFn<S, A> = Box<dyn Fn(&mut S, A) + Send + Sync>;
+// 本线程正在处理动作的 store（按地址）及处理期间再次派发、待处理的动作。
+type DispatchingStores = Vec<(usize, VecDeque<Box<dyn Any>>)>;
+
+// 派发动作的句柄。`Copy`，可移入事件 handler 或发送到异步任务 / 其他线程。
+pub struct Dispatch<A: 'static> {
+    inner: GenerationalBox<DispatchFn<A>, SyncStorage>,
+}
+
+impl<A> Clone for Dispatch<A> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<A> Copy for Dispatch<A> {}
+
+impl<A> Dispatch<A> {
+    // 派发一个动作。组件卸载后派发为空操作。在中间件或归约器内部再次派发的动作排队，
+    // 待当前动作处理完后依次处理。
+    pub fn dispatch(&self, action: A) {
+        let dispatch = self.inner.try_read().ok().map(|inner| inner.clone());
+        if let Some(dispatch) = dispatch {
+            dispatch(action);
+        }
+    }
+}
+
+struct Store<S, A>
+where
+    S: Send + Sync + 'static,
+    A: 'static,
+{
+    state: State<S>,
+    reducer: ReducerFn<S, A>,
+    middleware: Vec<Box<dyn Middleware<S, A>>>,
+}
+
+impl<S, A> Store<S, A>
+where
+    S: Send + Sync + 'static,
+    A: 'static,
+{
+    // 从第 `index` 个中间件开始处理动作，中间件都调用过 `next` 后交给归约器。
+    fn run(&self, index: usize, action: A) {
+        match self.middleware.get(index) {
+            Some(middleware) => {
+                middleware.handle(self.state, action, &|action| self.run(index + 1, action))
+            }
+            None => {
+                if let Some(mut state) = self.state.try_write() {
+                    (self.reducer)(&mut state, action);
+                }
+            }
+        }
+    }
+}
+
+thread_local! {
+    static DISPATCHING: RefCell<DispatchingStores> = const { RefCell::new(Vec::new()) };
+}
+
+// 处理结束（含 panic）时把 store 移出 `DISPATCHING`，丢弃未处理的动作。
+struct Dispatching(usize);
+
+impl Drop for Dispatching {
+    fn drop(&mut self) {
+        DISPATCHING.with_borrow_mut(|stores| stores.retain(|(store, _)| *store != self.0));
+    }
+}
+
+// 以 `run` 处理地址为 `id` 的 store 的一个动作。本线程已在处理该 store 的动作时（中间件或
+// 归约器里再次派发）只入队，由外层处理完当前动作后依次取出：此时状态可能正被写入，
+// 直接处理会在写锁上死锁。`use_reducer` 与全局 `Store` 共用。
+pub(crate) fn dispatch_queued<A: 'static>(id: usize, action: A, run: impl Fn(A)) {
+    let action = DISPATCHING.with_borrow_mut(|stores| {
+        match stores.iter_mut().find(|(store, _)| *store == id) {
+            Some((_, queue)) => {
+                queue.push_back(Box::new(action));
+                None
+            }
+            None => {
+                stores.push((id, VecDeque::new()));
+                Some(action)
+            }
+        }
+    });
+    let Some(mut action) = action else {
+        return;
+    };
+    let _dispatching = Dispatching(id);
+    loop {
+        run(action);
+        let queued = DISPATCHING.with_borrow_mut(|stores| {
+            stores
+                .iter_mut()
+                .find(|(store, _)| *store == id)
+                .and_then(|(_, queue)| queue.pop_front())
+        });
+        match queued.and_then(|queued| queued.downcast::<A>().ok()) {
+            Some(queued) => action = *queued,
+            None => break,
+        }
+    }
+}
+
+pub trait UseReducer: private::Sealed {
+    // 创建由 `reducer` 驱动的状态，返回状态与派发句柄。`init` 只在首帧调用；
+    // `reducer` 同样只在首帧登记，应为只依赖 `(state, action)` 的纯逻辑。
+    fn use_reducer<S, A, R, I>(&mut self, reducer: R, init: I) -> (State<S>, Dispatch<A>)
+    where
+        S: Unpin + Send + Sync + 'static,
+        A: 'static,
+        R: Fn(&mut S, A) + Send + Sync + 'static,
+        I: FnOnce() -> S;
+
+    // 同 `use_reducer`，动作按顺序经过 `middleware`（首个最外层）后到达归约器。
+    // 中间件与归约器一样只在首帧登记。
+    fn use_reducer_with_middleware<S, A, R, I>(
+        &mut self,
+        reducer: R,
+        init: I,
+        middleware: Vec<Box<dyn Middleware<S, A>>>,
+    ) -> (State<S>, Dispatch<A>)
+    where
+        S: Unpin + Send + Sync + 'static,
+        A: 'static,
+        R: Fn(&mut S, A) + Send + Sync + 'static,
+        I: FnOnce() -> S;
+}
+
+struct UseReducerImpl<S, A>
+where
+    S: Send + Sync + 'static,
+    A: 'static,
+{
+    state: State<S>,
+    dispatch: Dispatch<A>,
+    _storage: Owner<SyncStorage>,
+}
+
+impl<S, A> UseReducerImpl<S, A>
+where
+    S: Unpin + Send + Sync + 'static,
+    A: 'static,
+{
+    fn new<R>(reducer: R, initial_value: S, middleware: Vec<Box<dyn Middleware<S, A>>>) -> Self
+    where
+        R: Fn(&mut S, A) + Send + Sync + 'static,
+    {
+        let storage = Owner::default();
+        let state = State::new_in(&storage, initial_value);
+        let store = Store {
+            state,
+            reducer: Box::new(reducer),
+            middleware,
+        };
+        let dispatch: DispatchFn<A> = Arc::new(move |action| {
+            dispatch_queued(&store as *const _ as usize, action, |action| {
+                store.run(0, action)
+            })
+        });
+        UseReducerImpl {
+            state,
+            dispatch: Dispatch {
+                inner: storage.insert(dispatch),
+            },
+            _storage: storage,
+        }
+    }
+}
+
+impl<S, A> Hook for UseReducerImpl<S, A>
+where
+    S: Unpin + Send + Sync + 'static,
+    A: 'static,
+{
+    fn poll_change(&mut self, cx: &mut std::task::Context) -> std::task::Poll<()> {
+        self.state.poll_change(None, cx)
+    }
+}
+
+impl UseReducer for Hooks<'_, '_> {
+    fn use_reducer<S, A, R, I>(&mut self, reducer: R, init: I) -> (State<S>, Dispatch<A>)
+    where
+        S: Unpin + Send + Sync + 'static,
+        A: 'static,
+        R: Fn(&mut S, A) + Send + Sync + 'static,
+        I: FnOnce() -> S,
+    {
+        self.use_reducer_with_middleware(reducer, init, Vec::new())
+    }
+
+    fn use_reducer_with_middleware<S, A, R, I>(
+        &mut self,
+        reducer: R,
+        init: I,
+        middleware: Vec<Box<dyn Middleware<S, A>>>,
+    ) -> (State<S>, Dispatch<A>)
+    where
+        S: Unpin + Send + Sync + 'static,
+        A: 'static,
+        R: Fn(&mut S, A) + Send + Sync + 'static,
+        I: FnOnce() -> S,
+    {
+        let hook = self.use_hook(move || UseReducerImpl::new(reducer, init(), middleware));
+        (hook.state, hook.dispatch)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    enum Action {
+        Add(i32),
+        Reset,
+    }
+
+    fn counter(state: &mut i32, action: Action) {
+        match action {
+            Action::Add(n) => *state += n,
+            Action::Reset => *state = 0,
+        }
+    }
+
+    #[test]
+    fn dispatch_runs_reducer() {
+        let holder = UseReducerImpl::new(counter, 1, Vec::new());
+        let (state, dispatch) = (holder.state, holder.dispatch);
+        dispatch.dispatch(Action::Add(4));
+        assert_eq!(state.get(), 5);
+        dispatch.dispatch(Action::Reset);
+        assert_eq!(state.get(), 0);
+    }
+
+    #[test]
+    fn middleware_wraps_in_order_and_can_capture_undo() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let undo = Arc::new(Mutex::new(Vec::new()));
+        let outer = {
+            let log = log.clone();
+            move |state: State<i32>, action: Action, next: &dyn Fn(Action)| {
+                log.lock().unwrap().push(format!("outer {}", state.get()));
+                next(action);
+                log.lock()
+                    .unwrap()
+                    .push(format!("outer done {}", state.get()));
+            }
+        };
+        let history = {
+            let (log, undo) = (log.clone(), undo.clone());
+            move |state: State<i32>, action: Action, next: &dyn Fn(Action)| {
+                log.lock().unwrap().push("history".to_string());
+                undo.lock().unwrap().push(state.get());
+                next(action);
+            }
+        };
+        let holder = UseReducerImpl::new(counter, 0, vec![Box::new(outer), Box::new(history)]);
+
+        holder.dispatch.dispatch(Action::Add(2));
+        holder.dispatch.dispatch(Action::Add(3));
+
+        assert_eq!(holder.state.get(), 5);
+        assert_eq!(*undo.lock().unwrap(), [0, 2]);
+        assert_eq!(
+            log.lock().unwrap()[..3],
+            ["outer 0", "history", "outer done 2"]
+        );
+    }
+
+    #[test]
+    fn middleware_can_swallow_actions() {
+        let block_reset = |_: State<i32>, action: Action, next: &dyn Fn(Action)| {
+            if !matches!(action, Action::Reset) {
+                next(action);
+            }
+        };
+        let holder = UseReducerImpl::new(counter, 7, vec![Box::new(block_reset)]);
+        holder.dispatch.dispatch(Action::Reset);
+        assert_eq!(holder.state.get(), 7);
+    }
+
+    #[test]
+    fn dispatch_inside_reducer_is_queued() {
+        thread_local! {
+            static SELF: RefCell<Option<Dispatch<Action>>> = const { RefCell::new(None) };
+        }
+        let reducer = |state: &mut i32, action: Action| {
+            if matches!(action, Action::Reset) {
+                SELF.with_borrow(|dispatch| dispatch.unwrap().dispatch(Action::Add(1)));
+            }
+            counter(state, action);
+        };
+        let holder = UseReducerImpl::new(reducer, 5, Vec::new());
+        SELF.set(Some(holder.dispatch));
+        holder.dispatch.dispatch(Action::Reset);
+        assert_eq!(holder.state.get(), 1);
+    }
+
+    #[test]
+    fn dispatch_after_unmount_is_noop() {
+        let holder = UseReducerImpl::new(counter, 0, Vec::new());
+        let dispatch = holder.dispatch;
+        drop(holder);
+        dispatch.dispatch(Action::Add(1));
+    }
+}
@@ -0,0 +1,137 @@
+// 非响应式的组件实例存储：`use_ref` 提供跨帧保留、写入不触发重渲的可变槽位
+// （缓存解析器、记录上次看到的值等），`use_previous` 返回上一次渲染时的值。
+
+use generational_box::{GenerationalBox, Owner, UnsyncStorage};
+
+use crate::{Hook, Hooks};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::Hooks<'_, '_> {}
+}
+
+// `use_ref` 返回的句柄。`Copy`，可移入事件 handler；随组件卸载失效，之后访问会 panic。
+// 写入不通知渲染循环：需要界面随之更新的值应放进 `State`。
+pub struct RefHandle<T: 'static> {
+    inner: GenerationalBox<T, UnsyncStorage>,
+}
+
+impl<T> Clone for RefHandle<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for RefHandle<T> {}
+
+impl<T> RefHandle<T> {
+    // 以只读引用访问值。
+    pub fn with<R>(&self, f: impl FnOnce(&T) -> R) -> R {
+        f(&self
+            .inner
+            .try_read()
+            .expect("attempt to read a ref after its component unmounted or while it is borrowed"))
+    }
+
+    // 以可变引用访问值。
+    pub fn with_mut<R>(&self, f: impl FnOnce(&mut T) -> R) -> R {
+        f(&mut self
+            .inner
+            .try_write()
+            .expect("attempt to write a ref after its component unmounted or while it is borrowed"))
+    }
+
+    pub fn set(&self, value: T) {
+        self.with_mut(|current| *current = value);
+    }
+
+    // 写入新值并返回旧值。
+    pub fn replace(&self, value: T) -> T {
+        self.with_mut(|current| std::mem::replace(current, value))
+    }
+}
+
+impl<T: Clone> RefHandle<T> {
+    pub fn get(&self) -> T {
+        self.with(T::clone)
+    }
+}
+
+impl<T: Default> RefHandle<T> {
+    // 取出值，原处留下 `T::default()`。
+    pub fn take(&self) -> T {
+        self.with_mut(std::mem::take)
+    }
+}
+
+pub trait UseRef: private::Sealed {
+    // 组件实例私有的可变槽位，首帧以 `T::default()` 初始化。
+    fn use_ref<T>(&mut self) -> RefHandle<T>
+    where
+        T: Default + 'static;
+
+    // 同 `use_ref`，首帧以 `init()` 初始化。
+    fn use_ref_with<T, F>(&mut self, init: F) -> RefHandle<T>
+    where
+        T: 'static,
+        F: FnOnce() -> T;
+}
+
+pub trait UsePrevious: private::Sealed {
+    // 返回上一次渲染时传入的 `value`，首帧为 `None`。
+    fn use_previous<T>(&mut self, value: T) -> Option<T>
+    where
+        T: Unpin + 'static;
+}
+
+struct UseRefImpl<T: 'static> {
+    handle: RefHandle<T>,
+    _storage: Owner<UnsyncStorage>,
+}
+
+// 值存放在 generational-box 中，hook 本身从不被 pin 住访问，故对任意 `T` 都可移动。
+impl<T> Unpin for UseRefImpl<T> {}
+
+impl<T> Hook for UseRefImpl<T> {}
+
+struct UsePreviousImpl<T> {
+    current: Option<T>,
+}
+
+impl<T: Unpin> Hook for UsePreviousImpl<T> {}
+
+impl UseRef for Hooks<'_, '_> {
+    fn use_ref<T>(&mut self) -> RefHandle<T>
+    where
+        T: Default + 'static,
+    {
+        self.use_ref_with(T::default)
+    }
+
+    fn use_ref_with<T, F>(&mut self, init: F) -> RefHandle<T>
+    where
+        T: 'static,
+        F: FnOnce() -> T,
+    {
+        self.use_hook(move || {
+            let storage = Owner::default();
+            UseRefImpl {
+                handle: RefHandle {
+                    inner: storage.insert(init()),
+                },
+                _storage: storage,
+            }
+        })
+        .handle
+    }
+}
+
+impl UsePrevious for Hooks<'_, '_> {
+    fn use_previous<T>(&mut self, value: T) -> Option<T>
+    where
+        T: Unpin + 'static,
+    {
+        let hook = self.use_hook(|| UsePreviousImpl { current: None });
+        hook.current.replace(value)
+    }
+}
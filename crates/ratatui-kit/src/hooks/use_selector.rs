@@ -0,0 +1,123 @@
+// 选择器订阅：`use_atom_selector` 与 `use_context_selector` 共用的 hook。
+//
+// 订阅一个 `ReactiveHandle<T, WakerMap>`，每帧返回 `selector` 作用于当前值的结果；值变更时在
+// `poll_change` 中重新选取，结果与上一帧不同（`PartialEq`）才请求重渲。
+
+use std::task::Poll;
+
+use super::{Hook, Hooks};
+use crate::{ElementKey, ReactiveHandle, WakerMap};
+
+struct UseSelectorImpl<T, U>
+where
+    T: Unpin + Send + Sync + 'static,
+{
+    state: ReactiveHandle<T, WakerMap>,
+    selector: Box<dyn Fn(&T) -> U>,
+    // 上一帧返回的选取结果。
+    selected: Option<U>,
+    key: Option<ElementKey>,
+}
+
+impl<T, U> Hook for UseSelectorImpl<T, U>
+where
+    T: Unpin + Send + Sync + 'static,
+    U: PartialEq + Unpin,
+{
+    fn poll_change(&mut self, cx: &mut std::task::Context) -> Poll<()> {
+        let Some(key) = &self.key else {
+            return Poll::Pending;
+        };
+        // 值已释放（如上下文提供者卸载）：本组件随之卸载，无需再订阅。
+        let Some(value) = self.state.try_read() else {
+            return Poll::Pending;
+        };
+        // 只订阅不消费变更标记：同一值的其它订阅者仍各自判断。
+        if self.selected.as_ref() != Some(&(self.selector)(&value)) {
+            return Poll::Ready(());
+        }
+        drop(value);
+        self.state.subscribe(key, cx.waker());
+        Poll::Pending
+    }
+
+    fn post_component_update(&mut self, updater: &mut crate::ComponentUpdater) {
+        if self.key.is_none() {
+            self.key = Some(updater.key().clone());
+        }
+    }
+
+    fn on_drop(&mut self) {
+        if let Some(key) = &self.key {
+            self.state.remove_waker(key);
+        }
+    }
+}
+
+// 订阅 `state` 经 `selector` 选取的部分。`state` 换了（换了原子 / 提供者）时退订旧值。
+pub(crate) fn use_handle_selector<T, U, F>(
+    hooks: &mut Hooks,
+    state: ReactiveHandle<T, WakerMap>,
+    selector: F,
+) -> U
+where
+    T: Unpin + Send + Sync + 'static,
+    U: PartialEq + Clone + Unpin + 'static,
+    F: Fn(&T) -> U + 'static,
+{
+    let mut selector = Some(selector);
+    let hook = hooks.use_hook(|| UseSelectorImpl {
+        state,
+        selector: Box::new(selector.take().expect("selector is set")),
+        selected: None,
+        key: None,
+    });
+    // 每帧换上最新的 selector，使其捕获的 props 保持最新。
+    if let Some(selector) = selector {
+        hook.selector = Box::new(selector);
+    }
+    if !hook.state.same_storage(&state) {
+        if let Some(key) = &hook.key {
+            hook.state.remove_waker(key);
+        }
+        hook.state = state;
+    }
+    let selected = (hook.selector)(&state.read());
+    hook.selected = Some(selected.clone());
+    selected
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::task::noop_waker;
+    use generational_box::Owner;
+    use std::task::Context;
+
+    #[test]
+    fn selector_wakes_only_when_selection_changes() {
+        let storage = Owner::default();
+        let mut state = ReactiveHandle::<_, WakerMap>::new_in(&storage, (1, "a"));
+        let key = ElementKey::decl(3);
+        let mut hook = UseSelectorImpl {
+            state,
+            selector: Box::new(|value: &(i32, &str)| value.0),
+            selected: Some(1),
+            key: Some(key.clone()),
+        };
+        let waker = noop_waker();
+        let mut cx = Context::from_waker(&waker);
+
+        assert!(hook.poll_change(&mut cx).is_pending());
+        assert!(state.has_waker(&key));
+
+        state.set((1, "b"));
+        assert!(hook.poll_change(&mut cx).is_pending());
+
+        state.set((2, "b"));
+        assert!(hook.poll_change(&mut cx).is_ready());
+
+        hook.on_drop();
+        assert!(!state.has_waker(&key));
+    }
+}
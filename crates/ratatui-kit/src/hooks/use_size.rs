@@ -5,7 +5,7 @@ use ratatui::layout::Rect;
 
 use crate::{
     Hook, State, SystemContext, UseState,
-    input::{EventOptions, EventPriority, EventResult},
+    input::{EventOptions, EventPriority, EventResult, HandlerKey},
 };
 
 mod private {
@@ -58,6 +58,7 @@ impl Hook for UseTerminalSizeImpl {
             None,
             EventPriority::Normal,
             EventOptions::default(),
+            HandlerKey::next(),
             Rc::new(Cell::new(Rect::default())),
             Box::new(move |event| {
                 if let Event::Resize(width, height) = event {
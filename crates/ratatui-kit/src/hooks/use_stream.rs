@@ -0,0 +1,294 @@
+// 流与通道 hook。
+//
+// `use_stream` 在挂载时调用工厂创建一个 `Stream`，由本组件在 `poll_change` 中轮询，收到新条目即
+// 请求重渲；`use_channel` 在其上包了一层 `futures` 的无界通道，返回可跨线程发送的 `ChannelSender`。
+// 两者都不依赖 tokio 等执行器，组件卸载时丢弃流 / 接收端。
+//
+// ## 示例
+// ```rust
+// let (progress, latest) = hooks.use_channel::<u16>();
+// hooks.use_effect(
+//     move || {
+//         std::thread::spawn(move || {
+//             for percent in 0..=100 {
+//                 progress.send(percent);
+//             }
+//         });
+//     },
+//     (),
+// );
+// let percent = latest.unwrap_or(0);
+//
+// let lines = hooks.use_stream_buffer(|| tail_log("app.log"), 200);
+// ```
+
+use std::{collections::VecDeque, task::Poll};
+
+use futures::{
+    Stream, StreamExt,
+    channel::mpsc::{self, UnboundedSender},
+    stream::LocalBoxStream,
+};
+
+use super::{Hook, Hooks};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::Hooks<'_, '_> {}
+}
+
+// 单次 `poll_change` 最多取出的条目数。始终就绪的流取满后让出，留待下一轮继续，避免卡住渲染循环。
+const MAX_ITEMS_PER_POLL: usize = 1024;
+
+pub trait UseStream: private::Sealed {
+    // 挂载时调用 `factory` 创建流，返回最近收到的条目；尚未收到时为 `None`。
+    // 流结束后保留最后一项。`factory` 只在首帧调用。
+    fn use_stream<F, S, T>(&mut self, factory: F) -> Option<T>
+    where
+        F: FnOnce() -> S,
+        S: Stream<Item = T> + 'static,
+        T: Clone + Unpin + 'static;
+
+    // 同 `use_stream`，但按到达顺序累积条目，最多保留最近的 `cap` 项。
+    fn use_stream_buffer<F, S, T>(&mut self, factory: F, cap: usize) -> Vec<T>
+    where
+        F: FnOnce() -> S,
+        S: Stream<Item = T> + 'static,
+        T: Clone + Unpin + 'static;
+}
+
+pub trait UseChannel: private::Sealed {
+    // 创建一个随组件存活的通道：返回发送端与最近收到的值。发送端可 `clone` 并移入其它线程。
+    fn use_channel<T>(&mut self) -> (ChannelSender<T>, Option<T>)
+    where
+        T: Clone + Send + Unpin + 'static;
+
+    // 同 `use_channel`，但累积收到的值，最多保留最近的 `cap` 项。
+    fn use_channel_buffer<T>(&mut self, cap: usize) -> (ChannelSender<T>, Vec<T>)
+    where
+        T: Clone + Send + Unpin + 'static;
+}
+
+// `use_channel` 返回的发送端，`Send + Sync`，可在任意线程调用。
+pub struct ChannelSender<T> {
+    sender: UnboundedSender<T>,
+}
+
+impl<T> Clone for ChannelSender<T> {
+    fn clone(&self) -> Self {
+        Self {
+            sender: self.sender.clone(),
+        }
+    }
+}
+
+impl<T> ChannelSender<T> {
+    // 发送一个值并唤醒接收组件。组件已卸载时值被丢弃，返回 `false`。
+    pub fn send(&self, value: T) -> bool {
+        self.sender.unbounded_send(value).is_ok()
+    }
+
+    // 接收组件已卸载。
+    pub fn is_closed(&self) -> bool {
+        self.sender.is_closed()
+    }
+}
+
+struct UseStreamImpl<T> {
+    // 流结束后置为 `None`。
+    stream: Option<LocalBoxStream<'static, T>>,
+    items: VecDeque<T>,
+    cap: usize,
+}
+
+impl<T> UseStreamImpl<T> {
+    fn new(stream: LocalBoxStream<'static, T>, cap: usize) -> Self {
+        Self {
+            stream: Some(stream),
+            items: VecDeque::new(),
+            cap: cap.max(1),
+        }
+    }
+
+    // 每帧同步容量，缩小时丢弃最早的条目。
+    fn set_cap(&mut self, cap: usize) {
+        self.cap = cap.max(1);
+        while self.items.len() > self.cap {
+            self.items.pop_front();
+        }
+    }
+
+    fn latest(&self) -> Option<T>
+    where
+        T: Clone,
+    {
+        self.items.back().cloned()
+    }
+
+    fn buffer(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.items.iter().cloned().collect()
+    }
+}
+
+impl<T: Unpin> Hook for UseStreamImpl<T> {
+    fn poll_change(&mut self, cx: &mut std::task::Context) -> Poll<()> {
+        let Some(stream) = self.stream.as_mut() else {
+            return Poll::Pending;
+        };
+        let mut received = false;
+        let mut taken = 0;
+        loop {
+            if taken == MAX_ITEMS_PER_POLL {
+                cx.waker().wake_by_ref();
+                break;
+            }
+            match stream.poll_next_unpin(cx) {
+                Poll::Ready(Some(item)) => {
+                    if self.items.len() == self.cap {
+                        self.items.pop_front();
+                    }
+                    self.items.push_back(item);
+                    received = true;
+                    taken += 1;
+                }
+                Poll::Ready(None) => {
+                    self.stream = None;
+                    break;
+                }
+                Poll::Pending => break,
+            }
+        }
+        if received {
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+struct UseChannelImpl<T> {
+    sender: ChannelSender<T>,
+    receiver: UseStreamImpl<T>,
+}
+
+impl<T> UseChannelImpl<T>
+where
+    T: 'static,
+{
+    fn new(cap: usize) -> Self {
+        let (sender, receiver) = mpsc::unbounded();
+        Self {
+            sender: ChannelSender { sender },
+            receiver: UseStreamImpl::new(receiver.boxed_local(), cap),
+        }
+    }
+}
+
+impl<T: Unpin> Hook for UseChannelImpl<T> {
+    fn poll_change(&mut self, cx: &mut std::task::Context) -> Poll<()> {
+        self.receiver.poll_change(cx)
+    }
+}
+
+impl UseStream for Hooks<'_, '_> {
+    fn use_stream<F, S, T>(&mut self, factory: F) -> Option<T>
+    where
+        F: FnOnce() -> S,
+        S: Stream<Item = T> + 'static,
+        T: Clone + Unpin + 'static,
+    {
+        self.use_hook(move || UseStreamImpl::new(factory().boxed_local(), 1))
+            .latest()
+    }
+
+    fn use_stream_buffer<F, S, T>(&mut self, factory: F, cap: usize) -> Vec<T>
+    where
+        F: FnOnce() -> S,
+        S: Stream<Item = T> + 'static,
+        T: Clone + Unpin + 'static,
+    {
+        let hook = self.use_hook(move || UseStreamImpl::new(factory().boxed_local(), cap));
+        hook.set_cap(cap);
+        hook.buffer()
+    }
+}
+
+impl UseChannel for Hooks<'_, '_> {
+    fn use_channel<T>(&mut self) -> (ChannelSender<T>, Option<T>)
+    where
+        T: Clone + Send + Unpin + 'static,
+    {
+        let hook = self.use_hook(|| UseChannelImpl::new(1));
+        (hook.sender.clone(), hook.receiver.latest())
+    }
+
+    fn use_channel_buffer<T>(&mut self, cap: usize) -> (ChannelSender<T>, Vec<T>)
+    where
+        T: Clone + Send + Unpin + 'static,
+    {
+        let hook = self.use_hook(|| UseChannelImpl::new(cap));
+        hook.receiver.set_cap(cap);
+        (hook.sender.clone(), hook.receiver.buffer())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::{stream, task::noop_waker};
+    use std::task::Context;
+
+    #[test]
+    fn buffer_keeps_the_latest_items_and_survives_the_end_of_the_stream() {
+        let mut hook = UseStreamImpl::new(stream::iter(1..=5).boxed_local(), 3);
+        let waker = noop_waker();
+        let mut cx = Context::from_waker(&waker);
+
+        assert!(hook.poll_change(&mut cx).is_ready());
+        assert_eq!(hook.buffer(), [3, 4, 5]);
+        assert!(hook.stream.is_none());
+        assert!(hook.poll_change(&mut cx).is_pending());
+        assert_eq!(hook.latest(), Some(5));
+
+        hook.set_cap(1);
+        assert_eq!(hook.buffer(), [5]);
+    }
+
+    #[test]
+    fn endless_ready_stream_yields_between_polls() {
+        let mut hook = UseStreamImpl::new(stream::repeat(7).boxed_local(), 1);
+        let waker = noop_waker();
+        let mut cx = Context::from_waker(&waker);
+
+        assert!(hook.poll_change(&mut cx).is_ready());
+        assert_eq!(hook.latest(), Some(7));
+        assert!(hook.stream.is_some());
+    }
+
+    #[test]
+    fn channel_receives_from_other_threads_until_dropped() {
+        let mut hook = UseChannelImpl::new(10);
+        let waker = noop_waker();
+        let mut cx = Context::from_waker(&waker);
+        assert!(hook.poll_change(&mut cx).is_pending());
+
+        let sender = hook.sender.clone();
+        std::thread::spawn(move || {
+            for value in 0..3 {
+                assert!(sender.send(value));
+            }
+        })
+        .join()
+        .unwrap();
+        assert!(hook.poll_change(&mut cx).is_ready());
+        assert_eq!(hook.receiver.buffer(), [0, 1, 2]);
+
+        let sender = hook.sender.clone();
+        drop(hook);
+        assert!(sender.is_closed());
+        assert!(!sender.send(3));
+    }
+}
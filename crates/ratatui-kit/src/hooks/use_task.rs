@@ -0,0 +1,510 @@
+// 后台任务 hook。
+//
+// `use_future` 只在挂载时运行一个 future，既拿不到句柄也无法再发起新任务。`use_task` 返回一个
+// 可移入事件 handler 的 `TaskSpawner`：`spawn` 出的 future 由本组件在 `poll_change` 中轮询，
+// 不依赖 tokio 等执行器；组件卸载或调用 `abort` 时直接丢弃 future，即取消任务。
+// 任务的运行 / 排队 / 完成计数经 `status()` 读取，变化时请求重渲本组件。
+//
+// ## 示例
+// ```rust
+// let task = hooks.use_task_with(TaskConcurrency::Latest);
+// hooks.use_event_handler(EventScope::Current, EventPriority::Normal, move |event| {
+//     if let Event::Key(key) = event && key.code == KeyCode::Enter {
+//         task.spawn(async move {
+//             let hits = search(query.read().clone()).await;
+//             results.set(hits);
+//         });
+//     }
+//     EventResult::Ignored
+// });
+// let busy = task.status().is_busy();
+// ```
+
+use std::{
+    cell::RefCell,
+    collections::VecDeque,
+    mem,
+    rc::Rc,
+    task::{Poll, Waker},
+};
+
+use futures::{FutureExt, future::LocalBoxFuture};
+
+use super::{Hook, Hooks};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::Hooks<'_, '_> {}
+}
+
+// 并发策略：同时运行的任务数上限及超出时的处理方式。
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TaskConcurrency {
+    // 不限制，每个任务立即开始。
+    #[default]
+    Unlimited,
+    // 只保留最新的任务：`spawn` 先中止所有进行中的任务，适合搜索、预览等只关心最后一次的请求。
+    Latest,
+    // 最多同时运行 `n` 个，其余按发起顺序排队。`Queue(1)` 即串行执行。
+    Queue(usize),
+}
+
+// 任务计数快照。
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TaskStatus {
+    // 正在运行的任务数。
+    pub running: usize,
+    // 排队等待的任务数（仅 `TaskConcurrency::Queue`）。
+    pub queued: usize,
+    // 累计正常完成的任务数。
+    pub completed: u64,
+    // 累计被中止（`abort` 或 `Latest` 取代）的任务数。卸载时丢弃的任务不计入。
+    pub aborted: u64,
+}
+
+impl TaskStatus {
+    // 有任务在运行或排队。
+    pub fn is_busy(&self) -> bool {
+        self.running > 0 || self.queued > 0
+    }
+}
+
+type TaskFuture = LocalBoxFuture<'static, ()>;
+
+struct TaskQueue {
+    concurrency: TaskConcurrency,
+    next_id: u64,
+    running: Vec<(u64, TaskFuture)>,
+    queued: VecDeque<(u64, TaskFuture)>,
+    // 正被 `poll_change` 轮询、暂时移出 `running` 的任务。任务体内调用 `spawn` / `abort`
+    // 时据此判断，被中止的记入 `cancelled`，轮询结束后丢弃。
+    polling: Vec<u64>,
+    cancelled: Vec<u64>,
+    completed: u64,
+    aborted: u64,
+    // 计数有变化，下一次 `poll_change` 请求重渲。
+    changed: bool,
+    waker: Option<Waker>,
+    // 组件已卸载：之后的 `spawn` 直接丢弃。
+    closed: bool,
+}
+
+impl TaskQueue {
+    fn new(concurrency: TaskConcurrency) -> Self {
+        Self {
+            concurrency,
+            next_id: 0,
+            running: Vec::new(),
+            queued: VecDeque::new(),
+            polling: Vec::new(),
+            cancelled: Vec::new(),
+            completed: 0,
+            aborted: 0,
+            changed: false,
+            waker: None,
+            closed: false,
+        }
+    }
+
+    fn active(&self) -> usize {
+        self.running.len() + self.polling.len() - self.cancelled.len()
+    }
+
+    fn contains(&self, id: u64) -> bool {
+        self.running.iter().any(|(task, _)| *task == id)
+            || self.queued.iter().any(|(task, _)| *task == id)
+            || (self.polling.contains(&id) && !self.cancelled.contains(&id))
+    }
+
+    fn status(&self) -> TaskStatus {
+        TaskStatus {
+            running: self.active(),
+            queued: self.queued.len(),
+            completed: self.completed,
+            aborted: self.aborted,
+        }
+    }
+
+    fn notify(&mut self) {
+        self.changed = true;
+        if let Some(waker) = &self.waker {
+            waker.wake_by_ref();
+        }
+    }
+
+    // 以下移出 future 的方法都把它们返回给调用方，在释放借用之后丢弃：future 的析构里也可能
+    // 访问发起器。
+
+    // 返回任务 id 与被丢弃的 future（`Latest` 取代的任务，或组件已卸载时的新任务）。
+    fn spawn(&mut self, future: TaskFuture) -> (u64, Vec<TaskFuture>) {
+        let id = self.next_id;
+        self.next_id += 1;
+        if self.closed {
+            return (id, vec![future]);
+        }
+        let mut dropped = Vec::new();
+        match self.concurrency {
+            TaskConcurrency::Unlimited => self.running.push((id, future)),
+            TaskConcurrency::Latest => {
+                dropped = self.abort_all();
+                self.running.push((id, future));
+            }
+            TaskConcurrency::Queue(limit) => {
+                if self.active() < limit.max(1) {
+                    self.running.push((id, future));
+                } else {
+                    self.queued.push_back((id, future));
+                }
+            }
+        }
+        self.notify();
+        (id, dropped)
+    }
+
+    // 返回是否找到该任务，以及移出的 future（轮询中的任务由 `poll_change` 丢弃，这里为 `None`）。
+    fn abort(&mut self, id: u64) -> (bool, Option<TaskFuture>) {
+        let (found, future) =
+            if let Some(index) = self.running.iter().position(|(task, _)| *task == id) {
+                (true, Some(self.running.remove(index).1))
+            } else if let Some(index) = self.queued.iter().position(|(task, _)| *task == id) {
+                (true, self.queued.remove(index).map(|(_, future)| future))
+            } else if self.polling.contains(&id) && !self.cancelled.contains(&id) {
+                self.cancelled.push(id);
+                (true, None)
+            } else {
+                (false, None)
+            };
+        if found {
+            self.aborted += 1;
+            self.notify();
+        }
+        (found, future)
+    }
+
+    fn abort_all(&mut self) -> Vec<TaskFuture> {
+        let count = self.running.len() + self.queued.len();
+        let dropped = self
+            .running
+            .drain(..)
+            .chain(self.queued.drain(..))
+            .map(|(_, future)| future)
+            .collect();
+        for id in &self.polling {
+            if !self.cancelled.contains(id) {
+                self.cancelled.push(*id);
+                self.aborted += 1;
+            }
+        }
+        self.aborted += count as u64;
+        self.notify();
+        dropped
+    }
+
+    // 按并发上限把排队的任务移入运行。
+    fn promote(&mut self) {
+        let TaskConcurrency::Queue(limit) = self.concurrency else {
+            self.running.extend(self.queued.drain(..));
+            return;
+        };
+        while self.active() < limit.max(1)
+            && let Some(task) = self.queued.pop_front()
+        {
+            self.running.push(task);
+        }
+    }
+}
+
+// `use_task` 返回的任务发起器，可 `clone` 移入事件 handler 或任务自身。
+#[derive(Clone)]
+pub struct TaskSpawner {
+    queue: Rc<RefCell<TaskQueue>>,
+}
+
+impl TaskSpawner {
+    // 发起一个任务，按并发策略立即运行或排队。组件已卸载时任务被直接丢弃。
+    pub fn spawn<F>(&self, future: F) -> TaskHandle
+    where
+        F: Future<Output = ()> + 'static,
+    {
+        let (id, dropped) = self.queue.borrow_mut().spawn(future.boxed_local());
+        drop(dropped);
+        TaskHandle {
+            id,
+            queue: self.queue.clone(),
+        }
+    }
+
+    // 中止所有进行中和排队的任务。
+    pub fn abort(&self) {
+        let dropped = self.queue.borrow_mut().abort_all();
+        drop(dropped);
+    }
+
+    pub fn status(&self) -> TaskStatus {
+        self.queue.borrow().status()
+    }
+
+    pub fn is_busy(&self) -> bool {
+        self.status().is_busy()
+    }
+}
+
+// 单个任务的句柄。丢弃句柄不会取消任务。
+#[derive(Clone)]
+pub struct TaskHandle {
+    id: u64,
+    queue: Rc<RefCell<TaskQueue>>,
+}
+
+impl TaskHandle {
+    // 中止该任务；已结束时返回 `false`。
+    pub fn abort(&self) -> bool {
+        let (found, dropped) = self.queue.borrow_mut().abort(self.id);
+        drop(dropped);
+        found
+    }
+
+    // 已完成、被中止或随组件卸载丢弃。
+    pub fn is_finished(&self) -> bool {
+        !self.queue.borrow().contains(self.id)
+    }
+}
+
+struct UseTaskImpl {
+    queue: Rc<RefCell<TaskQueue>>,
+}
+
+impl Hook for UseTaskImpl {
+    fn poll_change(&mut self, cx: &mut std::task::Context) -> Poll<()> {
+        // 本次已轮询过的任务。轮询期间新发起或因名额空出而开始的任务在下一轮补上。
+        let mut polled = Vec::new();
+        loop {
+            let batch: Vec<(u64, TaskFuture)> = {
+                let mut queue = self.queue.borrow_mut();
+                queue.waker = Some(cx.waker().clone());
+                queue.promote();
+                let (batch, rest) = mem::take(&mut queue.running)
+                    .into_iter()
+                    .partition(|(id, _)| !polled.contains(id));
+                queue.running = rest;
+                queue.polling = batch.iter().map(|(id, _)| *id).collect();
+                batch
+            };
+            if batch.is_empty() {
+                break;
+            }
+            // 轮询时不持有借用，任务体内可以再调用 `spawn` / `abort`。
+            let results: Vec<(u64, Option<TaskFuture>)> = batch
+                .into_iter()
+                .map(|(id, mut future)| {
+                    let pending = future.as_mut().poll(cx).is_pending();
+                    (id, pending.then_some(future))
+                })
+                .collect();
+
+            let mut queue = self.queue.borrow_mut();
+            let cancelled = mem::take(&mut queue.cancelled);
+            queue.polling.clear();
+            let mut dropped = Vec::new();
+            for (id, future) in results {
+                polled.push(id);
+                // 轮询中被中止的任务即使恰好完成也按中止计，计数已在 `abort` 时加过。
+                if cancelled.contains(&id) {
+                    dropped.extend(future);
+                    continue;
+                }
+                match future {
+                    Some(future) => queue.running.push((id, future)),
+                    None => {
+                        queue.completed += 1;
+                        queue.changed = true;
+                    }
+                }
+            }
+            drop(queue);
+            drop(dropped);
+        }
+        if mem::take(&mut self.queue.borrow_mut().changed) {
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    }
+
+    fn on_drop(&mut self) {
+        let mut queue = self.queue.borrow_mut();
+        queue.closed = true;
+        queue.waker = None;
+        // 先移出再丢弃，future 的析构里也可能访问发起器。
+        let running = mem::take(&mut queue.running);
+        let queued = mem::take(&mut queue.queued);
+        drop(queue);
+        drop(running);
+        drop(queued);
+    }
+}
+
+pub trait UseTask: private::Sealed {
+    // 返回不限并发的任务发起器，任务随组件卸载取消。
+    fn use_task(&mut self) -> TaskSpawner;
+
+    // 指定并发策略的 `use_task`。策略可在渲染间改变，只影响之后的调度。
+    fn use_task_with(&mut self, concurrency: TaskConcurrency) -> TaskSpawner;
+}
+
+impl UseTask for Hooks<'_, '_> {
+    fn use_task(&mut self) -> TaskSpawner {
+        self.use_task_with(TaskConcurrency::Unlimited)
+    }
+
+    fn use_task_with(&mut self, concurrency: TaskConcurrency) -> TaskSpawner {
+        let hook = self.use_hook(|| UseTaskImpl {
+            queue: Rc::new(RefCell::new(TaskQueue::new(concurrency))),
+        });
+        hook.queue.borrow_mut().concurrency = concurrency;
+        TaskSpawner {
+            queue: hook.queue.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::{channel::oneshot, task::noop_waker};
+    use std::task::Context;
+
+    fn setup(concurrency: TaskConcurrency) -> (UseTaskImpl, TaskSpawner) {
+        let hook = UseTaskImpl {
+            queue: Rc::new(RefCell::new(TaskQueue::new(concurrency))),
+        };
+        let spawner = TaskSpawner {
+            queue: hook.queue.clone(),
+        };
+        (hook, spawner)
+    }
+
+    fn pending_task(spawner: &TaskSpawner) -> (TaskHandle, oneshot::Sender<()>) {
+        let (tx, rx) = oneshot::channel::<()>();
+        let handle = spawner.spawn(async move {
+            let _ = rx.await;
+        });
+        (handle, tx)
+    }
+
+    #[test]
+    fn queue_limits_concurrency_and_promotes_in_order() {
+        let (mut hook, spawner) = setup(TaskConcurrency::Queue(1));
+        let waker = noop_waker();
+        let mut cx = Context::from_waker(&waker);
+
+        let (first, first_tx) = pending_task(&spawner);
+        let (second, second_tx) = pending_task(&spawner);
+        assert!(hook.poll_change(&mut cx).is_ready());
+        assert_eq!((spawner.status().running, spawner.status().queued), (1, 1));
+
+        first_tx.send(()).unwrap();
+        assert!(hook.poll_change(&mut cx).is_ready());
+        assert!(first.is_finished());
+        assert!(!second.is_finished());
+        assert_eq!(spawner.status().running, 1);
+        assert_eq!(spawner.status().completed, 1);
+
+        second_tx.send(()).unwrap();
+        assert!(hook.poll_change(&mut cx).is_ready());
+        assert!(!spawner.is_busy());
+        assert!(hook.poll_change(&mut cx).is_pending());
+    }
+
+    #[test]
+    fn latest_aborts_the_running_task() {
+        let (mut hook, spawner) = setup(TaskConcurrency::Latest);
+        let waker = noop_waker();
+        let mut cx = Context::from_waker(&waker);
+
+        let (first, first_tx) = pending_task(&spawner);
+        assert!(hook.poll_change(&mut cx).is_ready());
+        let (second, _second_tx) = pending_task(&spawner);
+        assert!(first.is_finished());
+        assert!(first_tx.is_canceled(), "被取代的任务应已丢弃");
+        assert!(!second.is_finished());
+        assert_eq!(spawner.status().aborted, 1);
+
+        assert!(second.abort());
+        assert!(!second.abort());
+        assert_eq!(
+            spawner.status(),
+            TaskStatus {
+                aborted: 2,
+                ..TaskStatus::default()
+            }
+        );
+    }
+
+    #[test]
+    fn tasks_can_spawn_and_abort_from_inside() {
+        let (mut hook, spawner) = setup(TaskConcurrency::Unlimited);
+        let waker = noop_waker();
+        let mut cx = Context::from_waker(&waker);
+
+        let inner = spawner.clone();
+        let (victim, victim_tx) = pending_task(&spawner);
+        spawner.spawn(async move {
+            victim.abort();
+            inner.spawn(async {});
+        });
+        assert!(hook.poll_change(&mut cx).is_ready());
+        assert!(victim_tx.is_canceled());
+        assert_eq!(
+            spawner.status(),
+            TaskStatus {
+                completed: 2,
+                aborted: 1,
+                ..TaskStatus::default()
+            }
+        );
+    }
+
+    // 析构时读取发起器状态的守卫：中止时若仍持有借用会 panic。
+    struct StatusOnDrop(TaskSpawner, Rc<RefCell<Vec<TaskStatus>>>);
+
+    impl Drop for StatusOnDrop {
+        fn drop(&mut self) {
+            self.1.borrow_mut().push(self.0.status());
+        }
+    }
+
+    fn guarded_task(spawner: &TaskSpawner, seen: &Rc<RefCell<Vec<TaskStatus>>>) -> TaskHandle {
+        let guard = StatusOnDrop(spawner.clone(), seen.clone());
+        spawner.spawn(async move {
+            let _guard = guard;
+            futures::future::pending::<()>().await;
+        })
+    }
+
+    #[test]
+    fn aborted_futures_are_dropped_outside_the_borrow() {
+        let (_hook, spawner) = setup(TaskConcurrency::Latest);
+        let seen = Rc::new(RefCell::new(Vec::new()));
+
+        let first = guarded_task(&spawner, &seen);
+        assert!(first.abort());
+        guarded_task(&spawner, &seen);
+        guarded_task(&spawner, &seen);
+        spawner.abort();
+        assert_eq!(seen.borrow().len(), 3);
+        assert_eq!(spawner.status().aborted, 3);
+    }
+
+    #[test]
+    fn unmount_drops_pending_tasks() {
+        let (mut hook, spawner) = setup(TaskConcurrency::Unlimited);
+        let (handle, tx) = pending_task(&spawner);
+        hook.on_drop();
+        assert!(tx.is_canceled());
+        assert!(handle.is_finished());
+
+        let (_, late_tx) = pending_task(&spawner);
+        assert!(late_tx.is_canceled(), "卸载后发起的任务应直接丢弃");
+    }
+}
@@ -0,0 +1,300 @@
+// 计时 hook：间隔、延时、防抖、节流。
+//
+// 由组件树的时间轮（`SystemContext` 持有）驱动，不依赖 tokio 等执行器：到期时唤醒组件，
+// hook 在 `poll_change` 中执行回调，回调里写状态即触发重渲。
+
+use std::{
+    cell::RefCell,
+    rc::Rc,
+    task::{Poll, Waker},
+    time::{Duration, Instant},
+};
+
+use crate::{
+    Clock, Hook, Hooks, SystemContext, UseContext,
+    time::{Timer, TimerHandle},
+};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::Hooks<'_, '_> {}
+}
+
+pub trait UseTimer: private::Sealed {
+    // 组件树的时钟，`now()` 替代 `Instant::now()`：测试装入假时钟时随之推进。
+    fn use_clock(&mut self) -> Clock;
+
+    // 每隔 `period` 调用一次 `f`，首次在挂载 `period` 之后。`period` 变化时从当前时刻重新计时。
+    // 错过的周期（如渲染线程繁忙）不会补调。`period` 为零时暂停，不调用 `f`。
+    fn use_interval<F>(&mut self, period: Duration, f: F)
+    where
+        F: FnMut() + 'static;
+
+    // 挂载 `delay` 之后调用一次 `f`；`delay` 变化时从当前时刻重新计时（已触发的也会再触发一次）。
+    fn use_timeout<F>(&mut self, delay: Duration, f: F)
+    where
+        F: FnOnce() + 'static;
+
+    // 返回 `value` 的防抖值：`value` 停止变化 `delay` 之后才更新，适合搜索框等输入驱动的查询。
+    fn use_debounced_value<T>(&mut self, value: T, delay: Duration) -> T
+    where
+        T: Clone + PartialEq + Unpin + 'static;
+
+    // 返回节流后的回调：每 `interval` 内最多执行一次 `f`，期间的调用只保留最后一次，
+    // 在窗口结束时补执行。
+    fn use_throttled_callback<A, F>(&mut self, interval: Duration, f: F) -> Throttled<A>
+    where
+        A: 'static,
+        F: FnMut(A) + 'static;
+}
+
+fn timer_handle(hooks: &mut Hooks) -> TimerHandle {
+    hooks.use_context::<SystemContext>().timers.handle()
+}
+
+struct UseIntervalImpl {
+    timer: Timer,
+    period: Duration,
+    next: Instant,
+    callback: Box<dyn FnMut()>,
+}
+
+impl Hook for UseIntervalImpl {
+    fn poll_change(&mut self, cx: &mut std::task::Context) -> Poll<()> {
+        if self.period.is_zero() {
+            self.timer.cancel();
+            return Poll::Pending;
+        }
+        while self.timer.poll_deadline(self.next, cx) {
+            (self.callback)();
+            self.next += self.period;
+            let now = self.timer.now();
+            if self.next <= now {
+                self.next = now + self.period;
+            }
+        }
+        Poll::Pending
+    }
+}
+
+struct UseTimeoutImpl {
+    timer: Timer,
+    delay: Duration,
+    deadline: Option<Instant>,
+    callback: Option<Box<dyn FnOnce()>>,
+}
+
+impl Hook for UseTimeoutImpl {
+    fn poll_change(&mut self, cx: &mut std::task::Context) -> Poll<()> {
+        if let Some(deadline) = self.deadline
+            && self.timer.poll_deadline(deadline, cx)
+        {
+            self.deadline = None;
+            if let Some(callback) = self.callback.take() {
+                callback();
+            }
+        }
+        Poll::Pending
+    }
+}
+
+struct UseDebouncedValueImpl<T> {
+    timer: Timer,
+    current: T,
+    latest: T,
+    deadline: Option<Instant>,
+}
+
+impl<T: Clone + PartialEq + Unpin> Hook for UseDebouncedValueImpl<T> {
+    fn poll_change(&mut self, cx: &mut std::task::Context) -> Poll<()> {
+        if let Some(deadline) = self.deadline
+            && self.timer.poll_deadline(deadline, cx)
+        {
+            self.deadline = None;
+            if self.current != self.latest {
+                self.current = self.latest.clone();
+                return Poll::Ready(());
+            }
+        }
+        Poll::Pending
+    }
+}
+
+struct ThrottleState<A> {
+    interval: Duration,
+    // 执行期间被取出，见 `run_throttled`。
+    callback: Option<Box<dyn FnMut(A)>>,
+    last_run: Option<Instant>,
+    trailing: Option<A>,
+    waker: Option<Waker>,
+}
+
+// `use_throttled_callback` 返回的回调，可 `clone` 移入事件 handler。
+pub struct Throttled<A> {
+    state: Rc<RefCell<ThrottleState<A>>>,
+    timers: TimerHandle,
+}
+
+impl<A> Clone for Throttled<A> {
+    fn clone(&self) -> Self {
+        Self {
+            state: self.state.clone(),
+            timers: self.timers.clone(),
+        }
+    }
+}
+
+impl<A> Throttled<A> {
+    // 窗口外立即执行；窗口内只记下参数，由 hook 在窗口结束时补执行最后一次。
+    // 回调正在执行时（回调里再次调用）也按窗口内处理。
+    pub fn call(&self, arg: A) {
+        let now = self.timers.now();
+        let mut state = self.state.borrow_mut();
+        let ready = state.callback.is_some()
+            && state
+                .last_run
+                .is_none_or(|last| now.duration_since(last) >= state.interval);
+        if ready {
+            state.last_run = Some(now);
+            state.trailing = None;
+            drop(state);
+            run_throttled(&self.state, arg);
+        } else {
+            state.trailing = Some(arg);
+            if let Some(waker) = &state.waker {
+                waker.wake_by_ref();
+            }
+        }
+    }
+}
+
+// 取出回调在借用之外执行，回调里可以再次调用 `Throttled::call`。
+fn run_throttled<A>(state: &RefCell<ThrottleState<A>>, arg: A) {
+    let Some(mut callback) = state.borrow_mut().callback.take() else {
+        return;
+    };
+    callback(arg);
+    // 执行期间若重渲换上了新回调，保留新的。
+    state.borrow_mut().callback.get_or_insert(callback);
+}
+
+struct UseThrottledImpl<A> {
+    timer: Timer,
+    state: Rc<RefCell<ThrottleState<A>>>,
+}
+
+impl<A> Hook for UseThrottledImpl<A> {
+    fn poll_change(&mut self, cx: &mut std::task::Context) -> Poll<()> {
+        let mut state = self.state.borrow_mut();
+        state.waker = Some(cx.waker().clone());
+        if state.trailing.is_none() {
+            self.timer.cancel();
+            return Poll::Pending;
+        }
+        let deadline = state
+            .last_run
+            .map_or_else(|| self.timer.now(), |last| last + state.interval);
+        if self.timer.poll_deadline(deadline, cx)
+            && let Some(arg) = state.trailing.take()
+        {
+            state.last_run = Some(self.timer.now());
+            drop(state);
+            run_throttled(&self.state, arg);
+        }
+        Poll::Pending
+    }
+}
+
+impl UseTimer for Hooks<'_, '_> {
+    fn use_clock(&mut self) -> Clock {
+        self.use_context::<SystemContext>().clock().clone()
+    }
+
+    fn use_interval<F>(&mut self, period: Duration, f: F)
+    where
+        F: FnMut() + 'static,
+    {
+        let timers = timer_handle(self);
+        let hook = self.use_hook(|| {
+            let timer = Timer::new(timers);
+            UseIntervalImpl {
+                next: timer.now() + period,
+                timer,
+                period,
+                callback: Box::new(|| {}),
+            }
+        });
+        if hook.period != period {
+            hook.period = period;
+            hook.next = hook.timer.now() + period;
+        }
+        // 每帧换上最新的回调，使其总能读到本帧的 props / 状态。
+        hook.callback = Box::new(f);
+    }
+
+    fn use_timeout<F>(&mut self, delay: Duration, f: F)
+    where
+        F: FnOnce() + 'static,
+    {
+        let timers = timer_handle(self);
+        let hook = self.use_hook(|| {
+            let timer = Timer::new(timers);
+            UseTimeoutImpl {
+                deadline: Some(timer.now() + delay),
+                timer,
+                delay,
+                callback: None,
+            }
+        });
+        if hook.delay != delay {
+            hook.delay = delay;
+            hook.deadline = Some(hook.timer.now() + delay);
+        }
+        hook.callback = hook.deadline.map(|_| Box::new(f) as Box<dyn FnOnce()>);
+    }
+
+    fn use_debounced_value<T>(&mut self, value: T, delay: Duration) -> T
+    where
+        T: Clone + PartialEq + Unpin + 'static,
+    {
+        let timers = timer_handle(self);
+        let hook = self.use_hook(|| UseDebouncedValueImpl {
+            timer: Timer::new(timers),
+            current: value.clone(),
+            latest: value.clone(),
+            deadline: None,
+        });
+        if hook.latest != value {
+            hook.latest = value;
+            hook.deadline = Some(hook.timer.now() + delay);
+        }
+        hook.current.clone()
+    }
+
+    fn use_throttled_callback<A, F>(&mut self, interval: Duration, f: F) -> Throttled<A>
+    where
+        A: 'static,
+        F: FnMut(A) + 'static,
+    {
+        let timers = timer_handle(self);
+        let hook = self.use_hook(|| UseThrottledImpl {
+            timer: Timer::new(timers.clone()),
+            state: Rc::new(RefCell::new(ThrottleState {
+                interval,
+                callback: None,
+                last_run: None,
+                trailing: None,
+                waker: None,
+            })),
+        });
+        {
+            let mut state = hook.state.borrow_mut();
+            state.interval = interval;
+            state.callback = Some(Box::new(f));
+        }
+        Throttled {
+            state: hook.state.clone(),
+            timers,
+        }
+    }
+}
@@ -0,0 +1,90 @@
+// 应用级事件总线：组件发布「刷新」「已保存」这类类型化事件，由 `InputRuntime` 像终端事件一样
+// 分层投递给 `use_app_event::<E>` 订阅者。
+//
+// 发布只是入队（可在 handler、effect 或其它线程的异步任务里调用），渲染循环逐个取出分发，
+// 每个事件分发后重渲一帧——与终端事件同一节奏，handler 表始终是最新一帧登记的。
+
+use std::{
+    any::Any,
+    collections::VecDeque,
+    future::poll_fn,
+    marker::PhantomData,
+    sync::{Arc, Mutex},
+    task::{Context, Poll, Waker},
+};
+
+// 排队中的应用事件（类型擦除）。订阅 handler 各自 downcast 过滤。
+pub(crate) type AppEvent = Box<dyn Any + Send>;
+
+#[derive(Default)]
+struct QueueInner {
+    events: VecDeque<AppEvent>,
+    waker: Option<Waker>,
+}
+
+// 运行时持有的事件队列；`EventBus` 共享同一份。
+#[derive(Clone, Default)]
+pub(crate) struct AppEventQueue {
+    inner: Arc<Mutex<QueueInner>>,
+}
+
+impl AppEventQueue {
+    fn push(&self, event: AppEvent) {
+        let mut inner = self.inner.lock().unwrap();
+        inner.events.push_back(event);
+        if let Some(waker) = inner.waker.take() {
+            waker.wake();
+        }
+    }
+
+    fn poll_next(&self, cx: &mut Context<'_>) -> Poll<AppEvent> {
+        let mut inner = self.inner.lock().unwrap();
+        match inner.events.pop_front() {
+            Some(event) => Poll::Ready(event),
+            None => {
+                inner.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+
+    // 等待下一个应用事件。供渲染循环与终端事件一同 `select`。
+    pub(crate) async fn next(&self) -> AppEvent {
+        poll_fn(|cx| self.poll_next(cx)).await
+    }
+
+    // 非阻塞取出下一个事件。供无头驱动（测试）逐个分发。
+    #[cfg(any(test, feature = "test-util"))]
+    pub(crate) fn try_next(&self) -> Option<AppEvent> {
+        self.inner.lock().unwrap().events.pop_front()
+    }
+}
+
+// 类型化事件发布句柄。由 `use_event_bus::<E>` 返回，可 `clone` 后移入 handler 或异步任务。
+pub struct EventBus<E> {
+    queue: AppEventQueue,
+    _event: PhantomData<fn(E)>,
+}
+
+impl<E> Clone for EventBus<E> {
+    fn clone(&self) -> Self {
+        Self {
+            queue: self.queue.clone(),
+            _event: PhantomData,
+        }
+    }
+}
+
+impl<E: Send + 'static> EventBus<E> {
+    pub(crate) fn new(queue: AppEventQueue) -> Self {
+        Self {
+            queue,
+            _event: PhantomData,
+        }
+    }
+
+    // 发布一个事件。立即入队并唤醒渲染循环；投递发生在本次 handler / update 返回之后。
+    pub fn publish(&self, event: E) {
+        self.queue.push(Box::new(event));
+    }
+}
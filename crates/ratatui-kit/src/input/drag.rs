@@ -0,0 +1,207 @@
+// 拖放：建立在指针捕获之上的会话状态，由 `use_drag_source` / `use_drop_target` 共享。
+//
+// - 拖源在按下时 `start` 会话并返回 `EventResult::Capture`，之后拖动 / 松开都只投递给它；
+// - drop target 每帧在 update 期登记（`begin_frame` 清空，与 handler 同样每帧重建）；
+// - 松开时拖源 `finish` 会话，按登记序**从后往前**（越晚登记越靠上）取首个类型匹配且
+//   区域命中的 target 投递 payload。
+//
+// 会话只在捕获期间存在：捕获被运行时释放（失焦、拖源卸载）时一并 `cancel`。
+
+use std::{
+    any::{Any, TypeId},
+    cell::{Cell, RefCell},
+    rc::Rc,
+};
+
+use ratatui::layout::{Position, Rect};
+
+// 拖动进行中的位置信息（终端绝对坐标）。
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct DragInfo {
+    // 按下时的位置。
+    pub origin: Position,
+    // 指针当前位置。
+    pub position: Position,
+}
+
+// target 的投递回调：payload 已按登记类型过滤，回调内再 downcast。
+pub(crate) type DropHandler = Box<dyn FnMut(Rc<dyn Any>, DragInfo)>;
+
+struct DragSession {
+    payload: Rc<dyn Any>,
+    payload_type: TypeId,
+    info: DragInfo,
+    // 指针离开按下的单元格后才算开始拖动；原地松开视作点击，不投递。
+    started: bool,
+}
+
+struct DropTarget {
+    accepts: TypeId,
+    // 上一帧区域，由 owning hook 在 `pre_component_draw` 回填。
+    area: Rc<Cell<Rect>>,
+    on_drop: Rc<RefCell<DropHandler>>,
+}
+
+// 已结束会话的待投递结果，由拖源在释放 `DragState` 借用后 `deliver`。
+pub(crate) struct PendingDrop {
+    on_drop: Rc<RefCell<DropHandler>>,
+    payload: Rc<dyn Any>,
+    info: DragInfo,
+}
+
+impl PendingDrop {
+    pub(crate) fn deliver(self) {
+        (self.on_drop.borrow_mut())(self.payload, self.info);
+    }
+}
+
+#[derive(Default)]
+pub(crate) struct DragState {
+    session: Option<DragSession>,
+    targets: Vec<DropTarget>,
+}
+
+impl DragState {
+    pub(crate) fn begin_frame(&mut self) {
+        self.targets.clear();
+    }
+
+    pub(crate) fn start<T: 'static>(&mut self, payload: T, origin: Position) {
+        self.session = Some(DragSession {
+            payload: Rc::new(payload),
+            payload_type: TypeId::of::<T>(),
+            info: DragInfo {
+                origin,
+                position: origin,
+            },
+            started: false,
+        });
+    }
+
+    // 更新指针位置；返回拖动已开始时的最新信息。
+    pub(crate) fn move_to(&mut self, position: Position) -> Option<DragInfo> {
+        let session = self.session.as_mut()?;
+        session.info.position = position;
+        session.started |= position != session.info.origin;
+        session.started.then_some(session.info)
+    }
+
+    pub(crate) fn cancel(&mut self) {
+        self.session = None;
+    }
+
+    pub(crate) fn register_target(
+        &mut self,
+        accepts: TypeId,
+        area: Rc<Cell<Rect>>,
+        on_drop: Rc<RefCell<DropHandler>>,
+    ) {
+        self.targets.push(DropTarget {
+            accepts,
+            area,
+            on_drop,
+        });
+    }
+
+    // 拖动中且指针位于给定区域内、payload 类型匹配。
+    pub(crate) fn is_over(&self, accepts: TypeId, area: Rect) -> bool {
+        self.session.as_ref().is_some_and(|session| {
+            session.started
+                && session.payload_type == accepts
+                && area.contains(session.info.position)
+        })
+    }
+
+    // 结束会话，返回命中 target 的待投递结果。不在此调回调：回调可能再次读写拖放状态。
+    pub(crate) fn finish(&mut self) -> Option<PendingDrop> {
+        let session = self.session.take().filter(|session| session.started)?;
+        let target = self.targets.iter().rev().find(|target| {
+            target.accepts == session.payload_type
+                && target.area.get().contains(session.info.position)
+        })?;
+        Some(PendingDrop {
+            on_drop: target.on_drop.clone(),
+            payload: session.payload,
+            info: session.info,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn target(
+        state: &mut DragState,
+        accepts: TypeId,
+        area: Rect,
+        log: &Rc<RefCell<Vec<String>>>,
+        tag: &'static str,
+    ) {
+        let log = log.clone();
+        state.register_target(
+            accepts,
+            Rc::new(Cell::new(area)),
+            Rc::new(RefCell::new(Box::new(move |payload: Rc<dyn Any>, _| {
+                let payload = payload.downcast_ref::<u32>().unwrap();
+                log.borrow_mut().push(format!("{tag}:{payload}"));
+            }))),
+        );
+    }
+
+    #[test]
+    fn drop_goes_to_topmost_matching_target() {
+        let log = Rc::default();
+        let mut state = DragState::default();
+        target(
+            &mut state,
+            TypeId::of::<u32>(),
+            Rect::new(0, 0, 20, 20),
+            &log,
+            "bottom",
+        );
+        target(
+            &mut state,
+            TypeId::of::<String>(),
+            Rect::new(0, 0, 20, 20),
+            &log,
+            "wrong_type",
+        );
+        target(
+            &mut state,
+            TypeId::of::<u32>(),
+            Rect::new(5, 5, 5, 5),
+            &log,
+            "top",
+        );
+
+        state.start(7u32, Position::new(1, 1));
+        assert_eq!(
+            state.move_to(Position::new(6, 6)).map(|info| info.origin),
+            Some(Position::new(1, 1))
+        );
+        assert!(state.is_over(TypeId::of::<u32>(), Rect::new(5, 5, 5, 5)));
+        assert!(!state.is_over(TypeId::of::<String>(), Rect::new(0, 0, 20, 20)));
+
+        state.finish().expect("应命中上层 target").deliver();
+        assert_eq!(*log.borrow(), ["top:7"]);
+        assert!(state.finish().is_none(), "会话已结束");
+    }
+
+    #[test]
+    fn release_without_moving_does_not_drop() {
+        let log = Rc::default();
+        let mut state = DragState::default();
+        target(
+            &mut state,
+            TypeId::of::<u32>(),
+            Rect::new(0, 0, 20, 20),
+            &log,
+            "target",
+        );
+
+        state.start(1u32, Position::new(2, 2));
+        assert_eq!(state.move_to(Position::new(2, 2)), None, "原地不算开始拖动");
+        assert!(state.finish().is_none());
+    }
+}
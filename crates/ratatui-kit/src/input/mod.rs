@@ -7,22 +7,44 @@
 // - **优先级 / 作用域**（[`EventPriority`] / [`EventScope`]）：分层有序投递。
 // - **每帧重建**：`begin_frame` 在每帧 update 开头清空层与 handler，组件在 update 期间重新登记，
 //   因此关闭的弹窗 / 卸载的组件下一帧自动退出，无跨帧持久状态、无泄漏。
+// - **应用事件**（[`EventBus`]）：组件发布的类型化事件排队后逐个经同一套层/优先级/消费规则投递。
+// - **指针捕获**（[`EventResult::Capture`]）：按下鼠标的 handler 独占后续鼠标事件直到松开，
+//   拖出原区域也不丢目标。唯一的跨帧状态，按 [`HandlerKey`] 在下一帧重新找回 handler。
 //
 // 运行时单线程渲染，故 handler 闭包不要求 `Send + Sync`。
 
-use std::{cell::Cell, collections::HashMap, rc::Rc};
+use std::{
+    cell::{Cell, RefCell},
+    collections::HashMap,
+    rc::Rc,
+    sync::atomic::{AtomicU64, Ordering},
+};
 
-use crossterm::event::Event;
+use crossterm::event::{Event, MouseButton, MouseEventKind};
 use ratatui::layout::Rect;
 
+use crate::BatchGuard;
+
+mod app_event;
+mod drag;
+mod mouse;
+pub use app_event::*;
+pub use drag::*;
+pub use mouse::*;
+
 // handler 处理事件后的结果。`Default = Ignored`（让事件继续向后传)。
+// `#[non_exhaustive]`：之后可能再加变体，外部 `match` 须带 `_` 分支。
 #[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+#[non_exhaustive]
 pub enum EventResult {
     // 未消费，继续投递给后续 handler。
     #[default]
     Ignored,
     // 已消费，停止向后续 handler 传播。
     Consumed,
+    // 已消费，且（仅对 `MouseEventKind::Down` 有效）捕获指针：直到同一按键松开前，所有鼠标事件
+    // 只投递给本 handler，不做层截断与 `hit_test` 过滤。对其它事件等同 `Consumed`。
+    Capture,
 }
 
 // 事件投递优先级。同一层内 `High` 先于 `Normal` 先于 `Low`。
@@ -60,6 +82,24 @@ pub enum EventScope {
     Global,
 }
 
+// handler 的跨帧稳定身份。由 owning hook 首帧铸造并持有，供指针捕获在后续帧找回同一 handler。
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub(crate) struct HandlerKey(u64);
+
+impl HandlerKey {
+    pub(crate) fn next() -> Self {
+        static NEXT: AtomicU64 = AtomicU64::new(0);
+        Self(NEXT.fetch_add(1, Ordering::Relaxed))
+    }
+}
+
+// 当前的指针捕获：捕获者身份 + 按下的键（同键 `Up` 释放）。
+#[derive(Clone, Copy)]
+struct PointerCapture {
+    key: HandlerKey,
+    button: MouseButton,
+}
+
 // handler 登记选项。
 #[derive(Clone, Copy, Default)]
 pub struct EventOptions {
@@ -88,9 +128,24 @@ struct HandlerEntry {
     // 注册序，作为同层同优先级的稳定 tie-break（自顶向下，父先于子)。
     order: usize,
     options: EventOptions,
+    key: HandlerKey,
     // handler 所属组件区域，由 owning hook 在 `pre_component_draw` 经共享句柄回填（上一帧尺寸)。
     area: Rc<Cell<Rect>>,
-    f: Box<dyn FnMut(Event) -> EventResult>,
+    f: HandlerFn,
+}
+
+// handler 闭包：终端事件按值交付；应用事件按引用交付（同一事件可能经过多个 handler)，
+// 由闭包内部 downcast 过滤类型。
+enum HandlerFn {
+    Terminal(Box<dyn FnMut(Event) -> EventResult>),
+    App(Box<dyn FnMut(&AppEvent) -> EventResult>),
+}
+
+// 一次分发的事件。只投递给同类 handler，另一类视作 `Ignored`。
+#[derive(Clone, Copy)]
+enum Dispatched<'a> {
+    Terminal(&'a Event),
+    App(&'a AppEvent),
 }
 
 // 中央事件运行时，挂在 `SystemContext` 上。每帧重建层与 handler 表。
@@ -100,6 +155,12 @@ pub(crate) struct InputRuntime {
     handlers: Vec<HandlerEntry>,
     next_layer_id: u64,
     root_layer: Option<LayerId>,
+    // 跨帧保留：不在 `begin_frame` 清空。
+    capture: Option<PointerCapture>,
+    // 拖放会话与本帧 drop target，与拖放 hook 共享（handler 分发期间 runtime 不可借用）。
+    drag: Rc<RefCell<DragState>>,
+    // 待投递的应用事件。跨帧保留，由渲染循环逐个取出交 `dispatch_app_event`。
+    app_events: AppEventQueue,
 }
 
 impl InputRuntime {
@@ -107,6 +168,7 @@ impl InputRuntime {
     pub(crate) fn begin_frame(&mut self) {
         self.layers.clear();
         self.handlers.clear();
+        self.drag.borrow_mut().begin_frame();
         let root = self.mint_layer_id();
         self.root_layer = Some(root);
         self.layers.push(LayerEntry {
@@ -121,6 +183,16 @@ impl InputRuntime {
             .expect("`begin_frame` was not called before `root_layer`")
     }
 
+    // 拖放共享状态句柄，供 `use_drag_source` / `use_drop_target` 持有。
+    pub(crate) fn drag(&self) -> Rc<RefCell<DragState>> {
+        self.drag.clone()
+    }
+
+    // 应用事件队列句柄，供 `EventBus` 发布与渲染循环等待。
+    pub(crate) fn app_events(&self) -> AppEventQueue {
+        self.app_events.clone()
+    }
+
     fn mint_layer_id(&mut self) -> LayerId {
         let id = LayerId(self.next_layer_id);
         self.next_layer_id = self.next_layer_id.wrapping_add(1);
@@ -145,6 +217,7 @@ impl InputRuntime {
         layer: Option<LayerId>,
         priority: EventPriority,
         options: EventOptions,
+        key: HandlerKey,
         area: Rc<Cell<Rect>>,
         f: Box<dyn FnMut(Event) -> EventResult>,
     ) {
@@ -154,8 +227,28 @@ impl InputRuntime {
             priority,
             order,
             options,
+            key,
             area,
-            f,
+            f: HandlerFn::Terminal(f),
+        });
+    }
+
+    // 组件 update 期登记一个应用事件 handler。无区域、不参与指针捕获。
+    pub(crate) fn register_app_handler(
+        &mut self,
+        layer: Option<LayerId>,
+        priority: EventPriority,
+        f: Box<dyn FnMut(&AppEvent) -> EventResult>,
+    ) {
+        let order = self.handlers.len();
+        self.handlers.push(HandlerEntry {
+            layer,
+            priority,
+            order,
+            options: EventOptions::default(),
+            key: HandlerKey::next(),
+            area: Rc::default(),
+            f: HandlerFn::App(f),
         });
     }
 
@@ -165,7 +258,33 @@ impl InputRuntime {
     // 1. **Global**：所有 `layer=None` handler，按 `(priority desc, order asc)`，遇 `Consumed` 终止全程。
     // 2. **层内**：活跃层（从栈顶向下遇首个 `blocks_lower` 截断)的 handler，
     //    按 `(层 z-order desc, priority desc, order asc)`——**z-order 第一键，不跨层比 priority**，遇 `Consumed` 早停。
+    //
+    // 指针被捕获时，鼠标事件绕过两个 phase 直接交给捕获者（见 [`Self::dispatch_captured`]）。
     pub(crate) fn dispatch(&mut self, event: Event) {
+        // handler 常连续写多个状态：整次分发作为一个批次，只唤醒一次。
+        let _batch = BatchGuard::enter();
+        if matches!(event, Event::FocusLost) {
+            // 失焦后松开事件可能永远不会到达：释放捕获并作废拖放，事件本身照常分发。
+            self.release_capture();
+        }
+        let event = match self.dispatch_captured(event) {
+            Some(event) => event,
+            None => return,
+        };
+
+        if let Some(stopped) = self.deliver(Dispatched::Terminal(&event)) {
+            self.begin_capture(stopped, &event);
+        }
+    }
+
+    // 把一个应用事件分发给本帧匹配类型的 handler。层、优先级与消费语义同 [`Self::dispatch`]。
+    pub(crate) fn dispatch_app_event(&mut self, event: AppEvent) {
+        let _batch = BatchGuard::enter();
+        self.deliver(Dispatched::App(&event));
+    }
+
+    // 两 phase 投递，返回截断分发的 handler 身份与结果。本帧 handler 随后即弃。
+    fn deliver(&mut self, event: Dispatched) -> Option<(HandlerKey, EventResult)> {
         // 活跃层集：从栈顶（末尾)向下，遇首个 blocks_lower=true 截断（含该层)。
         let cut = self
             .layers
@@ -192,8 +311,8 @@ impl InputRuntime {
                 .cmp(&handlers[a].priority)
                 .then(handlers[a].order.cmp(&handlers[b].order))
         });
-        if Self::run_handlers(&mut handlers, &global_idx, &event) {
-            return;
+        if let Some(stopped) = Self::run_handlers(&mut handlers, &global_idx, event) {
+            return Some(stopped);
         }
 
         // Phase 2：活跃层内，按 (z-order desc, priority desc, order asc)。
@@ -207,37 +326,90 @@ impl InputRuntime {
                 .then(handlers[b].priority.cmp(&handlers[a].priority)) // priority 降序
                 .then(handlers[a].order.cmp(&handlers[b].order)) // 注册序升序
         });
-        Self::run_handlers(&mut handlers, &layer_idx, &event);
+        Self::run_handlers(&mut handlers, &layer_idx, event)
         // handlers 在此 drop（即弃)；下一帧 begin_frame 后由组件重建。
     }
 
-    // 按给定顺序依次调用 handler，遇 `Consumed` 早停并返回 `true`
-    // （供 Phase 1 决定是否截断 Phase 2）。
-    fn run_handlers(handlers: &mut [HandlerEntry], order: &[usize], event: &Event) -> bool {
-        for &i in order {
-            if Self::call_handler(&mut handlers[i], event) == EventResult::Consumed {
-                return true;
-            }
+    // 捕获期间的鼠标事件：交给捕获者并返回 `None`（同键 `Up` 时随后释放捕获)。
+    // 无捕获或非鼠标事件时原样返回，走常规分发；捕获者本帧未登记（组件已卸载）时先释放捕获。
+    fn dispatch_captured(&mut self, event: Event) -> Option<Event> {
+        let (Some(capture), Event::Mouse(mouse)) = (self.capture, &event) else {
+            return Some(event);
+        };
+        let Some(handler) = self.handlers.iter_mut().find(|h| h.key == capture.key) else {
+            self.release_capture();
+            return Some(event);
+        };
+
+        let released = mouse.kind == MouseEventKind::Up(capture.button);
+        if let HandlerFn::Terminal(f) = &mut handler.f {
+            f(event);
         }
-        false
+        if released {
+            self.capture = None;
+        }
+        // 与常规分发一致：本帧 handler 即弃，下一帧重建。
+        self.handlers.clear();
+        None
     }
 
-    // 调用单个 handler，先做鼠标命中过滤（仅当 `hit_test` 且事件为鼠标事件)。
-    // 区域外视作未调用，返回 `Ignored` 让分发继续下一个候选。
-    fn call_handler(h: &mut HandlerEntry, event: &Event) -> EventResult {
-        if h.options.hit_test
-            && let Event::Mouse(m) = event
+    // 截断分发的 handler 返回 `Capture` 且事件为鼠标按下时，开始捕获。
+    fn begin_capture(&mut self, (key, result): (HandlerKey, EventResult), event: &Event) {
+        if result != EventResult::Capture {
+            return;
+        }
+        if let Event::Mouse(mouse) = event
+            && let MouseEventKind::Down(button) = mouse.kind
         {
-            let a = h.area.get();
-            let hit = m.column >= a.x
-                && m.column < a.x.saturating_add(a.width)
-                && m.row >= a.y
-                && m.row < a.y.saturating_add(a.height);
-            if !hit {
-                return EventResult::Ignored;
+            self.capture = Some(PointerCapture { key, button });
+        }
+    }
+
+    // 释放捕获；拖放会话只存在于捕获期间，一并作废。
+    fn release_capture(&mut self) {
+        if self.capture.take().is_some() {
+            self.drag.borrow_mut().cancel();
+        }
+    }
+
+    // 按给定顺序依次调用 handler，遇非 `Ignored` 早停并返回截断者的身份与结果
+    // （供 Phase 1 决定是否截断 Phase 2，以及登记指针捕获）。
+    fn run_handlers(
+        handlers: &mut [HandlerEntry],
+        order: &[usize],
+        event: Dispatched,
+    ) -> Option<(HandlerKey, EventResult)> {
+        order.iter().find_map(|&i| {
+            let handler = &mut handlers[i];
+            match Self::call_handler(handler, event) {
+                EventResult::Ignored => None,
+                result => Some((handler.key, result)),
+            }
+        })
+    }
+
+    // 调用单个 handler。终端事件先做鼠标命中过滤（仅当 `hit_test` 且事件为鼠标事件)，
+    // 区域外视作未调用；事件与 handler 类别不符同样返回 `Ignored`，让分发继续下一个候选。
+    fn call_handler(h: &mut HandlerEntry, event: Dispatched) -> EventResult {
+        match (&mut h.f, event) {
+            (HandlerFn::Terminal(f), Dispatched::Terminal(event)) => {
+                if h.options.hit_test
+                    && let Event::Mouse(m) = event
+                {
+                    let a = h.area.get();
+                    let hit = m.column >= a.x
+                        && m.column < a.x.saturating_add(a.width)
+                        && m.row >= a.y
+                        && m.row < a.y.saturating_add(a.height);
+                    if !hit {
+                        return EventResult::Ignored;
+                    }
+                }
+                f(event.clone())
             }
+            (HandlerFn::App(f), Dispatched::App(event)) => f(event),
+            _ => EventResult::Ignored,
         }
-        (h.f)(event.clone())
     }
 }
 
@@ -295,6 +467,7 @@ mod tests {
             Some(root),
             EventPriority::Normal,
             opts(false),
+            HandlerKey::next(),
             full_area(),
             handler(&log, "bg", EventResult::Ignored),
         );
@@ -303,6 +476,7 @@ mod tests {
             Some(modal.id),
             EventPriority::Normal,
             opts(false),
+            HandlerKey::next(),
             full_area(),
             handler(&log, "modal", EventResult::Ignored),
         );
@@ -321,6 +495,7 @@ mod tests {
             Some(root),
             EventPriority::Normal,
             opts(false),
+            HandlerKey::next(),
             full_area(),
             handler(&log, "root", EventResult::Ignored),
         );
@@ -329,6 +504,7 @@ mod tests {
             Some(l1.id),
             EventPriority::Normal,
             opts(false),
+            HandlerKey::next(),
             full_area(),
             handler(&log, "l1", EventResult::Ignored),
         );
@@ -337,6 +513,7 @@ mod tests {
             Some(l2.id),
             EventPriority::Normal,
             opts(false),
+            HandlerKey::next(),
             full_area(),
             handler(&log, "l2", EventResult::Ignored),
         );
@@ -355,6 +532,7 @@ mod tests {
             Some(root),
             EventPriority::Normal,
             opts(false),
+            HandlerKey::next(),
             full_area(),
             handler(&log, "root", EventResult::Ignored),
         );
@@ -363,6 +541,7 @@ mod tests {
             Some(modal.id),
             EventPriority::Normal,
             opts(false),
+            HandlerKey::next(),
             full_area(),
             handler(&log, "modal", EventResult::Ignored),
         );
@@ -371,6 +550,7 @@ mod tests {
             Some(toast.id),
             EventPriority::Normal,
             opts(false),
+            HandlerKey::next(),
             full_area(),
             handler(&log, "toast", EventResult::Ignored),
         );
@@ -390,6 +570,7 @@ mod tests {
             Some(root),
             EventPriority::Normal,
             opts(false),
+            HandlerKey::next(),
             full_area(),
             handler(&log, "first", EventResult::Consumed),
         );
@@ -397,6 +578,7 @@ mod tests {
             Some(root),
             EventPriority::Normal,
             opts(false),
+            HandlerKey::next(),
             full_area(),
             handler(&log, "second", EventResult::Ignored),
         );
@@ -415,6 +597,7 @@ mod tests {
             Some(root),
             EventPriority::Normal,
             opts(false),
+            HandlerKey::next(),
             full_area(),
             handler(&log, "first", EventResult::Ignored),
         );
@@ -422,6 +605,7 @@ mod tests {
             Some(root),
             EventPriority::Normal,
             opts(false),
+            HandlerKey::next(),
             full_area(),
             handler(&log, "second", EventResult::Ignored),
         );
@@ -440,6 +624,7 @@ mod tests {
             Some(root),
             EventPriority::High,
             opts(false),
+            HandlerKey::next(),
             full_area(),
             handler(&log, "bg_high", EventResult::Ignored),
         );
@@ -448,6 +633,7 @@ mod tests {
             Some(top.id),
             EventPriority::Normal,
             opts(false),
+            HandlerKey::next(),
             full_area(),
             handler(&log, "top_normal", EventResult::Ignored),
         );
@@ -466,6 +652,7 @@ mod tests {
             None,
             EventPriority::Normal,
             opts(false),
+            HandlerKey::next(),
             full_area(),
             handler(&log, "global", EventResult::Consumed),
         );
@@ -473,6 +660,7 @@ mod tests {
             Some(root),
             EventPriority::High,
             opts(false),
+            HandlerKey::next(),
             full_area(),
             handler(&log, "layer", EventResult::Ignored),
         );
@@ -491,6 +679,7 @@ mod tests {
             None,
             EventPriority::Normal,
             opts(false),
+            HandlerKey::next(),
             full_area(),
             handler(&log, "global", EventResult::Ignored),
         );
@@ -498,6 +687,7 @@ mod tests {
             Some(root),
             EventPriority::Normal,
             opts(false),
+            HandlerKey::next(),
             full_area(),
             handler(&log, "layer", EventResult::Ignored),
         );
@@ -516,6 +706,7 @@ mod tests {
             Some(inactive.id),
             EventPriority::Normal,
             opts(false),
+            HandlerKey::next(),
             full_area(),
             handler(&log, "inactive", EventResult::Ignored),
         );
@@ -535,6 +726,7 @@ mod tests {
             Some(root),
             EventPriority::Normal,
             opts(true),
+            HandlerKey::next(),
             area,
             handler(&log, "hit", EventResult::Consumed),
         );
@@ -548,10 +740,135 @@ mod tests {
             Some(root2),
             EventPriority::Normal,
             opts(true),
+            HandlerKey::next(),
             area2,
             handler(&log, "hit", EventResult::Consumed),
         );
         rt.dispatch(mouse_at(5, 5)); // 命中
         assert_eq!(*log.borrow(), ["hit"]);
     }
+
+    fn mouse(kind: MouseEventKind, col: u16, row: u16) -> Event {
+        Event::Mouse(MouseEvent {
+            kind,
+            column: col,
+            row,
+            modifiers: KeyModifiers::NONE,
+        })
+    }
+
+    // 按下返回 Capture 的 hit_test handler（区域 0..10)+ 全区域旁观者,按给定 key 每帧重建。
+    fn capture_frame(rt: &mut InputRuntime, log: &Log, captor: Option<HandlerKey>) {
+        rt.begin_frame();
+        let root = rt.root_layer();
+        if let Some(key) = captor {
+            let log = log.clone();
+            rt.register_handler(
+                Some(root),
+                EventPriority::Normal,
+                opts(true),
+                key,
+                Rc::new(Cell::new(Rect::new(0, 0, 10, 10))),
+                Box::new(move |event| {
+                    log.borrow_mut().push("captor");
+                    match event {
+                        Event::Mouse(m) if matches!(m.kind, MouseEventKind::Down(_)) => {
+                            EventResult::Capture
+                        }
+                        _ => EventResult::Consumed,
+                    }
+                }),
+            );
+        }
+        rt.register_handler(
+            Some(root),
+            EventPriority::Normal,
+            opts(false),
+            HandlerKey::next(),
+            full_area(),
+            handler(log, "other", EventResult::Ignored),
+        );
+    }
+
+    // ⑨ 捕获期间鼠标事件只给捕获者(区域外也收到),同键松开后恢复常规分发
+    #[test]
+    fn capture_routes_mouse_to_captor_until_release() {
+        let log: Log = Default::default();
+        let mut rt = InputRuntime::default();
+        let captor = HandlerKey::next();
+
+        capture_frame(&mut rt, &log, Some(captor));
+        rt.dispatch(mouse(MouseEventKind::Down(MouseButton::Left), 5, 5));
+        capture_frame(&mut rt, &log, Some(captor));
+        rt.dispatch(mouse(MouseEventKind::Drag(MouseButton::Left), 50, 50));
+        capture_frame(&mut rt, &log, Some(captor));
+        rt.dispatch(mouse(MouseEventKind::Up(MouseButton::Left), 50, 50));
+        capture_frame(&mut rt, &log, Some(captor));
+        rt.dispatch(mouse(MouseEventKind::Moved, 50, 50));
+
+        assert_eq!(*log.borrow(), ["captor", "captor", "captor", "other"]);
+    }
+
+    // ⑩ 捕获不拦截键盘;捕获者卸载后释放,鼠标事件回到常规分发
+    #[test]
+    fn capture_released_when_captor_unregistered() {
+        let log: Log = Default::default();
+        let mut rt = InputRuntime::default();
+        let captor = HandlerKey::next();
+
+        capture_frame(&mut rt, &log, Some(captor));
+        rt.dispatch(mouse(MouseEventKind::Down(MouseButton::Left), 5, 5));
+        capture_frame(&mut rt, &log, Some(captor));
+        rt.dispatch(key());
+        capture_frame(&mut rt, &log, None);
+        rt.dispatch(mouse(MouseEventKind::Drag(MouseButton::Left), 5, 5));
+        assert!(rt.capture.is_none());
+
+        assert_eq!(*log.borrow(), ["captor", "captor", "other"]);
+    }
+
+    // ⑪ 应用事件按类型过滤、遵循 Consumed 截断,终端 handler 不会收到
+    fn app_event_frame(rt: &mut InputRuntime, log: &Log) {
+        rt.begin_frame();
+        let root = rt.root_layer();
+        rt.register_handler(
+            Some(root),
+            EventPriority::High,
+            opts(false),
+            HandlerKey::next(),
+            full_area(),
+            handler(log, "terminal", EventResult::Consumed),
+        );
+        for (tag, priority, result) in [
+            ("high", EventPriority::High, EventResult::Ignored),
+            ("normal", EventPriority::Normal, EventResult::Consumed),
+            ("low", EventPriority::Low, EventResult::Ignored),
+        ] {
+            let log = log.clone();
+            rt.register_app_handler(
+                Some(root),
+                priority,
+                Box::new(move |event| match event.downcast_ref::<u32>() {
+                    Some(_) => {
+                        log.borrow_mut().push(tag);
+                        result
+                    }
+                    None => EventResult::Ignored,
+                }),
+            );
+        }
+    }
+
+    #[test]
+    fn app_events_filter_by_type_and_stop_on_consumed() {
+        let log: Log = Default::default();
+        let mut rt = InputRuntime::default();
+
+        app_event_frame(&mut rt, &log);
+        rt.dispatch_app_event(Box::new("not a u32"));
+        assert!(log.borrow().is_empty());
+        app_event_frame(&mut rt, &log);
+        rt.dispatch_app_event(Box::new(1u32));
+        assert_eq!(*log.borrow(), ["high", "normal"]);
+    }
 }
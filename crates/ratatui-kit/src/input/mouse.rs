@@ -0,0 +1,159 @@
+// 鼠标交互的数据类型：悬停/点击命中信息与双击识别。
+//
+// 命中区域沿用 `InputRuntime` 的约定：组件区域由 owning hook 在 `pre_component_draw` 回填
+// （即**上一帧**尺寸），故点击/悬停总是对照用户眼前那一帧判定。
+
+use std::time::{Duration, Instant};
+
+use crossterm::event::{KeyModifiers, MouseButton};
+use ratatui::layout::{Position, Rect};
+
+// 两次按下被视作双击的最大间隔。
+pub const DOUBLE_CLICK_INTERVAL: Duration = Duration::from_millis(500);
+
+// 单击 / 双击。
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum ClickKind {
+    #[default]
+    Single,
+    // 同一位置、同一按键在 [`DOUBLE_CLICK_INTERVAL`] 内的第二次按下。
+    Double,
+}
+
+// 指针悬停在组件区域内时的命中信息。由 `use_hover` 返回。
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Hover {
+    // 指针所在单元格（终端绝对坐标）。
+    pub position: Position,
+    // 组件上一帧的区域。
+    pub area: Rect,
+}
+
+impl Hover {
+    // 指针相对组件区域左上角的偏移。
+    pub fn relative(&self) -> Position {
+        relative_position(self.position, self.area)
+    }
+}
+
+// 组件区域内的一次鼠标按下。由 `use_click` 回调。
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct MouseClick {
+    // 按下的单元格（终端绝对坐标）。
+    pub position: Position,
+    // 组件上一帧的区域。
+    pub area: Rect,
+    pub button: MouseButton,
+    pub modifiers: KeyModifiers,
+    pub kind: ClickKind,
+}
+
+impl MouseClick {
+    // 按下点相对组件区域左上角的偏移。
+    pub fn relative(&self) -> Position {
+        relative_position(self.position, self.area)
+    }
+
+    pub fn is_double(&self) -> bool {
+        self.kind == ClickKind::Double
+    }
+}
+
+fn relative_position(position: Position, area: Rect) -> Position {
+    Position::new(
+        position.x.saturating_sub(area.x),
+        position.y.saturating_sub(area.y),
+    )
+}
+
+// 双击识别：记住上一次按下的位置/按键/时刻。第二次按下判定为双击后清空，
+// 故连续三击是「双击 + 单击」而非两次双击。
+#[derive(Default)]
+pub(crate) struct ClickTracker {
+    last: Option<(Position, MouseButton, Instant)>,
+}
+
+impl ClickTracker {
+    pub(crate) fn press(
+        &mut self,
+        position: Position,
+        button: MouseButton,
+        now: Instant,
+    ) -> ClickKind {
+        let is_double = self.last.is_some_and(|(last_position, last_button, at)| {
+            last_position == position
+                && last_button == button
+                && now.saturating_duration_since(at) <= DOUBLE_CLICK_INTERVAL
+        });
+
+        if is_double {
+            self.last = None;
+            ClickKind::Double
+        } else {
+            self.last = Some((position, button, now));
+            ClickKind::Single
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn second_press_within_interval_is_double() {
+        let mut tracker = ClickTracker::default();
+        let now = Instant::now();
+        let at = Position::new(3, 4);
+        assert_eq!(tracker.press(at, MouseButton::Left, now), ClickKind::Single);
+        assert_eq!(
+            tracker.press(at, MouseButton::Left, now + Duration::from_millis(100)),
+            ClickKind::Double
+        );
+        // 双击后清空:第三击重新计为单击。
+        assert_eq!(
+            tracker.press(at, MouseButton::Left, now + Duration::from_millis(200)),
+            ClickKind::Single
+        );
+    }
+
+    #[test]
+    fn slow_moved_or_other_button_press_is_single() {
+        let mut tracker = ClickTracker::default();
+        let now = Instant::now();
+        let at = Position::new(3, 4);
+        tracker.press(at, MouseButton::Left, now);
+        assert_eq!(
+            tracker.press(at, MouseButton::Left, now + Duration::from_secs(1)),
+            ClickKind::Single
+        );
+        assert_eq!(
+            tracker.press(
+                Position::new(5, 4),
+                MouseButton::Left,
+                now + Duration::from_secs(1)
+            ),
+            ClickKind::Single
+        );
+        assert_eq!(
+            tracker.press(
+                Position::new(5, 4),
+                MouseButton::Right,
+                now + Duration::from_secs(1)
+            ),
+            ClickKind::Single
+        );
+    }
+
+    #[test]
+    fn relative_position_is_offset_from_area_origin() {
+        let click = MouseClick {
+            position: Position::new(12, 7),
+            area: Rect::new(10, 5, 8, 4),
+            button: MouseButton::Left,
+            modifiers: KeyModifiers::NONE,
+            kind: ClickKind::Single,
+        };
+        assert_eq!(click.relative(), Position::new(2, 2));
+    }
+}
@@ -1,5 +1,6 @@
 #![doc = include_str!("../README.md")]
 #![allow(clippy::needless_update)]
+mod animation;
 #[cfg(feature = "atom")]
 mod atom;
 mod component;
@@ -10,14 +11,20 @@ mod handler;
 mod hooks;
 mod input;
 mod multimap;
+#[cfg(feature = "serde")]
+mod persist;
 mod props;
+#[cfg(feature = "query")]
+mod query;
 mod reactive_handle;
 mod render;
 mod terminal;
 #[cfg(feature = "test-util")]
 pub mod test_util;
+mod time;
 
 mod flatten_export {
+    pub use crate::animation::*;
     #[cfg(feature = "atom")]
     pub use crate::atom::*;
     pub use crate::component::*;
@@ -27,10 +34,15 @@ mod flatten_export {
     pub use crate::handler::*;
     pub use crate::hooks::*;
     pub use crate::input::*;
+    #[cfg(feature = "serde")]
+    pub use crate::persist::{flush_persisted, persist_dir, set_persist_dir};
     pub use crate::props::*;
+    #[cfg(feature = "query")]
+    pub use crate::query::*;
     pub use crate::reactive_handle::*;
     pub use crate::render::*;
     pub use crate::terminal::*;
+    pub use crate::time::Clock;
 }
 
 pub use crossterm;
@@ -0,0 +1,282 @@
+// 状态持久化（`serde` 特性）：设置、会话状态（上次选中的标签页、主题、筛选条件）跨重启保留。
+//
+// - 存储：每个持久化状态对应持久化目录下的 `<key>.json`，内容形如 `{"version": 1, "value": ...}`。
+//   目录默认是当前工作目录下的 `.ratatui-kit`，用 [`set_persist_dir`] 修改。key 中的路径分隔符
+//   按 `%XX` 转义（`a/b` 存为 `a%2Fb.json`），文件总在该目录下。
+// - 读取：首次访问时读取；文件缺失、版本号不符或值无法按当前结构反序列化时改用初始值。
+// - 写回：每次带通知的写入安排一次写回，由后台线程防抖落盘：写入停止一段时间后（或持续写入
+//   达到上限时）才写一次。渲染循环退出时经 [`flush_persisted`] 写回剩余的变更。
+//
+// 入口是 `Atom::persisted`（同时开启 `atom` 特性）和 `use_persisted_state`。
+
+use std::{
+    collections::HashMap,
+    fs, io,
+    path::PathBuf,
+    sync::{Arc, LazyLock, Mutex, OnceLock, mpsc},
+    task::{Wake, Waker},
+    thread,
+    time::{Duration, Instant},
+};
+
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+
+use crate::{Notifier, ReactiveHandle, reactive_handle::Source};
+
+// 最后一次写入后等待的时间。
+const DEBOUNCE: Duration = Duration::from_millis(300);
+// 持续写入时最多推迟这么久。
+const MAX_DELAY: Duration = Duration::from_secs(2);
+
+static DIR: Mutex<Option<PathBuf>> = Mutex::new(None);
+
+// 待写回的状态，按 key 去重。
+static PENDING: LazyLock<Mutex<HashMap<String, Arc<dyn Persist>>>> = LazyLock::new(Mutex::default);
+
+static WRITER: OnceLock<mpsc::Sender<()>> = OnceLock::new();
+
+// 串行化写回：后台线程写到一半时，退出前的 `flush_persisted` 等它写完再返回。
+static FLUSH: Mutex<()> = Mutex::new(());
+
+// 设置持久化目录。应在首次访问持久化状态之前调用，之后的读写都使用新目录。
+pub fn set_persist_dir(dir: impl Into<PathBuf>) {
+    *DIR.lock().unwrap() = Some(dir.into());
+}
+
+// 当前的持久化目录。
+pub fn persist_dir() -> PathBuf {
+    DIR.lock()
+        .unwrap()
+        .clone()
+        .unwrap_or_else(|| PathBuf::from(".ratatui-kit"))
+}
+
+// 立即写回所有待写的变更，返回遇到的第一个错误（其余状态照常写回）。
+// 后台线程的写回会忽略错误；需要知道是否写成功时在退出前调用它。
+pub fn flush_persisted() -> io::Result<()> {
+    let _flush = FLUSH.lock().unwrap();
+    let pending = std::mem::take(&mut *PENDING.lock().unwrap());
+    let mut result = Ok(());
+    for target in pending.into_values() {
+        let saved = target.save();
+        if result.is_ok() {
+            result = saved;
+        }
+    }
+    result
+}
+
+// key 编码为单个文件名：路径分隔符、`:` 与 `%` 按 `%XX` 转义，key 中的 `/`、`..` 不会逃出持久化目录。
+fn path(key: &str) -> PathBuf {
+    let mut name = String::with_capacity(key.len() + 5);
+    for c in key.chars() {
+        match c {
+            '/' | '\\' | ':' | '%' | '\0' => name.push_str(&format!("%{:02X}", c as u32)),
+            c => name.push(c),
+        }
+    }
+    name.push_str(".json");
+    persist_dir().join(name)
+}
+
+#[derive(Deserialize)]
+struct Envelope<T> {
+    version: u32,
+    value: T,
+}
+
+#[derive(Serialize)]
+struct EnvelopeRef<'a, T> {
+    version: u32,
+    value: &'a T,
+}
+
+pub(crate) fn load<T: DeserializeOwned>(key: &str, version: u32) -> Option<T> {
+    let bytes = fs::read(path(key)).ok()?;
+    // 先只取版本号：版本不符时不按当前结构解析旧值。
+    let envelope: Envelope<serde_json::Value> = serde_json::from_slice(&bytes).ok()?;
+    if envelope.version != version {
+        return None;
+    }
+    serde_json::from_value(envelope.value).ok()
+}
+
+fn save<T: Serialize>(key: &str, version: u32, value: &T) -> io::Result<()> {
+    let path = path(key);
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let json = serde_json::to_vec_pretty(&EnvelopeRef { version, value })?;
+    // 先写临时文件再改名，中途退出不会留下半截文件。
+    let tmp = path.with_extension("json.tmp");
+    fs::write(&tmp, json)?;
+    fs::rename(tmp, path)
+}
+
+trait Persist: Send + Sync {
+    fn key(&self) -> &str;
+    fn save(self: Arc<Self>) -> io::Result<()>;
+}
+
+fn schedule(target: Arc<dyn Persist>) {
+    PENDING
+        .lock()
+        .unwrap()
+        .insert(target.key().to_owned(), target);
+    let writer = WRITER.get_or_init(|| {
+        let (sender, receiver) = mpsc::channel();
+        thread::Builder::new()
+            .name("ratatui-kit-persist".to_owned())
+            .spawn(move || write_loop(receiver))
+            .expect("failed to spawn the persistence writer thread");
+        sender
+    });
+    let _ = writer.send(());
+}
+
+fn write_loop(receiver: mpsc::Receiver<()>) {
+    while receiver.recv().is_ok() {
+        let start = Instant::now();
+        while start.elapsed() < MAX_DELAY && receiver.recv_timeout(DEBOUNCE).is_ok() {}
+        let _ = flush_persisted();
+    }
+}
+
+// 一个持久化中的状态。作为 waker 登记在状态上，状态变更时安排写回。
+pub(crate) struct Persisted<T, N>
+where
+    T: Send + Sync + 'static,
+    N: Notifier,
+{
+    key: String,
+    version: u32,
+    state: ReactiveHandle<T, N>,
+}
+
+impl<T, N> Persisted<T, N>
+where
+    T: Serialize + Send + Sync + 'static,
+    N: Notifier,
+{
+    // 开始持久化 `state`：之后每次带通知的写入都会安排一次写回。
+    pub(crate) fn attach(key: String, version: u32, state: ReactiveHandle<T, N>) -> Arc<Self> {
+        let persisted = Arc::new(Self {
+            key,
+            version,
+            state,
+        });
+        persisted.state.observe(&Waker::from(persisted.clone()));
+        persisted
+    }
+
+    // 立即写回当前值（组件卸载时使用），并撤下尚未执行的后台写回。
+    pub(crate) fn save_now(self: &Arc<Self>) -> io::Result<()> {
+        let _flush = FLUSH.lock().unwrap();
+        PENDING.lock().unwrap().remove(&self.key);
+        self.clone().save()
+    }
+}
+
+impl<T, N> Persist for Persisted<T, N>
+where
+    T: Serialize + Send + Sync + 'static,
+    N: Notifier,
+{
+    fn key(&self) -> &str {
+        &self.key
+    }
+
+    fn save(self: Arc<Self>) -> io::Result<()> {
+        // 状态已释放（组件卸载、原子族删除了 key）。
+        if self.state.version().is_none() {
+            return Ok(());
+        }
+        // 先重新登记再读取：读取之后的写入会再次安排写回。
+        self.state.observe(&Waker::from(self.clone()));
+        let Some(value) = self.state.try_read() else {
+            // 值正被写入，稍后重试。
+            schedule(self);
+            return Ok(());
+        };
+        save(&self.key, self.version, &*value)
+    }
+}
+
+// 唤醒发生在写锁释放之前，这里只登记，不读值。
+impl<T, N> Wake for Persisted<T, N>
+where
+    T: Serialize + Send + Sync + 'static,
+    N: Notifier,
+{
+    fn wake(self: Arc<Self>) {
+        schedule(self);
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::{SingleWaker, State};
+    use generational_box::{Owner, SyncStorage};
+
+    // 全部持久化测试共用一个临时目录（目录是进程级设置），各测试使用不同的 key。
+    pub(crate) fn use_temp_dir() -> PathBuf {
+        static TEMP: OnceLock<PathBuf> = OnceLock::new();
+        TEMP.get_or_init(|| {
+            let dir =
+                std::env::temp_dir().join(format!("ratatui-kit-persist-{}", std::process::id()));
+            let _ = fs::remove_dir_all(&dir);
+            set_persist_dir(&dir);
+            dir
+        })
+        .clone()
+    }
+
+    #[test]
+    fn saves_and_loads_with_matching_version() {
+        use_temp_dir();
+        save("round-trip", 2, &vec![1, 2, 3]).unwrap();
+        assert_eq!(load::<Vec<i32>>("round-trip", 2), Some(vec![1, 2, 3]));
+        assert_eq!(load::<Vec<i32>>("round-trip", 3), None, "版本不符时不读取");
+        assert_eq!(load::<String>("round-trip", 2), None, "结构不符时不读取");
+        assert_eq!(load::<i32>("missing", 1), None);
+    }
+
+    #[test]
+    fn keys_cannot_escape_the_persist_dir() {
+        let dir = use_temp_dir();
+        for key in ["../escape", "nested/key", "..\\windows", "C:drive"] {
+            assert_eq!(path(key).parent(), Some(dir.as_path()), "{key}");
+        }
+        assert_eq!(path("../escape"), dir.join("..%2Fescape.json"));
+
+        save("../escape", 1, &7).unwrap();
+        assert_eq!(load::<i32>("../escape", 1), Some(7));
+        assert!(!dir.parent().unwrap().join("escape.json").exists());
+    }
+
+    #[test]
+    fn corrupt_file_falls_back() {
+        let dir = use_temp_dir();
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("corrupt.json"), "{ not json").unwrap();
+        assert_eq!(load::<i32>("corrupt", 1), None);
+    }
+
+    #[test]
+    fn notified_writes_are_scheduled_and_flushed() {
+        use_temp_dir();
+        let owner = Owner::<SyncStorage>::default();
+        let mut state: State<String> = ReactiveHandle::<_, SingleWaker>::new_in(&owner, "a".into());
+        let persisted = Persisted::attach("scheduled".to_owned(), 1, state);
+
+        state.set("b".into());
+        flush_persisted().unwrap();
+        assert_eq!(load::<String>("scheduled", 1).as_deref(), Some("b"));
+
+        // 写回后重新登记，后续写入继续持久化。
+        state.set("c".into());
+        persisted.save_now().unwrap();
+        assert_eq!(load::<String>("scheduled", 1).as_deref(), Some("c"));
+    }
+}
@@ -0,0 +1,549 @@
+// 按 key 缓存的异步查询（类 React Query / SWR）。
+//
+// - [`QueryClient`]：缓存本体。默认每棵组件树一个（挂在 `SystemContext`），也可经
+//   `ContextProvider(value: Context::owned(client))` 为子树注入独立实例。`clone` 共享同一份缓存。
+// - [`crate::UseQuery::use_query`]：订阅一个 key。缓存有数据时立即返回（即使已过期），
+//   过期 / 失效时在后台重新获取（stale-while-revalidate）；同一 key 的并发获取合并为一次。
+// - [`crate::UseMutation::use_mutation`]：乐观更新缓存、发请求，失败回滚，成功后令 key 失效。
+//
+// 缓存条目无订阅者超过 `cache_time` 后被回收。获取由订阅者推进，最后一个订阅者离开时进行中的
+// 获取随之放弃，下一个订阅者挂载时重新获取。缓存值按类型擦除存放，同一 key 请始终使用
+// 同一 `T` / `E`，否则读取时 panic。
+
+use std::{
+    any::Any,
+    cell::RefCell,
+    collections::HashMap,
+    rc::Rc,
+    sync::atomic::{AtomicU64, Ordering},
+    task::Waker,
+    time::{Duration, Instant},
+};
+
+use futures::{
+    FutureExt,
+    future::{LocalBoxFuture, Shared},
+};
+
+use crate::time::Clock;
+
+mod use_query;
+pub use use_query::*;
+mod mutation;
+pub use mutation::*;
+
+// 查询的缓存策略。
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct QueryOptions {
+    // 数据获取后保持新鲜的时长；过期后下次挂载订阅时在后台重新获取。默认 0（总是重新验证）。
+    pub stale_time: Duration,
+    // 条目没有订阅者后保留的时长，超时回收。默认 5 分钟。
+    pub cache_time: Duration,
+}
+
+impl Default for QueryOptions {
+    fn default() -> Self {
+        Self {
+            stale_time: Duration::ZERO,
+            cache_time: Duration::from_secs(5 * 60),
+        }
+    }
+}
+
+type Erased = Rc<dyn Any>;
+type InFlight = Shared<LocalBoxFuture<'static, Result<Erased, Erased>>>;
+
+// 订阅者身份：每个 `use_query` hook 一个，跨帧稳定。
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub(crate) struct ObserverId(u64);
+
+impl ObserverId {
+    pub(crate) fn next() -> Self {
+        static NEXT: AtomicU64 = AtomicU64::new(0);
+        Self(NEXT.fetch_add(1, Ordering::Relaxed))
+    }
+}
+
+#[derive(Default)]
+struct QueryEntry {
+    data: Option<Erased>,
+    error: Option<Erased>,
+    // 最近一次获取完成（无论成败）的时间。
+    fetched_at: Option<Instant>,
+    invalidated: bool,
+    in_flight: Option<InFlight>,
+    // 进行中获取的编号，0 表示没有。获取被放弃（失效、移除、订阅者全部离开）后编号作废，
+    // 之后才完成的旧获取不再写入缓存。
+    fetch_id: u64,
+    // 数据 / 错误 / 获取状态 / 失效标记任一变化都会递增，订阅者据此重渲。
+    version: u64,
+    observers: HashMap<ObserverId, Option<Waker>>,
+    // 最后一个订阅者离开的时间，用于 `cache_time` 回收。
+    unused_since: Option<Instant>,
+}
+
+impl QueryEntry {
+    fn bump(&mut self) {
+        self.version += 1;
+        for waker in self.observers.values_mut().filter_map(Option::take) {
+            waker.wake();
+        }
+    }
+
+    // 放弃进行中的获取，下次 `fetch` 发起新的请求。返回是否确有获取被放弃。
+    fn abandon_fetch(&mut self) -> bool {
+        self.fetch_id = 0;
+        self.in_flight.take().is_some()
+    }
+
+    fn is_stale(&self, options: &QueryOptions, now: Instant) -> bool {
+        self.invalidated
+            || self
+                .fetched_at
+                .is_none_or(|at| now.duration_since(at) >= options.stale_time)
+    }
+}
+
+#[derive(Default)]
+struct ClientInner {
+    entries: HashMap<String, QueryEntry>,
+    defaults: QueryOptions,
+    clock: Clock,
+    // 上一次发起获取的编号，从 1 开始分配，0 表示条目未发起过获取。
+    last_fetch_id: u64,
+}
+
+impl ClientInner {
+    // 回收没有订阅者且超过 `cache_time` 的条目。
+    fn collect_garbage(&mut self, now: Instant) {
+        let cache_time = self.defaults.cache_time;
+        self.entries.retain(|_, entry| {
+            entry
+                .unused_since
+                .is_none_or(|since| now.duration_since(since) < cache_time)
+        });
+    }
+}
+
+// 查询缓存。`clone` 共享同一份缓存；只能在渲染线程使用。
+#[derive(Clone, Default)]
+pub struct QueryClient {
+    inner: Rc<RefCell<ClientInner>>,
+}
+
+impl QueryClient {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // 以给定的默认策略创建，`use_query` 未指定策略时使用。
+    pub fn with_defaults(defaults: QueryOptions) -> Self {
+        Self {
+            inner: Rc::new(RefCell::new(ClientInner {
+                defaults,
+                ..ClientInner::default()
+            })),
+        }
+    }
+
+    // 读组件树时钟的默认实例（挂在 `SystemContext`）。
+    pub(crate) fn with_clock(clock: Clock) -> Self {
+        Self {
+            inner: Rc::new(RefCell::new(ClientInner {
+                clock,
+                ..ClientInner::default()
+            })),
+        }
+    }
+
+    fn now(&self) -> Instant {
+        self.inner.borrow().clock.now()
+    }
+
+    pub fn defaults(&self) -> QueryOptions {
+        self.inner.borrow().defaults
+    }
+
+    // 读取缓存数据（可能已过期）。
+    pub fn get_query_data<T: Clone + 'static>(&self, key: &str) -> Option<T> {
+        let inner = self.inner.borrow();
+        let data = inner.entries.get(key)?.data.as_ref()?;
+        Some(downcast::<T>(data, key).clone())
+    }
+
+    // 直接写入缓存数据并视为刚获取，唤醒订阅者。
+    pub fn set_query_data<T: 'static>(&self, key: impl Into<String>, data: T) {
+        let now = self.now();
+        self.update_entry(key.into(), |entry| {
+            entry.data = Some(Rc::new(data));
+            entry.error = None;
+            entry.fetched_at = Some(now);
+            entry.invalidated = false;
+        });
+    }
+
+    // 以旧值计算新值写入缓存；返回写入前的值，便于调用方回滚。
+    pub fn update_query_data<T, F>(&self, key: impl Into<String>, f: F) -> Option<T>
+    where
+        T: Clone + 'static,
+        F: FnOnce(Option<&T>) -> T,
+    {
+        let key = key.into();
+        let previous = self.get_query_data::<T>(&key);
+        self.set_query_data(key, f(previous.as_ref()));
+        previous
+    }
+
+    // 使 key 失效：订阅者保留旧数据并在后台重新获取。失效前发起的获取可能读到旧数据，
+    // 随之放弃。
+    pub fn invalidate(&self, key: &str) {
+        let mut inner = self.inner.borrow_mut();
+        if let Some(entry) = inner.entries.get_mut(key) {
+            entry.invalidated = true;
+            entry.abandon_fetch();
+            entry.bump();
+        }
+    }
+
+    // 使所有以 `prefix` 开头的 key 失效（如 `"project/"`）。
+    pub fn invalidate_prefix(&self, prefix: &str) {
+        let mut inner = self.inner.borrow_mut();
+        for (_, entry) in inner
+            .entries
+            .iter_mut()
+            .filter(|(key, _)| key.starts_with(prefix))
+        {
+            entry.invalidated = true;
+            entry.abandon_fetch();
+            entry.bump();
+        }
+    }
+
+    // 移除 key 的缓存。订阅者会在下一帧重新获取。
+    pub fn remove(&self, key: &str) {
+        let mut inner = self.inner.borrow_mut();
+        if let Some(entry) = inner.entries.get_mut(key) {
+            *entry = QueryEntry {
+                observers: std::mem::take(&mut entry.observers),
+                version: entry.version,
+                ..QueryEntry::default()
+            };
+            entry.bump();
+        }
+    }
+
+    pub fn is_fetching(&self, key: &str) -> bool {
+        self.inner
+            .borrow()
+            .entries
+            .get(key)
+            .is_some_and(|entry| entry.in_flight.is_some())
+    }
+
+    // 获取 key 的数据。已有进行中的获取时复用它（去重），否则以 `fetcher` 发起新的获取。
+    // 完成后结果写入缓存并唤醒订阅者。
+    pub fn fetch<T, E, Fut>(
+        &self,
+        key: impl Into<String>,
+        fetcher: impl FnOnce() -> Fut,
+    ) -> LocalBoxFuture<'static, Result<T, E>>
+    where
+        T: Clone + 'static,
+        E: Clone + 'static,
+        Fut: Future<Output = Result<T, E>> + 'static,
+    {
+        let key = key.into();
+        let existing = self
+            .inner
+            .borrow()
+            .entries
+            .get(&key)
+            .and_then(|entry| entry.in_flight.clone());
+        // `fetcher` 在借用之外调用：它可能读缓存。
+        let shared = existing.unwrap_or_else(|| {
+            let client = self.clone();
+            let request = fetcher();
+            let request_key = key.clone();
+            let fetch_id = {
+                let mut inner = self.inner.borrow_mut();
+                inner.last_fetch_id += 1;
+                inner.last_fetch_id
+            };
+            let in_flight = async move {
+                let result = request
+                    .await
+                    .map(|data| Rc::new(data) as Erased)
+                    .map_err(|err| Rc::new(err) as Erased);
+                client.complete(&request_key, fetch_id, &result);
+                result
+            }
+            .boxed_local()
+            .shared();
+            let in_flight_clone = in_flight.clone();
+            self.update_entry(key.clone(), |entry| {
+                entry.in_flight = Some(in_flight_clone);
+                entry.fetch_id = fetch_id;
+            });
+            in_flight
+        });
+        shared
+            .map(move |result| {
+                result
+                    .map(|data| downcast::<T>(&data, &key).clone())
+                    .map_err(|err| downcast::<E>(&err, &key).clone())
+            })
+            .boxed_local()
+    }
+
+    fn complete(&self, key: &str, fetch_id: u64, result: &Result<Erased, Erased>) {
+        let now = self.now();
+        let mut inner = self.inner.borrow_mut();
+        let Some(entry) = inner.entries.get_mut(key) else {
+            return;
+        };
+        // 已被放弃（失效、移除或订阅者全部离开）：结果可能已过时，丢弃。
+        if entry.fetch_id != fetch_id {
+            return;
+        }
+        match result {
+            Ok(data) => {
+                entry.data = Some(data.clone());
+                entry.error = None;
+            }
+            Err(err) => entry.error = Some(err.clone()),
+        }
+        entry.fetched_at = Some(now);
+        entry.invalidated = false;
+        entry.fetch_id = 0;
+        entry.in_flight = None;
+        entry.bump();
+    }
+
+    fn update_entry(&self, key: String, f: impl FnOnce(&mut QueryEntry)) {
+        let mut inner = self.inner.borrow_mut();
+        let entry = inner.entries.entry(key).or_default();
+        f(entry);
+        entry.bump();
+    }
+
+    pub(crate) fn subscribe(&self, key: &str, observer: ObserverId) {
+        let mut inner = self.inner.borrow_mut();
+        let now = inner.clock.now();
+        inner.collect_garbage(now);
+        let entry = inner.entries.entry(key.to_string()).or_default();
+        entry.observers.entry(observer).or_default();
+        entry.unused_since = None;
+    }
+
+    pub(crate) fn unsubscribe(&self, key: &str, observer: ObserverId) {
+        let now = self.now();
+        let mut inner = self.inner.borrow_mut();
+        if let Some(entry) = inner.entries.get_mut(key) {
+            entry.observers.remove(&observer);
+            if entry.observers.is_empty() {
+                entry.unused_since = Some(now);
+                // 获取由订阅者轮询推进，没人轮询就不会完成：放弃它，下一个订阅者重新获取。
+                if entry.abandon_fetch() {
+                    entry.bump();
+                }
+            }
+        }
+    }
+
+    pub(crate) fn version(&self, key: &str) -> u64 {
+        self.inner
+            .borrow()
+            .entries
+            .get(key)
+            .map_or(0, |entry| entry.version)
+    }
+
+    // 订阅者轮询：版本已变返回 `true`，否则登记 waker 等待下次变化。
+    pub(crate) fn poll_version(
+        &self,
+        key: &str,
+        observer: ObserverId,
+        seen: u64,
+        waker: &Waker,
+    ) -> bool {
+        let mut inner = self.inner.borrow_mut();
+        let Some(entry) = inner.entries.get_mut(key) else {
+            return false;
+        };
+        if entry.version != seen {
+            return true;
+        }
+        entry.observers.insert(observer, Some(waker.clone()));
+        false
+    }
+
+    // 订阅者在 update 期读取的一帧快照。
+    pub(crate) fn snapshot<T, E>(&self, key: &str, options: &QueryOptions) -> QuerySnapshot<T, E>
+    where
+        T: Clone + 'static,
+        E: Clone + 'static,
+    {
+        let inner = self.inner.borrow();
+        let Some(entry) = inner.entries.get(key) else {
+            return QuerySnapshot::default();
+        };
+        QuerySnapshot {
+            data: entry
+                .data
+                .as_ref()
+                .map(|data| downcast::<T>(data, key).clone()),
+            error: entry
+                .error
+                .as_ref()
+                .map(|err| downcast::<E>(err, key).clone()),
+            is_fetching: entry.in_flight.is_some(),
+            is_stale: entry.is_stale(options, inner.clock.now()),
+            needs_fetch: entry.invalidated || entry.fetched_at.is_none(),
+        }
+    }
+}
+
+pub(crate) struct QuerySnapshot<T, E> {
+    pub(crate) data: Option<T>,
+    pub(crate) error: Option<E>,
+    pub(crate) is_fetching: bool,
+    pub(crate) is_stale: bool,
+    // 从未获取过或已被显式失效：无论是否新挂载都应获取。
+    pub(crate) needs_fetch: bool,
+}
+
+impl<T, E> Default for QuerySnapshot<T, E> {
+    fn default() -> Self {
+        Self {
+            data: None,
+            error: None,
+            is_fetching: false,
+            is_stale: true,
+            needs_fetch: true,
+        }
+    }
+}
+
+fn downcast<'a, T: 'static>(value: &'a Erased, key: &str) -> &'a T {
+    value.downcast_ref::<T>().unwrap_or_else(|| {
+        panic!(
+            "query `{key}` holds a value of a different type than `{}`",
+            std::any::type_name::<T>()
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::{channel::oneshot, executor::block_on, future::join};
+
+    #[test]
+    fn concurrent_fetches_share_one_request() {
+        let client = QueryClient::new();
+        let calls = Rc::new(RefCell::new(0));
+        let (tx, rx) = oneshot::channel::<u32>();
+        let rx = rx.shared();
+
+        let fetcher = || {
+            *calls.borrow_mut() += 1;
+            let rx = rx.clone();
+            async move { Ok::<_, String>(rx.await.unwrap()) }
+        };
+        let first = client.fetch("answer", fetcher);
+        let second = client.fetch("answer", fetcher);
+        assert!(client.is_fetching("answer"));
+
+        tx.send(42).unwrap();
+        let (first, second) = block_on(join(first, second));
+        assert_eq!((first, second), (Ok(42), Ok(42)));
+        assert_eq!(*calls.borrow(), 1);
+        assert_eq!(client.get_query_data::<u32>("answer"), Some(42));
+        assert!(!client.is_fetching("answer"));
+    }
+
+    #[test]
+    fn invalidate_marks_matching_keys_stale() {
+        let client = QueryClient::with_defaults(QueryOptions {
+            stale_time: Duration::from_secs(60),
+            ..Default::default()
+        });
+        client.set_query_data("project/1", 1u32);
+        client.set_query_data("user/1", 2u32);
+        let options = client.defaults();
+        let stale = |key| client.snapshot::<u32, ()>(key, &options).is_stale;
+
+        assert!(!stale("project/1"));
+        client.invalidate_prefix("project/");
+        assert!(stale("project/1"));
+        assert!(!stale("user/1"));
+    }
+
+    #[test]
+    fn unobserved_entries_are_collected_after_cache_time() {
+        let client = QueryClient::with_defaults(QueryOptions {
+            cache_time: Duration::ZERO,
+            ..Default::default()
+        });
+        let observer = ObserverId::next();
+        client.subscribe("a", observer);
+        client.set_query_data("a", 1u32);
+        client.unsubscribe("a", observer);
+        assert_eq!(client.get_query_data::<u32>("a"), Some(1));
+
+        client.subscribe("b", ObserverId::next());
+        assert_eq!(client.get_query_data::<u32>("a"), None);
+    }
+
+    #[test]
+    fn invalidating_during_a_fetch_starts_a_new_one() {
+        let client = QueryClient::new();
+        let (old_tx, old_rx) = oneshot::channel::<u32>();
+        let old = client.fetch("a", || async move { old_rx.await.map_err(|_| ()) });
+        client.invalidate("a");
+        assert!(!client.is_fetching("a"), "失效前发起的获取应被放弃");
+
+        let (new_tx, new_rx) = oneshot::channel::<u32>();
+        let new = client.fetch("a", || async move { new_rx.await.map_err(|_| ()) });
+        old_tx.send(1).unwrap();
+        assert_eq!(block_on(old), Ok(1));
+        assert_eq!(
+            client.get_query_data::<u32>("a"),
+            None,
+            "被放弃的获取不写入缓存"
+        );
+        assert!(client.is_fetching("a"));
+
+        new_tx.send(2).unwrap();
+        assert_eq!(block_on(new), Ok(2));
+        assert_eq!(client.get_query_data::<u32>("a"), Some(2));
+        let options = client.defaults();
+        assert!(!client.snapshot::<u32, ()>("a", &options).needs_fetch);
+    }
+
+    #[test]
+    fn last_observer_leaving_abandons_the_fetch() {
+        let client = QueryClient::with_defaults(QueryOptions {
+            cache_time: Duration::ZERO,
+            ..Default::default()
+        });
+        let observer = ObserverId::next();
+        client.subscribe("a", observer);
+        let (abandon, rx) = oneshot::channel::<u32>();
+        let abandoned = client.fetch("a", || async move { rx.await.map_err(|_| ()) });
+        assert!(client.is_fetching("a"));
+
+        client.unsubscribe("a", observer);
+        assert!(!client.is_fetching("a"));
+        client.subscribe("b", ObserverId::next());
+        assert!(!client.inner.borrow().entries.contains_key("a"));
+
+        // 放弃的获取若仍被轮询至完成，不应清掉之后发起的获取。
+        client.subscribe("a", observer);
+        let (_tx, rx) = oneshot::channel::<u32>();
+        let _pending = client.fetch("a", || async move { rx.await.map_err(|_| ()) });
+        drop(abandon);
+        assert_eq!(block_on(abandoned), Err(()));
+        assert!(client.is_fetching("a"));
+    }
+}
@@ -0,0 +1,140 @@
+use std::{cell::RefCell, future::Future, rc::Rc, task::Poll};
+
+use futures::{FutureExt, StreamExt, future::LocalBoxFuture, stream::FuturesUnordered};
+
+use super::{QueryClient, UseQuery};
+use crate::{Hook, Hooks, State, UseState};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::Hooks<'_, '_> {}
+}
+
+struct MutationState<E> {
+    pending: usize,
+    error: Option<E>,
+}
+
+// `use_mutation` 返回的句柄，可 `clone` 移入事件 handler。
+pub struct Mutation<E>
+where
+    E: Unpin + Send + Sync + 'static,
+{
+    client: QueryClient,
+    requests: Rc<RefCell<FuturesUnordered<LocalBoxFuture<'static, ()>>>>,
+    state: State<MutationState<E>>,
+}
+
+impl<E> Clone for Mutation<E>
+where
+    E: Unpin + Send + Sync + 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            client: self.client.clone(),
+            requests: self.requests.clone(),
+            state: self.state,
+        }
+    }
+}
+
+impl<E> Mutation<E>
+where
+    E: Unpin + Send + Sync + 'static,
+{
+    // 乐观更新 `key` 的缓存并发出 `request`。
+    //
+    // `update` 以当前缓存值计算新值，立即写入缓存；`request` 失败时缓存回滚到更新前的值并记录错误。
+    // 无论成败，结束后都令 `key` 失效，订阅者据此重新获取服务端的真实数据。
+    pub fn mutate<T, U, Fut>(&self, key: impl Into<String>, update: U, request: Fut)
+    where
+        T: Clone + 'static,
+        U: FnOnce(Option<&T>) -> T,
+        Fut: Future<Output = Result<(), E>> + 'static,
+    {
+        let key = key.into();
+        let previous = self.client.update_query_data(key.clone(), update);
+        let client = self.client.clone();
+        let state = self.state;
+        self.requests.borrow_mut().push(
+            async move {
+                let result = request.await;
+                if let Err(err) = result {
+                    match previous {
+                        Some(previous) => client.set_query_data(key.clone(), previous),
+                        None => client.remove(&key),
+                    }
+                    state.write().error = Some(err);
+                }
+                client.invalidate(&key);
+                state.write().pending -= 1;
+            }
+            .boxed_local(),
+        );
+        // 写入同时唤醒组件，hook 在随后的 `poll_change` 中开始推进新请求。
+        let mut state = self.state.write();
+        state.pending += 1;
+        state.error = None;
+    }
+
+    // 是否有尚未完成的请求。
+    pub fn is_pending(&self) -> bool {
+        self.state.read().pending > 0
+    }
+
+    // 最近一次失败的错误；下一次 `mutate` 时清空。
+    pub fn error(&self) -> Option<E>
+    where
+        E: Clone,
+    {
+        self.state.read().error.clone()
+    }
+
+    pub fn reset(&self) {
+        self.state.write().error = None;
+    }
+}
+
+pub trait UseMutation: private::Sealed {
+    // 创建一个修改查询缓存的句柄。进行中的请求随组件卸载一并取消。
+    fn use_mutation<E>(&mut self) -> Mutation<E>
+    where
+        E: Unpin + Send + Sync + 'static;
+}
+
+struct UseMutationImpl {
+    requests: Rc<RefCell<FuturesUnordered<LocalBoxFuture<'static, ()>>>>,
+}
+
+impl Hook for UseMutationImpl {
+    fn poll_change(&mut self, cx: &mut std::task::Context) -> Poll<()> {
+        let mut requests = self.requests.borrow_mut();
+        while let Poll::Ready(Some(())) = requests.poll_next_unpin(cx) {}
+        // 结果经 `State` 与缓存版本通知，这里无需额外触发重渲。
+        Poll::Pending
+    }
+}
+
+impl UseMutation for Hooks<'_, '_> {
+    fn use_mutation<E>(&mut self) -> Mutation<E>
+    where
+        E: Unpin + Send + Sync + 'static,
+    {
+        let client = self.use_query_client();
+        let state = self.use_state(|| MutationState {
+            pending: 0,
+            error: None,
+        });
+        let requests = self
+            .use_hook(|| UseMutationImpl {
+                requests: Rc::default(),
+            })
+            .requests
+            .clone();
+        Mutation {
+            client,
+            requests,
+            state,
+        }
+    }
+}
@@ -0,0 +1,215 @@
+use std::{future::Future, rc::Rc, task::Poll};
+
+use futures::{FutureExt, future::LocalBoxFuture};
+
+use super::{ObserverId, QueryClient, QueryOptions};
+use crate::{Hook, Hooks, SystemContext, UseContext};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::Hooks<'_, '_> {}
+}
+
+// `use_query` 在本帧读到的查询结果。`refetch` 等操作经共享缓存生效，可 `clone` 移入 handler。
+#[derive(Clone)]
+pub struct Query<T, E> {
+    client: QueryClient,
+    key: String,
+    data: Option<T>,
+    error: Option<E>,
+    is_fetching: bool,
+    is_stale: bool,
+}
+
+impl<T, E> Query<T, E> {
+    // 缓存中的数据（可能已过期，后台正在重新获取）。
+    pub fn data(&self) -> Option<&T> {
+        self.data.as_ref()
+    }
+
+    // 最近一次获取失败的错误；之后获取成功时清空。
+    pub fn error(&self) -> Option<&E> {
+        self.error.as_ref()
+    }
+
+    // 尚无数据且正在获取（首次加载）。
+    pub fn is_loading(&self) -> bool {
+        self.data.is_none() && self.is_fetching
+    }
+
+    // 正在获取，包括已有数据时的后台重新验证。
+    pub fn is_fetching(&self) -> bool {
+        self.is_fetching
+    }
+
+    pub fn is_stale(&self) -> bool {
+        self.is_stale
+    }
+
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    // 令本 key 失效并重新获取，所有订阅者共享结果。
+    pub fn refetch(&self) {
+        self.client.invalidate(&self.key);
+    }
+
+    pub fn client(&self) -> &QueryClient {
+        &self.client
+    }
+}
+
+pub trait UseQuery: private::Sealed {
+    // 当前生效的查询缓存：最近的 `QueryClient` context，没有则用组件树默认实例。
+    fn use_query_client(&mut self) -> QueryClient;
+
+    // 订阅 `key` 的查询，采用缓存的默认策略。`fetcher` 仅在需要发起新的获取时调用。
+    fn use_query<T, E, F, Fut>(&mut self, key: impl Into<String>, fetcher: F) -> Query<T, E>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<T, E>> + 'static,
+        T: Clone + 'static,
+        E: Clone + 'static;
+
+    // 指定缓存策略的 `use_query`。`cache_time` 以缓存默认策略为准。
+    fn use_query_with<T, E, F, Fut>(
+        &mut self,
+        key: impl Into<String>,
+        options: QueryOptions,
+        fetcher: F,
+    ) -> Query<T, E>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<T, E>> + 'static,
+        T: Clone + 'static,
+        E: Clone + 'static;
+}
+
+// 跨帧持有订阅：key / client 变化时换订阅，卸载时退订。
+struct UseQueryImpl {
+    observer: ObserverId,
+    subscription: Option<(QueryClient, String)>,
+    // 本帧已读到的缓存版本，版本变化即重渲。
+    seen: u64,
+    // 本 key 进行中的获取。多个订阅者各持一份（同一次请求），任一订阅者在场就能推进它。
+    request: Option<LocalBoxFuture<'static, ()>>,
+}
+
+impl Default for UseQueryImpl {
+    fn default() -> Self {
+        Self {
+            observer: ObserverId::next(),
+            subscription: None,
+            seen: 0,
+            request: None,
+        }
+    }
+}
+
+impl UseQueryImpl {
+    // 返回是否为新订阅（首次挂载或 key / client 变化）。
+    fn observe(&mut self, client: &QueryClient, key: &str) -> bool {
+        if let Some((current_client, current_key)) = &self.subscription
+            && current_key == key
+            && Rc::ptr_eq(&current_client.inner, &client.inner)
+        {
+            return false;
+        }
+        self.unsubscribe();
+        client.subscribe(key, self.observer);
+        self.subscription = Some((client.clone(), key.to_string()));
+        true
+    }
+
+    fn unsubscribe(&mut self) {
+        self.request = None;
+        if let Some((client, key)) = self.subscription.take() {
+            client.unsubscribe(&key, self.observer);
+        }
+    }
+}
+
+impl Hook for UseQueryImpl {
+    fn poll_change(&mut self, cx: &mut std::task::Context) -> Poll<()> {
+        if let Some(request) = self.request.as_mut()
+            && request.as_mut().poll(cx).is_ready()
+        {
+            self.request = None;
+        }
+        match &self.subscription {
+            Some((client, key))
+                if client.poll_version(key, self.observer, self.seen, cx.waker()) =>
+            {
+                Poll::Ready(())
+            }
+            _ => Poll::Pending,
+        }
+    }
+
+    fn on_drop(&mut self) {
+        self.unsubscribe();
+    }
+}
+
+impl UseQuery for Hooks<'_, '_> {
+    fn use_query_client(&mut self) -> QueryClient {
+        if let Some(client) = self.try_use_context::<QueryClient>() {
+            return client.clone();
+        }
+        self.use_context::<SystemContext>().query.clone()
+    }
+
+    fn use_query<T, E, F, Fut>(&mut self, key: impl Into<String>, fetcher: F) -> Query<T, E>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<T, E>> + 'static,
+        T: Clone + 'static,
+        E: Clone + 'static,
+    {
+        let options = self.use_query_client().defaults();
+        self.use_query_with(key, options, fetcher)
+    }
+
+    fn use_query_with<T, E, F, Fut>(
+        &mut self,
+        key: impl Into<String>,
+        options: QueryOptions,
+        fetcher: F,
+    ) -> Query<T, E>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<T, E>> + 'static,
+        T: Clone + 'static,
+        E: Clone + 'static,
+    {
+        let client = self.use_query_client();
+        let key = key.into();
+
+        let hook = self.use_hook(UseQueryImpl::default);
+        let mounted = hook.observe(&client, &key);
+        let snapshot = client.snapshot::<T, E>(&key, &options);
+
+        // 过期数据只在新订阅时重新验证，失效 / 无数据时总是获取；
+        // 已有进行中的获取（别的订阅者发起）则 `fetch` 直接复用，不调用 `fetcher`。
+        // 需要获取而缓存中没有进行中的获取：本 hook 持有的请求已被放弃（失效、移除），换新的。
+        if snapshot.needs_fetch && !snapshot.is_fetching {
+            hook.request = None;
+        }
+        let should_fetch =
+            snapshot.is_fetching || snapshot.needs_fetch || (mounted && snapshot.is_stale);
+        if should_fetch && hook.request.is_none() {
+            hook.request = Some(client.fetch(key.clone(), fetcher).map(drop).boxed_local());
+        }
+        hook.seen = client.version(&key);
+
+        Query {
+            is_fetching: snapshot.is_fetching || hook.request.is_some(),
+            client,
+            key,
+            data: snapshot.data,
+            error: snapshot.error,
+            is_stale: snapshot.is_stale,
+        }
+    }
+}
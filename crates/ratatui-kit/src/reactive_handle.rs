@@ -1,15 +1,17 @@
 use std::{
+    cell::RefCell,
     cmp,
     collections::HashMap,
     fmt::{self, Debug, Display, Formatter},
     hash::{Hash, Hasher},
     ops::{Deref, DerefMut},
+    sync::Arc,
     task::{Context, Poll, Waker},
 };
 
-use generational_box::{AnyStorage, GenerationalBox, Owner, SyncStorage};
+use generational_box::{AnyStorage, GenerationalBox, GenerationalBoxId, Owner, SyncStorage};
 
-use crate::ElementKey;
+use crate::{Computed, ElementKey};
 
 #[doc(hidden)]
 pub trait Notifier: Default + Send + Sync + 'static {
@@ -28,7 +30,7 @@ pub struct SingleWaker {
 impl Notifier for SingleWaker {
     fn wake(&mut self) {
         if let Some(waker) = self.waker.take() {
-            waker.wake();
+            notify(waker);
         }
     }
 
@@ -50,7 +52,7 @@ pub struct WakerMap {
 impl Notifier for WakerMap {
     fn wake(&mut self) {
         for waker in self.wakers.values() {
-            waker.wake_by_ref();
+            notify(waker.clone());
         }
     }
 
@@ -69,11 +71,165 @@ impl Notifier for WakerMap {
     }
 }
 
+// 批量更新：批内的状态写入立即生效，但唤醒推迟到最外层批次结束时统一发出，同一 waker 只唤醒一次，
+// 渲染循环每批只看到一次变更。批次按线程计，只推迟当前线程上的写入；可嵌套。
+// 事件分发与 hook 轮询（`use_future` 等持有的 future）已自动处于批次中。
+pub fn batch<R>(f: impl FnOnce() -> R) -> R {
+    let _batch = BatchGuard::enter();
+    f()
+}
+
+#[derive(Default)]
+struct Batch {
+    depth: usize,
+    pending: Vec<Waker>,
+}
+
+thread_local! {
+    static BATCH: RefCell<Batch> = RefCell::default();
+}
+
+// 批次守卫：drop 时（包括 panic 展开）退出批次，最外层退出时发出积攒的唤醒。
+pub(crate) struct BatchGuard(());
+
+impl BatchGuard {
+    pub(crate) fn enter() -> Self {
+        BATCH.with_borrow_mut(|batch| batch.depth += 1);
+        BatchGuard(())
+    }
+}
+
+impl Drop for BatchGuard {
+    fn drop(&mut self) {
+        let pending = BATCH.with_borrow_mut(|batch| {
+            batch.depth -= 1;
+            if batch.depth == 0 {
+                std::mem::take(&mut batch.pending)
+            } else {
+                Vec::new()
+            }
+        });
+        // 先释放借用再唤醒：waker 可能同步触发新的状态写入。
+        pending.into_iter().for_each(Waker::wake);
+    }
+}
+
+// 批次内记下 waker（去重），否则立即唤醒。
+fn notify(waker: Waker) {
+    let waker = BATCH.with_borrow_mut(|batch| {
+        if batch.depth == 0 {
+            return Some(waker);
+        }
+        if !batch
+            .pending
+            .iter()
+            .any(|pending| pending.will_wake(&waker))
+        {
+            batch.pending.push(waker);
+        }
+        None
+    });
+    if let Some(waker) = waker {
+        waker.wake();
+    }
+}
+
+// 读取追踪：`track` 期间读取的响应式句柄记为依赖，`Computed` / `use_computed` 据此判断何时重算。
+// 依赖以版本号比较：每次带通知的写入使版本加一，并唤醒登记的观察者。
+pub(crate) trait Source: Send + Sync {
+    fn id(&self) -> GenerationalBoxId;
+    // 当前版本；句柄已失效时为 `None`。
+    fn version(&self) -> Option<u64>;
+    // 下一次变更时唤醒 `waker`。
+    fn observe(&self, waker: &Waker);
+}
+
+// 一次求值读到的依赖及当时的版本。
+#[derive(Clone)]
+pub(crate) struct Dependency {
+    source: Arc<dyn Source>,
+    version: Option<u64>,
+}
+
+impl Dependency {
+    pub(crate) fn is_stale(&self) -> bool {
+        self.source.version() != self.version
+    }
+
+    pub(crate) fn observe(&self, waker: &Waker) {
+        self.source.observe(waker);
+    }
+}
+
+thread_local! {
+    // 嵌套求值各占一层。
+    static TRACKING: RefCell<Vec<Vec<Dependency>>> = const { RefCell::new(Vec::new()) };
+}
+
+// 追踪层守卫：正常结束时取出依赖，panic 展开时丢弃本层。
+struct TrackingFrame(());
+
+impl TrackingFrame {
+    fn finish(self) -> Vec<Dependency> {
+        let dependencies = TRACKING.with_borrow_mut(Vec::pop).unwrap_or_default();
+        std::mem::forget(self);
+        dependencies
+    }
+}
+
+impl Drop for TrackingFrame {
+    fn drop(&mut self) {
+        TRACKING.with_borrow_mut(Vec::pop);
+    }
+}
+
+// 运行 `f` 并返回其间读取的依赖。
+pub(crate) fn track<R>(f: impl FnOnce() -> R) -> (R, Vec<Dependency>) {
+    TRACKING.with_borrow_mut(|stack| stack.push(Vec::new()));
+    let frame = TrackingFrame(());
+    let value = f();
+    (value, frame.finish())
+}
+
+// 把依赖记入当前追踪层（无追踪时为空操作），按句柄去重。
+pub(crate) fn record(dependencies: impl IntoIterator<Item = Dependency>) {
+    TRACKING.with_borrow_mut(|stack| {
+        let Some(frame) = stack.last_mut() else {
+            return;
+        };
+        for dependency in dependencies {
+            let id = dependency.source.id();
+            if !frame.iter().any(|existing| existing.source.id() == id) {
+                frame.push(dependency);
+            }
+        }
+    });
+}
+
+fn is_tracking() -> bool {
+    TRACKING.with_borrow(|stack| !stack.is_empty())
+}
+
 #[doc(hidden)]
 pub struct ReactiveValue<T, N> {
     value: T,
     notifier: N,
     is_changed: bool,
+    version: u64,
+    // 追踪此值的 `use_computed` 等观察者，变更时全部唤醒并清空。
+    observers: Vec<Waker>,
+}
+
+impl<T, N: Notifier> ReactiveValue<T, N> {
+    // 记一次变更：标记待重渲、推进版本并唤醒订阅者与观察者。
+    fn mark_changed(&mut self) {
+        self.is_changed = true;
+        self.version += 1;
+        self.notifier.wake();
+        for observer in self.observers.drain(..) {
+            notify(observer);
+        }
+    }
 }
 
 // 响应式状态核心句柄。
@@ -113,23 +269,67 @@ where
                 value,
                 notifier: N::default(),
                 is_changed: false,
+                version: 0,
+                observers: Vec::new(),
             }),
         }
     }
 
-    // 仅 `use_atom`(atom 特性)用于参数同步/退订,故随 atom 特性门控,避免无特性时的 dead_code 警告。
-    #[cfg(feature = "atom")]
+    // `use_atom` 与响应式上下文的消费者据此判断句柄是否换了底层存储，换了则退订旧的。
     pub(crate) fn same_storage(&self, other: &Self) -> bool {
         self.inner.ptr_eq(&other.inner)
     }
 
+    // 不改值地通知一次变更（派生原子的来源变化时使用）。
     #[cfg(feature = "atom")]
+    pub(crate) fn mark_changed(&self) {
+        if let Ok(mut value) = self.inner.try_write() {
+            value.mark_changed();
+        }
+    }
+
+    // 以 `key` 订阅变更而不消费变更标记，供 `use_atom_selector` / `use_context_selector`
+    // 自行比较选取结果。
+    pub(crate) fn subscribe(&self, key: &ElementKey, waker: &Waker) {
+        if let Ok(mut value) = self.inner.try_write() {
+            value.notifier.register(Some(key), waker.clone());
+        }
+    }
+
     pub(crate) fn remove_waker(&self, key: &ElementKey) {
         if let Ok(mut value) = self.inner.try_write() {
             value.notifier.remove(key);
         }
     }
 
+    // 版本号与 `seen` 不同时更新 `seen` 并以 `f` 读取当前值，否则以 `key` 登记 waker；
+    // 检查与登记在同一把写锁下完成，不会漏掉其间的变更。句柄失效时返回 `Ready(None)`。
+    #[cfg(feature = "atom")]
+    pub(crate) fn poll_version<R>(
+        &self,
+        key: &ElementKey,
+        seen: &mut u64,
+        cx: &mut Context<'_>,
+        f: impl FnOnce(&T) -> R,
+    ) -> Poll<Option<R>> {
+        let Ok(mut value) = self.inner.try_write() else {
+            return Poll::Ready(None);
+        };
+        if value.version != *seen {
+            *seen = value.version;
+            return Poll::Ready(Some(f(&value.value)));
+        }
+        value.notifier.register(Some(key), cx.waker().clone());
+        Poll::Pending
+    }
+
+    // 释放底层存储（`AtomFamily::remove` 使用）：先唤醒订阅者，之后句柄失效。
+    #[cfg(feature = "atom")]
+    pub(crate) fn dispose(&self) {
+        self.mark_changed();
+        self.inner.manually_drop();
+    }
+
     #[cfg(test)]
     pub(crate) fn has_waker(&self, key: &ElementKey) -> bool
     where
@@ -145,7 +345,11 @@ where
         if let Ok(mut value) = self.inner.try_write() {
             if value.is_changed {
                 value.is_changed = false;
-                value.notifier.clear();
+                // 带 key 时只撤下自己的登记：`WakerMap` 上还有组件树外的订阅（`Atom::subscribe`）。
+                match key {
+                    Some(key) => value.notifier.remove(key),
+                    None => value.notifier.clear(),
+                }
                 Poll::Ready(())
             } else {
                 value.notifier.register(key, cx.waker().clone());
@@ -156,8 +360,14 @@ where
         }
     }
 
-    // 尝试获取只读引用，失败时返回 None。
+    // 尝试获取只读引用，失败时返回 None。在 `use_computed` 等求值中读取会记为依赖。
     pub fn try_read(&'_ self) -> Option<ReactiveRef<'_, T, N>> {
+        if is_tracking() {
+            record([Dependency {
+                source: Arc::new(*self),
+                version: Source::version(self),
+            }]);
+        }
         self.inner
             .try_read()
             .ok()
@@ -216,6 +426,47 @@ where
     }
 }
 
+impl<T, N> ReactiveHandle<T, N>
+where
+    T: Send + Sync + 'static,
+    N: Notifier,
+{
+    // 派生出只读的计算值：`f` 只在本句柄变更后重新调用。
+    pub fn map<U, F>(&self, f: F) -> Computed<U>
+    where
+        U: Clone + 'static,
+        F: Fn(&T) -> U + 'static,
+    {
+        let handle = *self;
+        Computed::new(move || f(&handle.read()))
+    }
+}
+
+impl<T, N> Source for ReactiveHandle<T, N>
+where
+    T: Send + Sync + 'static,
+    N: Notifier,
+{
+    fn id(&self) -> GenerationalBoxId {
+        self.inner.id()
+    }
+
+    fn version(&self) -> Option<u64> {
+        self.inner.try_read().ok().map(|value| value.version)
+    }
+
+    fn observe(&self, waker: &Waker) {
+        if let Ok(mut value) = self.inner.try_write()
+            && !value
+                .observers
+                .iter()
+                .any(|observer| observer.will_wake(waker))
+        {
+            value.observers.push(waker.clone());
+        }
+    }
+}
+
 #[cfg(test)]
 pub(crate) trait WakerLookup {
     fn has_waker(&self, key: &ElementKey) -> bool;
@@ -309,8 +560,7 @@ where
 {
     fn drop(&mut self) {
         if self.is_deref_mut {
-            self.inner.is_changed = true;
-            self.inner.notifier.wake();
+            self.inner.mark_changed();
         }
     }
 }
@@ -518,3 +768,67 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    };
+
+    struct CountingWaker(AtomicUsize);
+
+    impl std::task::Wake for CountingWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    fn counting_waker() -> (Arc<CountingWaker>, Waker) {
+        let count = Arc::new(CountingWaker(AtomicUsize::new(0)));
+        (count.clone(), Waker::from(count))
+    }
+
+    // 登记 waker 后写入两个状态，返回唤醒次数。
+    fn write_twice(wrap: impl FnOnce(&mut dyn FnMut())) -> usize {
+        let owner = Owner::<SyncStorage>::default();
+        let mut a = ReactiveHandle::<i32, SingleWaker>::new_in(&owner, 0);
+        let mut b = ReactiveHandle::<i32, WakerMap>::new_in(&owner, 0);
+        let (count, waker) = counting_waker();
+        let mut cx = Context::from_waker(&waker);
+        let key = ElementKey::user("probe");
+        assert!(a.poll_change(None, &mut cx).is_pending());
+        assert!(b.poll_change(Some(&key), &mut cx).is_pending());
+        wrap(&mut || {
+            a.set(1);
+            b.set(2);
+        });
+        assert_eq!((a.get(), b.get()), (1, 2));
+        count.0.load(Ordering::SeqCst)
+    }
+
+    #[test]
+    fn writes_wake_immediately_outside_batch() {
+        assert_eq!(write_twice(|write| write()), 2);
+    }
+
+    #[test]
+    fn batch_defers_and_dedupes_wakes() {
+        assert_eq!(write_twice(|write| batch(write)), 1);
+    }
+
+    #[test]
+    fn nested_batch_wakes_when_outermost_ends() {
+        let owner = Owner::<SyncStorage>::default();
+        let mut state = ReactiveHandle::<i32, SingleWaker>::new_in(&owner, 0);
+        let (count, waker) = counting_waker();
+        let mut cx = Context::from_waker(&waker);
+        assert!(state.poll_change(None, &mut cx).is_pending());
+        batch(|| {
+            batch(|| state.set(1));
+            assert_eq!(count.0.load(Ordering::SeqCst), 0);
+        });
+        assert_eq!(count.0.load(Ordering::SeqCst), 1);
+    }
+}
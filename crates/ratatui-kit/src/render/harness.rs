@@ -8,7 +8,8 @@
 use crate::{
     AnyElement, ComponentDrawer, ElementRepr, render::tree::Tree, terminal::UpdaterTerminal,
 };
-use ratatui::buffer::Buffer;
+use crossterm::event::{Event, KeyEvent};
+use ratatui::{backend::TestBackend, buffer::Buffer};
 use std::io;
 
 // no-op 终端：`insert_before` 空操作。仅供驱动 update。事件不再经终端订阅（改由 `InputRuntime`)，
@@ -41,24 +42,53 @@ fn render_to_buffer_frames(
     let helper = el.helper();
     let mut tree = Tree::new(el.props_mut(), helper);
 
-    let mut noop = NoopTerminal;
-    let mut terminal =
-        ratatui::Terminal::new(ratatui::backend::TestBackend::new(width, height)).unwrap();
+    let mut terminal = ratatui::Terminal::new(TestBackend::new(width, height)).unwrap();
 
     for _ in 0..frames.max(1) {
-        tree.update_once(&mut noop);
-        terminal
-            .draw(|frame| {
-                let area = frame.area();
-                let mut drawer = ComponentDrawer::new(frame, area);
-                tree.draw_root(&mut drawer);
-            })
-            .unwrap();
+        frame(&mut tree, &mut terminal);
     }
 
     terminal.backend().buffer().clone()
 }
 
+// 驱动一帧：no-op 跑 update → 画到 `TestBackend`。多帧测试在帧间 `dispatch` / `poll_once`。
+pub(super) fn frame(tree: &mut Tree, terminal: &mut ratatui::Terminal<TestBackend>) {
+    tree.update_once(&mut NoopTerminal);
+    terminal
+        .draw(|frame| {
+            let area = frame.area();
+            let mut drawer = ComponentDrawer::new(frame, area);
+            tree.draw_root(&mut drawer);
+        })
+        .unwrap();
+}
+
+// 驱动一帧并返回首行文本（去掉行尾空白），供单行探针组件断言。
+pub(super) fn frame_line(tree: &mut Tree, terminal: &mut ratatui::Terminal<TestBackend>) -> String {
+    frame(tree, terminal);
+    row(terminal.backend().buffer(), 0).trim_end().to_string()
+}
+
+// 挂载一棵测试用组件树。树持有元素的 props，不再借用元素。
+pub(super) fn mount(el: impl Into<AnyElement<'static>>) -> Tree<'static> {
+    let el = el.into();
+    let helper = el.helper();
+    Tree::new(el.into_props(), helper)
+}
+
+// 派发一次按键；传 `KeyCode` 即不带修饰键。
+pub(super) fn press(tree: &mut Tree, key: impl Into<KeyEvent>) {
+    tree.dispatch(Event::Key(key.into()));
+}
+
+// 按键后轮询一次并以 no-op 终端重渲，返回按键是否请求了重渲。
+pub(super) fn press_and_update(tree: &mut Tree, key: impl Into<KeyEvent>) -> bool {
+    press(tree, key);
+    let changed = tree.poll_once();
+    tree.update_once(&mut NoopTerminal);
+    changed
+}
+
 // 把 Buffer 第 `y` 行拼成字符串，便于断言。
 fn row(buf: &Buffer, y: u16) -> String {
     (0..buf.area.width).map(|x| buf[(x, y)].symbol()).collect()
@@ -908,3 +938,1729 @@ mod router_tests {
         );
     }
 }
+
+// 鼠标:手动驱动帧并在帧间 `dispatch` 鼠标事件。命中依赖上一帧回填的区域,故先画一帧再点击。
+mod mouse_tests {
+    use super::{cell_style, find, frame, mount};
+    use crate::prelude::*;
+    use crossterm::event::{Event, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+    use ratatui::{
+        backend::TestBackend,
+        layout::{Constraint, Direction},
+        style::Modifier,
+    };
+    use ratatui_kit_macros::component;
+    use std::sync::Mutex;
+
+    static SELECTED: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+    // 每帧重建 `Select`,使 on_select 在每次 update 都存在。
+    #[component]
+    fn ClickableSelect(_hooks: Hooks) -> impl Into<AnyElement<'static>> {
+        element!(Select<String>(
+            items: vec!["a".to_string(), "b".to_string(), "c".to_string()],
+            on_select: |item: String| SELECTED.lock().unwrap().push(item),
+        ))
+    }
+
+    fn mouse(kind: MouseEventKind, (column, row): (u16, u16)) -> Event {
+        Event::Mouse(MouseEvent {
+            kind,
+            column,
+            row,
+            modifiers: KeyModifiers::NONE,
+        })
+    }
+
+    #[test]
+    fn click_selects_and_double_click_fires_on_select() {
+        SELECTED.lock().unwrap().clear();
+        let mut tree = mount(crate::element!(ClickableSelect));
+        let mut terminal = ratatui::Terminal::new(TestBackend::new(10, 5)).unwrap();
+        let selection = Palette::default().selection;
+
+        frame(&mut tree, &mut terminal);
+        let at = find(terminal.backend().buffer(), "c").expect("应渲染列表项 'c'");
+
+        tree.dispatch(mouse(MouseEventKind::Down(MouseButton::Left), at));
+        frame(&mut tree, &mut terminal);
+        assert_eq!(
+            cell_style(terminal.backend().buffer(), at.0, at.1).bg,
+            Some(selection),
+            "单击应选中被点中的行"
+        );
+        assert!(
+            SELECTED.lock().unwrap().is_empty(),
+            "单击不应触发 on_select"
+        );
+
+        tree.dispatch(mouse(MouseEventKind::Down(MouseButton::Left), at));
+        frame(&mut tree, &mut terminal);
+        assert_eq!(*SELECTED.lock().unwrap(), vec!["c".to_string()]);
+    }
+
+    #[test]
+    fn hover_patches_row_under_pointer() {
+        let mut tree = mount(crate::element!(ClickableSelect));
+        let mut terminal = ratatui::Terminal::new(TestBackend::new(10, 5)).unwrap();
+
+        frame(&mut tree, &mut terminal);
+        let at = find(terminal.backend().buffer(), "b").expect("应渲染列表项 'b'");
+
+        tree.dispatch(mouse(MouseEventKind::Moved, at));
+        frame(&mut tree, &mut terminal);
+        assert!(
+            cell_style(terminal.backend().buffer(), at.0, at.1)
+                .add_modifier
+                .contains(Modifier::BOLD),
+            "悬停行应叠加 hover_style"
+        );
+
+        // 移出组件区域后悬停消失。
+        tree.dispatch(mouse(MouseEventKind::Moved, (20, 20)));
+        frame(&mut tree, &mut terminal);
+        assert!(
+            !cell_style(terminal.backend().buffer(), at.0, at.1)
+                .add_modifier
+                .contains(Modifier::BOLD)
+        );
+    }
+
+    static DROPPED: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+    // 左 5 列拖源,右 5 列放置目标;以单字符标记拖动 / 悬停状态。
+    #[component]
+    fn DragProbe(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+        let dragging = hooks.use_drag_source("x".to_string());
+        element!(View(width: Constraint::Length(5)) {
+            Text(text: if dragging.is_some() { "D" } else { "S" })
+        })
+    }
+
+    #[component]
+    fn DropProbe(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+        let over =
+            hooks.use_drop_target(|payload: String, _| DROPPED.lock().unwrap().push(payload));
+        element!(View(width: Constraint::Length(5)) {
+            Text(text: if over { "O" } else { "T" })
+        })
+    }
+
+    #[test]
+    fn drag_source_drops_payload_on_target() {
+        DROPPED.lock().unwrap().clear();
+        let mut tree = mount(
+            crate::element!(View(flex_direction: Direction::Horizontal) {
+                DragProbe
+                DropProbe
+            }),
+        );
+        let mut terminal = ratatui::Terminal::new(TestBackend::new(10, 1)).unwrap();
+
+        frame(&mut tree, &mut terminal);
+        assert_eq!(
+            super::row(terminal.backend().buffer(), 0).trim_end(),
+            "S    T"
+        );
+
+        tree.dispatch(mouse(MouseEventKind::Down(MouseButton::Left), (0, 0)));
+        frame(&mut tree, &mut terminal);
+        // 捕获后拖出拖源区域仍由拖源处理。
+        tree.dispatch(mouse(MouseEventKind::Drag(MouseButton::Left), (7, 0)));
+        frame(&mut tree, &mut terminal);
+        assert_eq!(
+            super::row(terminal.backend().buffer(), 0).trim_end(),
+            "D    O"
+        );
+
+        tree.dispatch(mouse(MouseEventKind::Up(MouseButton::Left), (7, 0)));
+        frame(&mut tree, &mut terminal);
+        assert_eq!(
+            super::row(terminal.backend().buffer(), 0).trim_end(),
+            "S    T"
+        );
+        assert_eq!(*DROPPED.lock().unwrap(), vec!["x".to_string()]);
+    }
+}
+
+// 粘贴:编辑态 SearchInput 一次插入整段文本,换行按 `paste_newlines` 规整而不提交。
+#[cfg(feature = "input")]
+mod paste_tests {
+    use super::{frame, mount, press, row};
+    use crate::prelude::*;
+    use crossterm::event::{Event, KeyCode};
+    use ratatui::backend::TestBackend;
+    use ratatui_kit_macros::component;
+    use std::sync::Mutex;
+
+    static SUBMITTED: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+    #[component]
+    fn PasteProbe(_hooks: Hooks) -> impl Into<AnyElement<'static>> {
+        element!(SearchInput(
+            on_submit: |value: String| {
+                SUBMITTED.lock().unwrap().push(value);
+                true
+            },
+        ))
+    }
+
+    #[test]
+    fn search_input_inserts_paste_without_submitting() {
+        let mut tree = mount(crate::element!(PasteProbe));
+        let mut terminal = ratatui::Terminal::new(TestBackend::new(20, 3)).unwrap();
+
+        frame(&mut tree, &mut terminal);
+        press(&mut tree, KeyCode::Char('s'));
+        frame(&mut tree, &mut terminal);
+        tree.dispatch(Event::Paste("ab\ncd\n".to_string()));
+        frame(&mut tree, &mut terminal);
+
+        assert!(
+            row(terminal.backend().buffer(), 1).contains("ab cd"),
+            "实际: {:?}",
+            row(terminal.backend().buffer(), 1)
+        );
+        assert!(SUBMITTED.lock().unwrap().is_empty(), "粘贴中的换行不应提交");
+    }
+}
+
+// 事件总线：按键 handler 发布应用事件，下一次分发投递给另一组件的订阅者。
+mod event_bus_tests {
+    use super::{frame, mount, press, row};
+    use crate::prelude::*;
+    use crossterm::event::{Event, KeyCode};
+    use ratatui::backend::TestBackend;
+    use ratatui_kit_macros::component;
+
+    struct Refresh(u32);
+
+    #[component]
+    fn Publisher(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+        let bus = hooks.use_event_bus::<Refresh>();
+        hooks.use_event_handler(
+            EventScope::Current,
+            EventPriority::Normal,
+            move |event| match event {
+                Event::Key(key) if key.code == KeyCode::Char('r') => {
+                    bus.publish(Refresh(2));
+                    EventResult::Consumed
+                }
+                _ => EventResult::Ignored,
+            },
+        );
+        element!(Fragment)
+    }
+
+    #[component]
+    fn Subscriber(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+        let mut total = hooks.use_state(|| 0u32);
+        hooks.use_app_event(move |event: &Refresh| {
+            total += event.0;
+            EventResult::Consumed
+        });
+        element!(Text(text: format!("total={}", total.get())))
+    }
+
+    #[component]
+    fn BusProbe(_hooks: Hooks) -> impl Into<AnyElement<'static>> {
+        element!(View {
+            Publisher
+            Subscriber
+        })
+    }
+
+    #[test]
+    fn published_event_reaches_subscriber() {
+        let mut tree = mount(crate::element!(BusProbe));
+        let mut terminal = ratatui::Terminal::new(TestBackend::new(20, 2)).unwrap();
+
+        frame(&mut tree, &mut terminal);
+        press(&mut tree, KeyCode::Char('r'));
+        frame(&mut tree, &mut terminal);
+        assert!(tree.dispatch_app_event(), "发布后应有排队事件");
+        frame(&mut tree, &mut terminal);
+        assert!(!tree.dispatch_app_event());
+
+        let buf = terminal.backend().buffer();
+        let rows = [row(buf, 0), row(buf, 1)];
+        assert!(
+            rows.iter().any(|row| row.contains("total=2")),
+            "实际: {rows:?}"
+        );
+    }
+}
+
+// effect 清理：依赖变化时先清理上一次副作用再重新执行，组件卸载时执行最后一次清理。
+mod effect_cleanup_tests {
+    use super::{frame, mount, press};
+    use crate::prelude::*;
+    use crossterm::event::{Event, KeyCode};
+    use ratatui::backend::TestBackend;
+    use ratatui_kit_macros::component;
+    use std::sync::Mutex;
+
+    static LOG: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+    fn on_key(code: char, mut f: impl FnMut() + 'static) -> impl FnMut(Event) -> EventResult {
+        move |event| match event {
+            Event::Key(key) if key.code == KeyCode::Char(code) => {
+                f();
+                EventResult::Consumed
+            }
+            _ => EventResult::Ignored,
+        }
+    }
+
+    #[component]
+    fn Subscription(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+        let mut topic = hooks.use_state(|| 0u32);
+        hooks.use_event_handler(
+            EventScope::Current,
+            EventPriority::Normal,
+            on_key('n', move || topic += 1),
+        );
+        let current = topic.get();
+        hooks.use_effect_with_cleanup(
+            move || {
+                LOG.lock().unwrap().push(format!("subscribe {current}"));
+                move || LOG.lock().unwrap().push(format!("unsubscribe {current}"))
+            },
+            current,
+        );
+        element!(Fragment)
+    }
+
+    #[component]
+    fn CleanupProbe(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+        let mut mounted = hooks.use_state(|| true);
+        hooks.use_event_handler(
+            EventScope::Current,
+            EventPriority::Normal,
+            on_key('h', move || mounted.set(false)),
+        );
+        element!(View {
+            if mounted.get() {
+                Subscription
+            }
+        })
+    }
+
+    #[test]
+    fn cleanup_runs_before_rerun_and_on_unmount() {
+        let mut tree = mount(crate::element!(CleanupProbe));
+        let mut terminal = ratatui::Terminal::new(TestBackend::new(4, 1)).unwrap();
+
+        frame(&mut tree, &mut terminal);
+        frame(&mut tree, &mut terminal);
+        press(&mut tree, KeyCode::Char('n'));
+        frame(&mut tree, &mut terminal);
+        press(&mut tree, KeyCode::Char('h'));
+        frame(&mut tree, &mut terminal);
+
+        assert_eq!(
+            *LOG.lock().unwrap(),
+            [
+                "subscribe 0",
+                "unsubscribe 0",
+                "subscribe 1",
+                "unsubscribe 1"
+            ]
+        );
+    }
+}
+
+// 异步状态：refetch 取代进行中的请求，cancel 丢弃请求并回到 Idle，旧数据保留。
+mod async_state_tests {
+    use super::{frame_line, mount, press};
+    use crate::render::tree::Tree;
+    use crate::{prelude::*, time::Clock};
+    use crossterm::event::{Event, KeyCode};
+    use futures::channel::oneshot;
+    use ratatui::backend::TestBackend;
+    use ratatui_kit_macros::component;
+    use std::{
+        cell::{Cell, RefCell},
+        time::{Duration, Instant},
+    };
+
+    type Reply = oneshot::Sender<Result<u32, String>>;
+
+    thread_local! {
+        static ATTEMPTS: Cell<u32> = const { Cell::new(0) };
+        static PENDING: RefCell<Vec<Reply>> = const { RefCell::new(Vec::new()) };
+    }
+
+    fn reply(index: usize, value: u32) -> bool {
+        PENDING.with_borrow_mut(|pending| {
+            let (tx, _) = oneshot::channel();
+            std::mem::replace(&mut pending[index], tx)
+                .send(Ok(value))
+                .is_ok()
+        })
+    }
+
+    #[component]
+    fn Fetcher(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+        let result = hooks.use_async_state(
+            || {
+                let (tx, rx) = oneshot::channel();
+                PENDING.with_borrow_mut(|pending| pending.push(tx));
+                async move { rx.await.unwrap_or_else(|_| Err("dropped".to_string())) }
+            },
+            (),
+        );
+        hooks.use_event_handler(
+            EventScope::Current,
+            EventPriority::Normal,
+            move |event| match event {
+                Event::Key(key) if key.code == KeyCode::Char('r') => {
+                    result.refetch();
+                    EventResult::Consumed
+                }
+                Event::Key(key) if key.code == KeyCode::Char('c') => {
+                    result.cancel();
+                    EventResult::Consumed
+                }
+                _ => EventResult::Ignored,
+            },
+        );
+        let value = result.read();
+        element!(Text(text: format!("{:?} {:?}", value.status(), value.data())))
+    }
+
+    // 画一帧后轮询一次，推进进行中的请求。
+    fn step(tree: &mut Tree, terminal: &mut ratatui::Terminal<TestBackend>) -> String {
+        let line = frame_line(tree, terminal);
+        tree.poll_once();
+        line
+    }
+
+    #[test]
+    fn refetch_supersedes_and_cancel_keeps_data() {
+        let mut tree = mount(crate::element!(Fetcher));
+        let mut terminal = ratatui::Terminal::new(TestBackend::new(30, 1)).unwrap();
+
+        assert_eq!(step(&mut tree, &mut terminal), "Loading None");
+        press(&mut tree, KeyCode::Char('r'));
+        step(&mut tree, &mut terminal);
+        assert!(!reply(0, 1), "被 refetch 取代的请求应已丢弃");
+        assert!(reply(1, 2));
+        tree.poll_once();
+        assert_eq!(step(&mut tree, &mut terminal), "Success Some(2)");
+
+        press(&mut tree, KeyCode::Char('r'));
+        assert_eq!(step(&mut tree, &mut terminal), "Loading Some(2)");
+        press(&mut tree, KeyCode::Char('c'));
+        assert_eq!(step(&mut tree, &mut terminal), "Idle Some(2)");
+        assert!(!reply(2, 3), "取消的请求应已丢弃");
+        assert_eq!(step(&mut tree, &mut terminal), "Idle Some(2)");
+    }
+
+    // 前两次失败、第三次成功的请求，退避 100ms 起翻倍。
+    #[component]
+    fn RetryProbe(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+        let retry = RetryPolicy {
+            max_retries: 2,
+            backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(1),
+        };
+        let result = hooks.use_async_state_with_retry(
+            retry,
+            || {
+                ATTEMPTS.set(ATTEMPTS.get() + 1);
+                let attempt = ATTEMPTS.get();
+                async move {
+                    if attempt < 3 {
+                        Err("busy".to_string())
+                    } else {
+                        Ok(attempt)
+                    }
+                }
+            },
+            (),
+        );
+        let value = result.read();
+        element!(Text(text: format!(
+            "{:?} {} {:?}",
+            value.status(),
+            value.retries(),
+            value.data()
+        )))
+    }
+
+    #[test]
+    fn retry_backoff_follows_the_manual_clock() {
+        let clock = Clock::manual(Instant::now());
+        let _guard = clock.install();
+        let mut tree = mount(crate::element!(RetryProbe));
+        let mut terminal = ratatui::Terminal::new(TestBackend::new(30, 1)).unwrap();
+
+        assert_eq!(step(&mut tree, &mut terminal), "Loading 0 None");
+        assert_eq!(ATTEMPTS.get(), 1);
+        clock.advance(Duration::from_millis(99));
+        tree.poll_once();
+        assert_eq!(ATTEMPTS.get(), 1, "退避未到期前不重试");
+
+        clock.advance(Duration::from_millis(1));
+        tree.poll_once();
+        assert_eq!(ATTEMPTS.get(), 2);
+        assert_eq!(frame_line(&mut tree, &mut terminal), "Loading 1 None");
+
+        clock.advance(Duration::from_millis(199));
+        tree.poll_once();
+        assert_eq!(ATTEMPTS.get(), 2, "第二次退避翻倍");
+        clock.advance(Duration::from_millis(1));
+        tree.poll_once();
+        assert_eq!(ATTEMPTS.get(), 3);
+        assert_eq!(frame_line(&mut tree, &mut terminal), "Success 2 Some(3)");
+    }
+}
+
+// 查询缓存：同 key 的多个订阅者共享一次获取；乐观更新失败后回滚并重新获取；
+// 唯一订阅者在获取中卸载时放弃该获取，重新挂载后重新获取；获取中失效则换新的获取。
+#[cfg(feature = "query")]
+mod query_tests {
+    use super::{frame, mount, press};
+    use crate::prelude::*;
+    use crate::render::tree::Tree;
+    use crossterm::event::{Event, KeyCode};
+    use futures::channel::oneshot;
+    use ratatui::backend::TestBackend;
+    use ratatui_kit_macros::component;
+    use std::{cell::RefCell, collections::BTreeMap};
+
+    thread_local! {
+        static FETCHES: RefCell<Vec<oneshot::Sender<u32>>> = const { RefCell::new(Vec::new()) };
+        static SAVES: RefCell<Vec<oneshot::Sender<Result<(), String>>>> =
+            const { RefCell::new(Vec::new()) };
+        static SEEN: RefCell<BTreeMap<&'static str, String>> = const { RefCell::new(BTreeMap::new()) };
+        static CLIENT: RefCell<Option<QueryClient>> = const { RefCell::new(None) };
+    }
+
+    #[derive(Default, Props)]
+    struct ReaderProps {
+        name: &'static str,
+    }
+
+    #[component]
+    fn Reader(props: &ReaderProps, mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+        let query = hooks.use_query("count", || {
+            let (tx, rx) = oneshot::channel();
+            FETCHES.with_borrow_mut(|fetches| fetches.push(tx));
+            async move { rx.await.map_err(|_| "dropped".to_string()) }
+        });
+        let seen = format!("{:?} {}", query.data(), query.is_fetching());
+        SEEN.with_borrow_mut(|map| map.insert(props.name, seen));
+        element!(Fragment)
+    }
+
+    #[component]
+    fn Writer(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+        let mutation = hooks.use_mutation::<String>();
+        hooks.use_event_handler(
+            EventScope::Current,
+            EventPriority::Normal,
+            move |event| match event {
+                Event::Key(key) if key.code == KeyCode::Char('+') => {
+                    let (tx, rx) = oneshot::channel();
+                    SAVES.with_borrow_mut(|saves| saves.push(tx));
+                    mutation.mutate(
+                        "count",
+                        |count: Option<&u32>| count.copied().unwrap_or_default() + 10,
+                        async move { rx.await.unwrap_or(Err("dropped".to_string())) },
+                    );
+                    EventResult::Consumed
+                }
+                _ => EventResult::Ignored,
+            },
+        );
+        element!(Fragment)
+    }
+
+    #[component]
+    fn QueryProbe(_hooks: Hooks) -> impl Into<AnyElement<'static>> {
+        element!(View {
+            Reader(name: "a")
+            Reader(name: "b")
+            Writer
+        })
+    }
+
+    fn step(tree: &mut Tree, terminal: &mut ratatui::Terminal<TestBackend>) -> Vec<String> {
+        frame(tree, terminal);
+        tree.poll_once();
+        SEEN.with_borrow(|map| map.values().cloned().collect())
+    }
+
+    // 按 `x` 切换唯一订阅者的挂载。
+    #[component]
+    fn ToggleProbe(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+        let client = hooks.use_query_client();
+        CLIENT.with_borrow_mut(|slot| *slot = Some(client));
+        let mut mounted = hooks.use_state(|| true);
+        hooks.use_event_handler(
+            EventScope::Current,
+            EventPriority::Normal,
+            move |event| match event {
+                Event::Key(key) if key.code == KeyCode::Char('x') => {
+                    mounted.set(!mounted.get());
+                    EventResult::Consumed
+                }
+                _ => EventResult::Ignored,
+            },
+        );
+        element!(View {
+            { mounted.get().then(|| element!(Reader(name: "a"))) }
+        })
+    }
+
+    fn settle_fetch(index: usize, value: u32) {
+        FETCHES.with_borrow_mut(|fetches| {
+            let (tx, _) = oneshot::channel();
+            std::mem::replace(&mut fetches[index], tx)
+                .send(value)
+                .unwrap();
+        });
+    }
+
+    #[test]
+    fn subscribers_share_fetch_and_failed_mutation_rolls_back() {
+        let mut tree = mount(crate::element!(QueryProbe));
+        let mut terminal = ratatui::Terminal::new(TestBackend::new(10, 1)).unwrap();
+
+        assert_eq!(step(&mut tree, &mut terminal), ["None true", "None true"]);
+        assert_eq!(FETCHES.with_borrow(Vec::len), 1, "并发订阅应合并为一次获取");
+        settle_fetch(0, 1);
+        tree.poll_once();
+        assert_eq!(
+            step(&mut tree, &mut terminal),
+            ["Some(1) false", "Some(1) false"]
+        );
+
+        press(&mut tree, KeyCode::Char('+'));
+        assert_eq!(
+            step(&mut tree, &mut terminal),
+            ["Some(11) false", "Some(11) false"]
+        );
+
+        SAVES.with_borrow_mut(|saves| saves.remove(0).send(Err("conflict".into())).unwrap());
+        tree.poll_once();
+        assert_eq!(
+            step(&mut tree, &mut terminal),
+            ["Some(1) true", "Some(1) true"]
+        );
+        assert_eq!(FETCHES.with_borrow(Vec::len), 2, "失败回滚后应重新获取");
+        settle_fetch(1, 5);
+        tree.poll_once();
+        assert_eq!(
+            step(&mut tree, &mut terminal),
+            ["Some(5) false", "Some(5) false"]
+        );
+    }
+
+    #[test]
+    fn unmounting_mid_fetch_abandons_it_and_remount_refetches() {
+        let mut tree = mount(crate::element!(ToggleProbe));
+        let mut terminal = ratatui::Terminal::new(TestBackend::new(10, 1)).unwrap();
+        let toggle = |tree: &mut Tree| press(tree, KeyCode::Char('x'));
+        let is_fetching =
+            || CLIENT.with_borrow(|client| client.as_ref().unwrap().is_fetching("count"));
+
+        assert_eq!(step(&mut tree, &mut terminal), ["None true"]);
+        assert!(is_fetching());
+
+        toggle(&mut tree);
+        step(&mut tree, &mut terminal);
+        assert!(!is_fetching(), "最后一个订阅者卸载后不应停留在获取中");
+
+        toggle(&mut tree);
+        assert_eq!(step(&mut tree, &mut terminal), ["None true"]);
+        assert_eq!(FETCHES.with_borrow(Vec::len), 2, "重新挂载应重新获取");
+        settle_fetch(1, 7);
+        tree.poll_once();
+        assert_eq!(step(&mut tree, &mut terminal), ["Some(7) false"]);
+    }
+
+    #[test]
+    fn invalidating_mid_fetch_replaces_the_request() {
+        let mut tree = mount(crate::element!(ToggleProbe));
+        let mut terminal = ratatui::Terminal::new(TestBackend::new(10, 1)).unwrap();
+
+        assert_eq!(step(&mut tree, &mut terminal), ["None true"]);
+        CLIENT.with_borrow(|client| client.as_ref().unwrap().invalidate("count"));
+        tree.poll_once();
+        assert_eq!(step(&mut tree, &mut terminal), ["None true"]);
+        assert_eq!(FETCHES.with_borrow(Vec::len), 2, "失效后应发起新的获取");
+
+        settle_fetch(1, 3);
+        tree.poll_once();
+        assert_eq!(step(&mut tree, &mut terminal), ["Some(3) false"]);
+    }
+}
+
+// 计时 hook：手动时钟推进后，到期的定时器经 `poll_change` 触发回调 / 更新防抖值；
+// 零周期的间隔不触发，节流回调里可再次调用节流回调。
+mod timer_tests {
+    use super::{NoopTerminal, mount, press};
+    use crate::prelude::*;
+    use crate::render::tree::Tree;
+    use crate::time::Clock;
+    use crossterm::event::{Event, KeyCode};
+    use ratatui_kit_macros::component;
+    use std::{
+        cell::{Cell, RefCell},
+        time::{Duration, Instant},
+    };
+
+    thread_local! {
+        static TICKS: Cell<u32> = const { Cell::new(0) };
+        static DEBOUNCED: RefCell<String> = const { RefCell::new(String::new()) };
+        static PAUSED_TICKS: Cell<u32> = const { Cell::new(0) };
+        static THROTTLED: RefCell<Option<Throttled<u32>>> = const { RefCell::new(None) };
+        static RUNS: RefCell<Vec<u32>> = const { RefCell::new(Vec::new()) };
+    }
+
+    #[component]
+    fn TimerProbe(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+        let query = hooks.use_state(String::new);
+        hooks.use_interval(Duration::from_millis(100), || TICKS.set(TICKS.get() + 1));
+        let debounced = hooks.use_debounced_value(query.read().clone(), Duration::from_millis(300));
+        DEBOUNCED.set(debounced);
+        hooks.use_event_handler(
+            EventScope::Current,
+            EventPriority::Normal,
+            move |event| match event {
+                Event::Key(key) => {
+                    if let KeyCode::Char(c) = key.code {
+                        query.write().push(c);
+                    }
+                    EventResult::Consumed
+                }
+                _ => EventResult::Ignored,
+            },
+        );
+        element!(Fragment)
+    }
+
+    #[component]
+    fn ThrottleProbe(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+        hooks.use_interval(Duration::ZERO, || PAUSED_TICKS.set(PAUSED_TICKS.get() + 1));
+        let throttled = hooks.use_throttled_callback(Duration::from_millis(100), |n: u32| {
+            RUNS.with_borrow_mut(|runs| runs.push(n));
+            if n == 1 {
+                THROTTLED.with_borrow(|throttled| throttled.as_ref().unwrap().call(2));
+            }
+        });
+        THROTTLED.set(Some(throttled.clone()));
+        hooks.use_event_handler(
+            EventScope::Current,
+            EventPriority::Normal,
+            move |event| match event {
+                Event::Key(_) => {
+                    throttled.call(1);
+                    EventResult::Consumed
+                }
+                _ => EventResult::Ignored,
+            },
+        );
+        element!(Fragment)
+    }
+
+    // 推进时钟后轮询一次，有变更就重渲一帧。
+    fn advance(tree: &mut Tree, clock: &Clock, millis: u64) {
+        clock.advance(Duration::from_millis(millis));
+        if tree.poll_once() {
+            tree.update_once(&mut NoopTerminal);
+        }
+    }
+
+    #[test]
+    fn interval_and_debounce_follow_the_manual_clock() {
+        let clock = Clock::manual(Instant::now());
+        let _guard = clock.install();
+        let mut tree = mount(crate::element!(TimerProbe));
+
+        tree.update_once(&mut NoopTerminal);
+        tree.poll_once();
+        advance(&mut tree, &clock, 99);
+        assert_eq!(TICKS.get(), 0);
+        advance(&mut tree, &clock, 1);
+        advance(&mut tree, &clock, 100);
+        assert_eq!(TICKS.get(), 2);
+        // 一次跨过多个周期只触发一次，错过的周期不补调。
+        advance(&mut tree, &clock, 350);
+        assert_eq!(TICKS.get(), 3);
+
+        press(&mut tree, KeyCode::Char('a'));
+        tree.update_once(&mut NoopTerminal);
+        advance(&mut tree, &clock, 200);
+        press(&mut tree, KeyCode::Char('b'));
+        tree.update_once(&mut NoopTerminal);
+        advance(&mut tree, &clock, 200);
+        assert_eq!(DEBOUNCED.with_borrow(String::clone), "", "防抖期内保持旧值");
+        advance(&mut tree, &clock, 100);
+        assert_eq!(DEBOUNCED.with_borrow(String::clone), "ab");
+    }
+
+    #[test]
+    fn zero_interval_pauses_and_throttled_callback_may_reenter() {
+        let clock = Clock::manual(Instant::now());
+        let _guard = clock.install();
+        let mut tree = mount(crate::element!(ThrottleProbe));
+
+        tree.update_once(&mut NoopTerminal);
+        tree.poll_once();
+        press(&mut tree, KeyCode::Char('x'));
+        tree.update_once(&mut NoopTerminal);
+        assert_eq!(
+            RUNS.with_borrow(Vec::clone),
+            [1],
+            "回调内的调用留待窗口结束"
+        );
+        advance(&mut tree, &clock, 100);
+        assert_eq!(RUNS.with_borrow(Vec::clone), [1, 2]);
+        advance(&mut tree, &clock, 1000);
+        assert_eq!(PAUSED_TICKS.get(), 0);
+    }
+}
+
+// 动画：目标变化后按帧时钟过渡，进行中按帧间隔请求重渲，结束后不再请求。
+mod animation_tests {
+    use super::{NoopTerminal, mount, press};
+    use crate::prelude::*;
+    use crate::render::tree::Tree;
+    use crate::time::Clock;
+    use crossterm::event::{Event, KeyCode};
+    use ratatui_kit_macros::component;
+    use std::{
+        cell::Cell,
+        time::{Duration, Instant},
+    };
+
+    thread_local! {
+        static WIDTH: Cell<u16> = const { Cell::new(0) };
+    }
+
+    #[component]
+    fn Bar(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+        let mut target = hooks.use_state(|| 0u16);
+        let width = hooks.use_tween(target.get(), Duration::from_millis(160), Easing::Linear);
+        WIDTH.set(width);
+        hooks.use_event_handler(
+            EventScope::Current,
+            EventPriority::Normal,
+            move |event| match event {
+                Event::Key(_) => {
+                    target.set(100);
+                    EventResult::Consumed
+                }
+                _ => EventResult::Ignored,
+            },
+        );
+        element!(Fragment)
+    }
+
+    // 推进一帧的时间；组件请求了重渲才 update，返回是否重渲。
+    fn tick(tree: &mut Tree, clock: &Clock) -> bool {
+        clock.advance(Duration::from_millis(16));
+        let changed = tree.poll_once();
+        if changed {
+            tree.update_once(&mut NoopTerminal);
+        }
+        changed
+    }
+
+    #[test]
+    fn tween_advances_per_frame_and_stops_requesting_frames() {
+        let clock = Clock::manual(Instant::now());
+        let _guard = clock.install();
+        let mut tree = mount(crate::element!(Bar));
+
+        tree.update_once(&mut NoopTerminal);
+        assert!(!tick(&mut tree, &clock), "静止时不请求帧");
+
+        press(&mut tree, KeyCode::Char(' '));
+        tree.poll_once();
+        tree.update_once(&mut NoopTerminal);
+        assert_eq!(WIDTH.get(), 0);
+
+        let mut widths = Vec::new();
+        while tick(&mut tree, &clock) {
+            widths.push(WIDTH.get());
+        }
+        assert_eq!(widths, [10, 20, 30, 40, 50, 60, 70, 80, 90, 100]);
+        assert!(!tick(&mut tree, &clock), "结束后不再请求帧");
+    }
+}
+
+// 归约器：事件 handler 与其他线程派发的动作都经同一归约器修改状态并唤醒组件；
+// 中间件里再次派发的动作排队处理。
+mod reducer_tests {
+    use super::{NoopTerminal, mount, press};
+    use crate::prelude::*;
+    use crossterm::event::{Event, KeyCode};
+    use ratatui_kit_macros::component;
+    use std::cell::{Cell, RefCell};
+
+    enum Action {
+        Increment,
+        Add(u32),
+    }
+
+    thread_local! {
+        static COUNT: Cell<u32> = const { Cell::new(0) };
+        static DISPATCH: RefCell<Option<Dispatch<Action>>> = const { RefCell::new(None) };
+        static APPLIED: RefCell<Vec<u32>> = const { RefCell::new(Vec::new()) };
+    }
+
+    #[component]
+    fn Counter(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+        let (count, dispatch) = hooks.use_reducer(
+            |count: &mut u32, action| match action {
+                Action::Increment => *count += 1,
+                Action::Add(n) => *count += n,
+            },
+            || 0,
+        );
+        COUNT.set(count.get());
+        DISPATCH.set(Some(dispatch));
+        hooks.use_event_handler(
+            EventScope::Current,
+            EventPriority::Normal,
+            move |event| match event {
+                Event::Key(_) => {
+                    dispatch.dispatch(Action::Increment);
+                    EventResult::Consumed
+                }
+                _ => EventResult::Ignored,
+            },
+        );
+        element!(Fragment)
+    }
+
+    // 中间件在 `next` 之前派发后续动作：后续动作排队，待当前动作归约后处理。
+    #[component]
+    fn BonusCounter(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+        let bonus = |_: State<u32>, action: Action, next: &dyn Fn(Action)| {
+            if let Action::Add(n) = action
+                && n >= 10
+            {
+                DISPATCH.with_borrow(|dispatch| dispatch.unwrap().dispatch(Action::Increment));
+            }
+            next(action);
+        };
+        let (count, dispatch) = hooks.use_reducer_with_middleware(
+            |count: &mut u32, action| {
+                let n = match action {
+                    Action::Increment => 1,
+                    Action::Add(n) => n,
+                };
+                APPLIED.with_borrow_mut(|applied| applied.push(n));
+                *count += n;
+            },
+            || 0,
+            vec![Box::new(bonus)],
+        );
+        COUNT.set(count.get());
+        DISPATCH.set(Some(dispatch));
+        element!(Fragment)
+    }
+
+    #[test]
+    fn dispatch_from_middleware_is_queued_after_the_current_action() {
+        let mut tree = mount(crate::element!(BonusCounter));
+        tree.update_once(&mut NoopTerminal);
+
+        DISPATCH.with_borrow(|dispatch| dispatch.unwrap().dispatch(Action::Add(10)));
+        assert!(tree.poll_once());
+        tree.update_once(&mut NoopTerminal);
+        assert_eq!(COUNT.get(), 11);
+        assert_eq!(APPLIED.with_borrow(Vec::clone), [10, 1]);
+    }
+
+    #[test]
+    fn handler_and_background_dispatch_rerender() {
+        let mut tree = mount(crate::element!(Counter));
+        tree.update_once(&mut NoopTerminal);
+        assert!(!tree.poll_once());
+
+        press(&mut tree, KeyCode::Char(' '));
+        assert!(tree.poll_once(), "派发后应请求重渲");
+        tree.update_once(&mut NoopTerminal);
+        assert_eq!(COUNT.get(), 1);
+
+        let dispatch = DISPATCH.take().unwrap();
+        std::thread::spawn(move || dispatch.dispatch(Action::Add(10)))
+            .join()
+            .unwrap();
+        assert!(tree.poll_once(), "其他线程派发也应唤醒组件");
+        tree.update_once(&mut NoopTerminal);
+        assert_eq!(COUNT.get(), 11);
+    }
+}
+
+// 实例存储：`use_ref` 跨帧保留且写入不请求重渲，`use_previous` 返回上一次渲染的值。
+mod ref_tests {
+    use super::{NoopTerminal, mount, press_and_update};
+    use crate::prelude::*;
+    use crossterm::event::{Event, KeyCode};
+    use ratatui_kit_macros::component;
+    use std::cell::RefCell;
+
+    thread_local! {
+        static SEEN: RefCell<Vec<(u32, Option<u32>, usize)>> = const { RefCell::new(Vec::new()) };
+    }
+
+    #[component]
+    fn Probe(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+        let mut value = hooks.use_state(|| 0u32);
+        let renders = hooks.use_ref::<usize>();
+        let keys = hooks.use_ref_with(Vec::<char>::new);
+        let previous = hooks.use_previous(value.get());
+        renders.with_mut(|renders| *renders += 1);
+        SEEN.with_borrow_mut(|seen| seen.push((value.get(), previous, renders.get())));
+        hooks.use_event_handler(
+            EventScope::Current,
+            EventPriority::Normal,
+            move |event| match event {
+                Event::Key(key) => {
+                    match key.code {
+                        KeyCode::Char('+') => value += 1,
+                        KeyCode::Char(c) => keys.with_mut(|keys| keys.push(c)),
+                        _ => return EventResult::Ignored,
+                    }
+                    EventResult::Consumed
+                }
+                _ => EventResult::Ignored,
+            },
+        );
+        element!(Text(text: keys.with(|keys| keys.iter().collect::<String>())))
+    }
+
+    // 与渲染循环一致：分发后无论是否有变更都重渲一帧（handler 每帧重新登记）。
+    #[test]
+    fn ref_persists_without_rerender_and_previous_lags_one_render() {
+        let mut tree = mount(crate::element!(Probe));
+        tree.update_once(&mut NoopTerminal);
+
+        assert!(
+            !press_and_update(&mut tree, KeyCode::Char('x')),
+            "写 ref 不应请求重渲"
+        );
+        assert!(press_and_update(&mut tree, KeyCode::Char('+')));
+        assert!(press_and_update(&mut tree, KeyCode::Char('+')));
+
+        assert_eq!(
+            SEEN.take(),
+            [
+                (0, None, 1),
+                (0, Some(0), 2),
+                (1, Some(0), 3),
+                (2, Some(1), 4)
+            ]
+        );
+    }
+}
+
+// 计算值：只在读取过的句柄变更后重算；组件外的句柄变更也会唤醒组件。
+mod computed_tests {
+    use super::{NoopTerminal, mount};
+    use crate::prelude::*;
+    use generational_box::{Owner, SyncStorage};
+    use ratatui_kit_macros::component;
+    use std::cell::Cell;
+
+    thread_local! {
+        static HANDLES: Cell<Option<(State<i32>, State<i32>)>> = const { Cell::new(None) };
+        static RUNS: Cell<u32> = const { Cell::new(0) };
+        static TOTAL: Cell<i32> = const { Cell::new(0) };
+    }
+
+    #[component]
+    fn Probe(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+        let (external, unrelated) = HANDLES.get().unwrap();
+        let local = hooks.use_state(|| 2);
+        let total = hooks.use_computed(move || {
+            RUNS.set(RUNS.get() + 1);
+            external.get() + local.get()
+        });
+        // 在计算之外读取，不成为依赖。
+        let _ = unrelated.get();
+        TOTAL.set(total);
+        element!(Fragment)
+    }
+
+    #[test]
+    fn recomputes_only_when_a_read_handle_changes() {
+        let owner = Owner::<SyncStorage>::default();
+        let mut external = State::new_in(&owner, 1);
+        let mut unrelated = State::new_in(&owner, 0);
+        HANDLES.set(Some((external, unrelated)));
+        let mut tree = mount(crate::element!(Probe));
+
+        tree.update_once(&mut NoopTerminal);
+        assert!(!tree.poll_once());
+        assert_eq!((TOTAL.get(), RUNS.get()), (3, 1));
+
+        unrelated.set(7);
+        assert!(!tree.poll_once(), "未在计算中读取的句柄变更不唤醒");
+        tree.update_once(&mut NoopTerminal);
+        assert_eq!(RUNS.get(), 1, "依赖未变时返回缓存");
+
+        external.set(10);
+        assert!(tree.poll_once(), "读取过的句柄变更应唤醒组件");
+        tree.update_once(&mut NoopTerminal);
+        assert_eq!((TOTAL.get(), RUNS.get()), (12, 2));
+    }
+}
+
+// 派生原子与选择器：依赖变更唤醒派生原子的订阅者；选择器只在选取结果变化时请求重渲。
+#[cfg(feature = "atom")]
+mod atom_selector_tests {
+    use super::{NoopTerminal, mount};
+    use crate::prelude::*;
+    use ratatui_kit_macros::component;
+    use std::cell::Cell;
+
+    static POINT: Atom<(i32, i32)> = Atom::new(|| (0, 0));
+    static BASE: Atom<i32> = Atom::new(|| 1);
+    static DOUBLE: Atom<i32> = Atom::derived(|atoms| atoms.get(&BASE) * 2);
+
+    thread_local! {
+        static X: Cell<i32> = const { Cell::new(0) };
+        static RENDERS: Cell<u32> = const { Cell::new(0) };
+        static DOUBLED: Cell<i32> = const { Cell::new(0) };
+    }
+
+    #[component]
+    fn PointX(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+        let x = hooks.use_atom_selector(&POINT, |point| point.0);
+        X.set(x);
+        RENDERS.set(RENDERS.get() + 1);
+        element!(Fragment)
+    }
+
+    #[component]
+    fn Doubled(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+        let doubled = hooks.use_atom(&DOUBLE);
+        DOUBLED.set(doubled.get());
+        element!(Fragment)
+    }
+
+    #[test]
+    fn selector_rerenders_only_when_selection_changes() {
+        let mut tree = mount(crate::element!(PointX));
+        tree.update_once(&mut NoopTerminal);
+        assert!(!tree.poll_once());
+
+        POINT.set((0, 5));
+        assert!(!tree.poll_once(), "未选取的字段变化不请求重渲");
+
+        POINT.set((3, 5));
+        assert!(tree.poll_once(), "选取结果变化应请求重渲");
+        tree.update_once(&mut NoopTerminal);
+        assert_eq!((X.get(), RENDERS.get()), (3, 2));
+    }
+
+    #[test]
+    fn derived_atom_follows_its_source() {
+        let mut tree = mount(crate::element!(Doubled));
+        tree.update_once(&mut NoopTerminal);
+        assert!(!tree.poll_once());
+        assert_eq!(DOUBLED.get(), 2);
+
+        BASE.set(5);
+        assert!(tree.poll_once(), "依赖变更应唤醒派生原子的订阅者");
+        tree.update_once(&mut NoopTerminal);
+        assert_eq!(DOUBLED.get(), 10);
+        assert!(!tree.poll_once());
+    }
+}
+
+// 原子族：只有订阅了同一 key 的组件被唤醒；删除订阅中的 key 后组件重渲并拿到重建的状态。
+#[cfg(feature = "atom")]
+mod atom_family_tests {
+    use super::{NoopTerminal, mount};
+    use crate::prelude::*;
+    use ratatui_kit_macros::component;
+    use std::cell::Cell;
+
+    static SCORES: AtomFamily<u32, i32> = AtomFamily::new(|id| *id as i32 * 100);
+
+    thread_local! {
+        static SCORE: Cell<i32> = const { Cell::new(0) };
+    }
+
+    #[component]
+    fn Player(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+        let score = hooks.use_atom_family(&SCORES, 1);
+        SCORE.set(score.get());
+        element!(Fragment)
+    }
+
+    #[test]
+    fn wakes_only_for_the_subscribed_key_and_survives_removal() {
+        let mut tree = mount(crate::element!(Player));
+        tree.update_once(&mut NoopTerminal);
+        assert!(!tree.poll_once());
+        assert_eq!(SCORE.get(), 100);
+
+        SCORES.set(&2, 5);
+        assert!(!tree.poll_once(), "其它 key 的写入不唤醒");
+
+        SCORES.set(&1, 7);
+        assert!(tree.poll_once());
+        tree.update_once(&mut NoopTerminal);
+        assert_eq!(SCORE.get(), 7);
+
+        assert_eq!(SCORES.remove_unused(), 1, "只回收未订阅的 key 2");
+        assert!(SCORES.remove(&1));
+        assert!(tree.poll_once(), "删除订阅中的 key 应唤醒组件");
+        tree.update_once(&mut NoopTerminal);
+        assert_eq!(SCORE.get(), 100);
+        assert!(!tree.poll_once());
+    }
+}
+
+// 持久化状态：首帧从文件恢复，组件卸载时立即写回，下一棵树读到上次的值。
+#[cfg(feature = "serde")]
+mod persisted_state_tests {
+    use super::{NoopTerminal, mount};
+    use crate::prelude::*;
+    use ratatui_kit_macros::component;
+    use std::cell::Cell;
+
+    thread_local! {
+        static TAB: Cell<Option<State<u8>>> = const { Cell::new(None) };
+    }
+
+    #[component]
+    fn Tabs(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+        let tab = hooks.use_persisted_state("harness-tab", || 0u8);
+        TAB.set(Some(tab));
+        element!(Fragment)
+    }
+
+    #[test]
+    fn restores_the_value_saved_on_unmount() {
+        crate::persist::tests::use_temp_dir();
+        let _ = std::fs::remove_file(crate::persist_dir().join("harness-tab.json"));
+
+        let mut tree = mount(crate::element!(Tabs));
+        tree.update_once(&mut NoopTerminal);
+        let mut tab = TAB.take().unwrap();
+        assert_eq!(tab.get(), 0);
+        tab.set(3);
+        drop(tree);
+
+        let mut tree = mount(crate::element!(Tabs));
+        tree.update_once(&mut NoopTerminal);
+        assert_eq!(TAB.take().unwrap().get(), 3, "卸载时写回，重新挂载时恢复");
+    }
+}
+
+// 撤销历史：绑定按键后 Ctrl+Z / Ctrl+Y 经输入运行时撤销、重做并触发重渲。
+mod history_state_tests {
+    use super::{NoopTerminal, mount, press_and_update};
+    use crate::prelude::*;
+    use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+    use ratatui_kit_macros::component;
+    use std::cell::Cell;
+
+    thread_local! {
+        static VALUE: Cell<u32> = const { Cell::new(0) };
+    }
+
+    #[component]
+    fn Editor(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+        let value = hooks.use_history_state_with(
+            || 0u32,
+            HistoryOptions {
+                bind_keys: true,
+                ..HistoryOptions::default()
+            },
+        );
+        VALUE.set(value.get());
+        hooks.use_event_handler(
+            EventScope::Current,
+            EventPriority::Normal,
+            move |event| match event {
+                Event::Key(key) if key.code == KeyCode::Char('+') => {
+                    value.update(|value| *value += 1);
+                    EventResult::Consumed
+                }
+                _ => EventResult::Ignored,
+            },
+        );
+        element!(Fragment)
+    }
+
+    #[test]
+    fn ctrl_z_and_ctrl_y_undo_and_redo() {
+        let mut tree = mount(crate::element!(Editor));
+        tree.update_once(&mut NoopTerminal);
+
+        press_and_update(
+            &mut tree,
+            KeyEvent::new(KeyCode::Char('+'), KeyModifiers::NONE),
+        );
+        press_and_update(
+            &mut tree,
+            KeyEvent::new(KeyCode::Char('+'), KeyModifiers::NONE),
+        );
+        assert_eq!(VALUE.get(), 2);
+
+        assert!(press_and_update(
+            &mut tree,
+            KeyEvent::new(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        ));
+        assert_eq!(VALUE.get(), 1);
+        assert!(press_and_update(
+            &mut tree,
+            KeyEvent::new(KeyCode::Char('y'), KeyModifiers::CONTROL)
+        ));
+        assert_eq!(VALUE.get(), 2);
+        assert!(
+            !press_and_update(
+                &mut tree,
+                KeyEvent::new(KeyCode::Char('y'), KeyModifiers::CONTROL)
+            ),
+            "没有可重做的历史时不重渲"
+        );
+    }
+}
+
+// 表单：绑定字段的输入组件只在获得焦点时接收键盘，Tab 切换焦点，提交失败聚焦出错字段。
+#[cfg(feature = "input")]
+mod form_tests {
+    use super::{NoopTerminal, mount, press_and_update};
+    use crate::prelude::*;
+    use crossterm::event::{Event, KeyCode};
+    use ratatui_kit_macros::component;
+    use std::cell::RefCell;
+
+    thread_local! {
+        static FORM: RefCell<Option<FormHandle>> = const { RefCell::new(None) };
+    }
+
+    #[component]
+    fn Signup(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+        let form = hooks.use_form(|form| {
+            form.field("name", String::new()).validate(|name: &String| {
+                if name.len() < 2 {
+                    Err("too short".to_string())
+                } else {
+                    Ok(())
+                }
+            });
+            form.field("role", None::<usize>)
+                .validate(|role: &Option<usize>| {
+                    role.map(|_| ()).ok_or_else(|| "pick a role".to_string())
+                });
+        });
+        FORM.set(Some(form));
+        hooks.use_event_handler(
+            EventScope::Current,
+            EventPriority::Low,
+            move |event| match event {
+                Event::Key(key) if key.code == KeyCode::Char('s') => {
+                    form.submit();
+                    EventResult::Consumed
+                }
+                _ => EventResult::Ignored,
+            },
+        );
+        let name = form.field::<String>("name");
+        let role = form.field::<Option<usize>>("role");
+
+        element!(Form(form: form) {
+            FormField(field: name, label: "Name") {
+                Input(field: name)
+            }
+            FormField(field: role, label: "Role") {
+                Select<&'static str>(items: vec!["admin", "viewer"], field: role)
+            }
+        })
+    }
+
+    #[test]
+    fn bound_inputs_follow_focus_and_submit_checks_fields() {
+        let mut tree = mount(crate::element!(Signup));
+        tree.update_once(&mut NoopTerminal);
+        let form = FORM.with_borrow(|form| form.expect("form is rendered"));
+        let name = form.field::<String>("name");
+        let role = form.field::<Option<usize>>("role");
+
+        press_and_update(&mut tree, KeyCode::Char('a'));
+        assert_eq!(name.get(), "a");
+        assert_eq!(role.get(), None, "未获得焦点的 Select 不响应键盘");
+
+        press_and_update(&mut tree, KeyCode::Tab);
+        assert_eq!(form.focused().as_deref(), Some("role"));
+        assert_eq!(name.display_error().as_deref(), Some("too short"));
+        press_and_update(&mut tree, KeyCode::Down);
+        assert_eq!(role.get(), Some(0));
+
+        // 提交失败时焦点回到第一个出错的字段，之后的键入进入该字段。
+        form.blur();
+        press_and_update(&mut tree, KeyCode::Char('s'));
+        assert_eq!(form.submit_count(), 1);
+        assert_eq!(form.focused().as_deref(), Some("name"));
+        press_and_update(&mut tree, KeyCode::Char('b'));
+        assert_eq!(name.get(), "ab");
+        assert!(form.is_valid());
+    }
+}
+
+// 响应式上下文：提供者之外写入也会唤醒消费者；选择器只在选取结果变化时请求重渲；
+// 同类型的两个标记各自解析到自己的提供者。
+mod reactive_context_tests {
+    use super::{NoopTerminal, mount};
+    use crate::prelude::*;
+    use ratatui_kit_macros::component;
+    use std::cell::Cell;
+
+    #[derive(Clone, PartialEq)]
+    struct Settings {
+        count: i32,
+        name: &'static str,
+    }
+
+    static SETTINGS: ReactiveContext<Settings> = create_context();
+    // 与 `SETTINGS` 同类型的另一个标记，各自解析到自己的提供者。
+    static DEFAULTS: ReactiveContext<Settings> = create_context();
+
+    thread_local! {
+        static HANDLE: Cell<Option<ContextState<Settings>>> = const { Cell::new(None) };
+        static COUNT: Cell<i32> = const { Cell::new(0) };
+        static NAME: Cell<&'static str> = const { Cell::new("") };
+        static DEFAULT_NAME: Cell<&'static str> = const { Cell::new("") };
+    }
+
+    #[component]
+    fn Provider(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+        let settings = hooks.use_context_state(&SETTINGS, || Settings {
+            count: 0,
+            name: "a",
+        });
+        HANDLE.set(Some(settings));
+        element!(ContextProvider(value: SETTINGS.provide(settings)) {
+            CountView()
+        })
+    }
+
+    #[component]
+    fn CountView(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+        let count = hooks.use_context_selector(&SETTINGS, |settings| settings.count);
+        COUNT.set(count);
+        element!(Fragment)
+    }
+
+    #[component]
+    fn WholeProvider(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+        let settings = hooks.use_context_state(&SETTINGS, || Settings {
+            count: 0,
+            name: "a",
+        });
+        HANDLE.set(Some(settings));
+        element!(ContextProvider(value: SETTINGS.provide(settings)) {
+            NameView()
+        })
+    }
+
+    #[component]
+    fn NameView(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+        let settings = hooks.use_reactive_context(&SETTINGS);
+        NAME.set(settings.read().name);
+        element!(Fragment)
+    }
+
+    // 外层提供 `SETTINGS`，内层提供 `DEFAULTS`：`SETTINGS` 的消费者不应读到更近的 `DEFAULTS`。
+    #[component]
+    fn TwoProviders(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+        let settings = hooks.use_context_state(&SETTINGS, || Settings {
+            count: 0,
+            name: "settings",
+        });
+        let defaults = hooks.use_context_state(&DEFAULTS, || Settings {
+            count: 0,
+            name: "defaults",
+        });
+        element!(ContextProvider(value: SETTINGS.provide(settings)) {
+            ContextProvider(value: DEFAULTS.provide(defaults)) {
+                NameView()
+                DefaultsView()
+            }
+        })
+    }
+
+    #[component]
+    fn DefaultsView(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+        let name = hooks.use_context_selector(&DEFAULTS, |defaults| defaults.name);
+        DEFAULT_NAME.set(name);
+        element!(Fragment)
+    }
+
+    fn handle() -> ContextState<Settings> {
+        HANDLE.get().expect("provider rendered")
+    }
+
+    #[test]
+    fn selector_consumer_wakes_only_for_its_slice() {
+        let mut tree = mount(crate::element!(Provider));
+        tree.update_once(&mut NoopTerminal);
+        assert!(!tree.poll_once());
+
+        handle().write().name = "b";
+        assert!(!tree.poll_once(), "未选取的字段变化不请求重渲");
+
+        handle().write().count = 3;
+        assert!(tree.poll_once(), "选取结果变化应请求重渲");
+        tree.update_once(&mut NoopTerminal);
+        assert_eq!(COUNT.get(), 3);
+        assert!(!tree.poll_once());
+    }
+
+    #[test]
+    fn whole_consumer_follows_writes_outside_the_provider() {
+        let mut tree = mount(crate::element!(WholeProvider));
+        tree.update_once(&mut NoopTerminal);
+        assert_eq!(NAME.get(), "a");
+        assert!(!tree.poll_once());
+
+        handle().write().name = "b";
+        assert!(tree.poll_once());
+        tree.update_once(&mut NoopTerminal);
+        assert_eq!(NAME.get(), "b");
+        assert!(!tree.poll_once());
+    }
+
+    #[test]
+    fn contexts_of_the_same_type_resolve_to_their_own_providers() {
+        let mut tree = mount(crate::element!(TwoProviders));
+        tree.update_once(&mut NoopTerminal);
+        assert_eq!(NAME.get(), "settings");
+        assert_eq!(DEFAULT_NAME.get(), "defaults");
+    }
+}
+
+// 全局 store：订阅者轮询异步 thunk；动作日志面板经键盘跳回历史状态。
+#[cfg(feature = "atom")]
+mod store_tests {
+    use super::{NoopTerminal, mount, press_and_update};
+    use crate::prelude::*;
+    use crossterm::event::KeyCode;
+    use futures::channel::oneshot;
+    use ratatui_kit_macros::component;
+    use std::cell::Cell;
+
+    #[derive(Debug)]
+    enum Action {
+        Add(i32),
+    }
+
+    fn reduce(count: &mut i32, action: Action) {
+        match action {
+            Action::Add(n) => *count += n,
+        }
+    }
+
+    static COUNTER: Store<i32, Action> = Store::new(|| 0, reduce);
+    static HISTORY: Store<i32, Action> = Store::new(|| 0, reduce);
+
+    thread_local! {
+        static COUNT: Cell<i32> = const { Cell::new(0) };
+    }
+
+    #[component]
+    fn Counter(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+        let count = hooks.use_store_selector(&COUNTER, |count| *count);
+        COUNT.set(count);
+        element!(Fragment)
+    }
+
+    #[component]
+    fn Devtools(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+        let log = hooks.use_action_log(&HISTORY);
+        element!(ActionLogPanel(
+            log: log,
+            on_jump: move |index| {
+                HISTORY.jump_to(index);
+            },
+        ))
+    }
+
+    #[test]
+    fn subscribers_drive_async_thunks() {
+        let mut tree = mount(crate::element!(Counter));
+        tree.update_once(&mut NoopTerminal);
+
+        let (tx, rx) = oneshot::channel();
+        COUNTER.dispatch_async(|store| async move {
+            if let Ok(n) = rx.await {
+                store.dispatch(Action::Add(n));
+            }
+        });
+        assert!(!tree.poll_once(), "thunk 未完成时不请求重渲");
+        tx.send(5).unwrap();
+        assert!(tree.poll_once());
+        tree.update_once(&mut NoopTerminal);
+        assert_eq!(COUNT.get(), 5);
+    }
+
+    #[test]
+    fn action_log_panel_jumps_to_the_selected_entry() {
+        HISTORY.record_actions(16);
+        HISTORY.dispatch(Action::Add(1));
+        HISTORY.dispatch(Action::Add(2));
+
+        let mut tree = mount(crate::element!(Devtools));
+        tree.update_once(&mut NoopTerminal);
+
+        press_and_update(&mut tree, KeyCode::Up);
+        press_and_update(&mut tree, KeyCode::Enter);
+        assert_eq!(HISTORY.state().get(), 1);
+        assert_eq!(HISTORY.action_log().cursor(), 1);
+    }
+}
+
+// 后台任务：事件中发起的任务由组件轮询，完成后写回状态；abort 丢弃进行中的任务。
+mod task_tests {
+    use super::{frame_line, mount, press};
+    use crate::prelude::*;
+    use crate::render::tree::Tree;
+    use crossterm::event::{Event, KeyCode};
+    use futures::channel::oneshot;
+    use ratatui::backend::TestBackend;
+    use ratatui_kit_macros::component;
+    use std::cell::RefCell;
+
+    thread_local! {
+        static PENDING: RefCell<Vec<oneshot::Sender<u32>>> = const { RefCell::new(Vec::new()) };
+    }
+
+    #[component]
+    fn Loader(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+        let task = hooks.use_task_with(TaskConcurrency::Queue(1));
+        let mut total = hooks.use_state(|| 0);
+        let spawner = task.clone();
+        hooks.use_event_handler(
+            EventScope::Current,
+            EventPriority::Normal,
+            move |event| match event {
+                Event::Key(key) if key.code == KeyCode::Char('s') => {
+                    let (tx, rx) = oneshot::channel();
+                    PENDING.with_borrow_mut(|pending| pending.push(tx));
+                    spawner.spawn(async move {
+                        if let Ok(value) = rx.await {
+                            total += value;
+                        }
+                    });
+                    EventResult::Consumed
+                }
+                Event::Key(key) if key.code == KeyCode::Char('a') => {
+                    spawner.abort();
+                    EventResult::Consumed
+                }
+                _ => EventResult::Ignored,
+            },
+        );
+        let status = task.status();
+        element!(Text(text: format!(
+            "{} {}/{} done {} aborted {}",
+            total.get(),
+            status.running,
+            status.queued,
+            status.completed,
+            status.aborted
+        )))
+    }
+
+    // 先轮询任务再画一帧。
+    fn step(tree: &mut Tree, terminal: &mut ratatui::Terminal<TestBackend>) -> String {
+        tree.poll_once();
+        frame_line(tree, terminal)
+    }
+
+    fn reply(index: usize, value: u32) -> bool {
+        PENDING.with_borrow_mut(|pending| {
+            let (tx, _) = oneshot::channel();
+            std::mem::replace(&mut pending[index], tx)
+                .send(value)
+                .is_ok()
+        })
+    }
+
+    #[test]
+    fn queued_tasks_run_in_order_and_abort_drops_them() {
+        let mut tree = mount(crate::element!(Loader));
+        let mut terminal = ratatui::Terminal::new(TestBackend::new(40, 1)).unwrap();
+
+        assert_eq!(step(&mut tree, &mut terminal), "0 0/0 done 0 aborted 0");
+        press(&mut tree, KeyCode::Char('s'));
+        assert_eq!(step(&mut tree, &mut terminal), "0 1/0 done 0 aborted 0");
+        press(&mut tree, KeyCode::Char('s'));
+        assert_eq!(step(&mut tree, &mut terminal), "0 1/1 done 0 aborted 0");
+
+        assert!(reply(0, 5));
+        assert_eq!(step(&mut tree, &mut terminal), "5 1/0 done 1 aborted 0");
+
+        press(&mut tree, KeyCode::Char('a'));
+        assert_eq!(step(&mut tree, &mut terminal), "5 0/0 done 1 aborted 1");
+        assert!(!reply(1, 7), "中止的任务应已丢弃");
+        assert_eq!(step(&mut tree, &mut terminal), "5 0/0 done 1 aborted 1");
+    }
+}
+
+// 流与通道：其它线程发送的值经 poll 进入组件并触发重渲，缓冲只保留最近的条目。
+mod stream_tests {
+    use super::{frame_line, mount};
+    use crate::prelude::*;
+    use futures::channel::mpsc;
+    use ratatui::backend::TestBackend;
+    use ratatui_kit_macros::component;
+    use std::{cell::RefCell, sync::Mutex};
+
+    static SENDER: Mutex<Option<ChannelSender<u32>>> = Mutex::new(None);
+
+    thread_local! {
+        static FEED: RefCell<Option<mpsc::UnboundedReceiver<&'static str>>> =
+            const { RefCell::new(None) };
+    }
+
+    #[component]
+    fn Feed(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+        let (sender, values) = hooks.use_channel_buffer::<u32>(2);
+        *SENDER.lock().unwrap() = Some(sender);
+        let latest = hooks.use_stream(|| FEED.with_borrow_mut(|feed| feed.take().unwrap()));
+        element!(Text(text: format!("{values:?} {latest:?}")))
+    }
+
+    #[test]
+    fn items_from_streams_and_channels_trigger_renders() {
+        let (feed, receiver) = mpsc::unbounded();
+        FEED.with_borrow_mut(|slot| *slot = Some(receiver));
+        let mut tree = mount(crate::element!(Feed));
+        let mut terminal = ratatui::Terminal::new(TestBackend::new(30, 1)).unwrap();
+
+        assert_eq!(frame_line(&mut tree, &mut terminal), "[] None");
+        assert!(!tree.poll_once());
+
+        let sender = SENDER.lock().unwrap().clone().unwrap();
+        std::thread::spawn(move || {
+            for value in 1..=3 {
+                sender.send(value);
+            }
+        })
+        .join()
+        .unwrap();
+        assert!(tree.poll_once());
+        assert_eq!(frame_line(&mut tree, &mut terminal), "[2, 3] None");
+
+        feed.unbounded_send("a").unwrap();
+        feed.unbounded_send("b").unwrap();
+        assert!(tree.poll_once());
+        assert_eq!(frame_line(&mut tree, &mut terminal), "[2, 3] Some(\"b\")");
+    }
+}
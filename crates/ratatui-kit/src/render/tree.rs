@@ -10,7 +10,7 @@ use crate::{
     context::{ContextStack, SystemContext},
     element::ElementRepr,
     props::AnyProps,
-    terminal::{CrossTerminal, Terminal, TerminalImpl, UpdaterTerminal},
+    terminal::{CrossTerminal, Recorder, Terminal, TerminalImpl, UpdaterTerminal},
 };
 
 use super::ComponentDrawer;
@@ -19,7 +19,7 @@ struct RestoreGuard;
 
 impl Drop for RestoreGuard {
     fn drop(&mut self) {
-        ratatui::restore();
+        CrossTerminal::restore();
     }
 }
 
@@ -52,12 +52,56 @@ impl<'a> Tree<'a> {
     pub(crate) fn update_once(&mut self, terminal: &mut dyn UpdaterTerminal) {
         // 每帧重建输入注册表（清空上一帧层/handler、铸造 root 层)。
         // 必须在 ContextStack::root 借走 &mut system_context 之前完成,二者借用不重叠。
+        self.system_context.begin_frame();
         self.system_context.input.begin_frame();
         let mut component_context_stack = ContextStack::root(&mut self.system_context);
         self.root_component
             .update(terminal, &mut component_context_stack, self.props.borrow());
     }
 
+    // 把一个 raw 事件交中央运行时分发给本帧 handler。须在 update/draw 完整返回后调用。
+    #[cfg(test)]
+    pub(crate) fn dispatch(&mut self, event: crossterm::event::Event) {
+        self.system_context.input.dispatch(event);
+    }
+
+    // 按渲染循环的规则处理一个 raw 事件：Ctrl+C 自动退出时不分发并返回 `false`。
+    #[cfg(feature = "test-util")]
+    pub(crate) fn handle_event(&mut self, event: crossterm::event::Event) -> bool {
+        if should_quit_on_ctrl_c(&self.system_context, &event) {
+            return false;
+        }
+        self.system_context.input.dispatch(event);
+        true
+    }
+
+    #[cfg(feature = "test-util")]
+    pub(crate) fn should_exit(&self) -> bool {
+        self.system_context.should_exit()
+    }
+
+    // 取出一个排队的应用事件并分发；队列为空时返回 `false`。无头驱动每次分发后需重渲一帧。
+    #[cfg(any(test, feature = "test-util"))]
+    pub(crate) fn dispatch_app_event(&mut self) -> bool {
+        let Some(event) = self.system_context.input.app_events().try_next() else {
+            return false;
+        };
+        self.system_context.input.dispatch_app_event(event);
+        true
+    }
+
+    // 轮询一次组件树的 hook（推进 `use_future` / `use_async_state` 等持有的 future），
+    // 返回是否有状态变更待重渲。
+    #[cfg(any(test, feature = "test-util"))]
+    pub(crate) fn poll_once(&mut self) -> bool {
+        let mut cx = std::task::Context::from_waker(futures::task::noop_waker_ref());
+        self.root_component
+            .wait()
+            .boxed_local()
+            .poll_unpin(&mut cx)
+            .is_ready()
+    }
+
     // 只跑一次 draw（把树绘到给定 drawer）。供渲染 harness 直接画到 TestBackend Buffer。
     pub(crate) fn draw_root(&mut self, drawer: &mut ComponentDrawer) {
         self.root_component.draw(drawer);
@@ -75,7 +119,16 @@ impl<'a> Tree<'a> {
         Ok(())
     }
 
-    async fn render_loop(&mut self, terminal: &mut Terminal) -> io::Result<()> {
+    async fn render_loop(
+        &mut self,
+        terminal: &mut Terminal,
+        recorder: Option<&Recorder>,
+    ) -> io::Result<()> {
+        // 队列句柄独立于 `self` 借用,可与组件树的等待并列 select。
+        let app_events = self.system_context.input.app_events();
+        if let Some(recorder) = recorder {
+            recorder.restart();
+        }
         loop {
             self.render(terminal)?;
             if self.system_context.should_exit() {
@@ -83,14 +136,21 @@ impl<'a> Tree<'a> {
             }
             match select(
                 self.root_component.wait().boxed_local(),
-                terminal.next_event().boxed_local(),
+                select(
+                    terminal.next_event().boxed_local(),
+                    app_events.next().boxed_local(),
+                ),
             )
             .await
             {
                 // 组件树/状态变更：仅回到循环顶重渲染。
                 Either::Left(((), _)) => continue,
                 // 取到一个 raw 事件。
-                Either::Right((Some(event), _)) => {
+                Either::Right((Either::Left((Some(event), _)), _)) => {
+                    // 录制点：终端取到之后、分发之前，Ctrl+C 也照录（回放时在同一处退出）。
+                    if let Some(recorder) = recorder {
+                        recorder.record(&event);
+                    }
                     if should_quit_on_ctrl_c(&self.system_context, &event) {
                         break;
                     }
@@ -100,7 +160,12 @@ impl<'a> Tree<'a> {
                     continue;
                 }
                 // 事件流结束。
-                Either::Right((None, _)) => break,
+                Either::Right((Either::Left((None, _)), _)) => break,
+                // 取到一个应用事件：与 raw 事件同样分发后重渲。
+                Either::Right((Either::Right((event, _)), _)) => {
+                    self.system_context.input.dispatch_app_event(event);
+                    continue;
+                }
             }
         }
         Ok(())
@@ -110,12 +175,18 @@ impl<'a> Tree<'a> {
 pub(crate) async fn render_loop<E: ElementRepr>(
     mut element: E,
     mut terminal: Terminal,
+    recorder: Option<&Recorder>,
 ) -> io::Result<()> {
     let helper = element.helper();
     let mut tree = Tree::new(element.props_mut(), helper);
     let _restore_guard = RestoreGuard;
 
-    tree.render_loop(&mut terminal).await
+    let result = tree.render_loop(&mut terminal, recorder).await;
+    // 先卸载组件树（持久化状态在卸载时写回），再写回其余待写的持久化变更。
+    drop(tree);
+    #[cfg(feature = "serde")]
+    let _ = crate::persist::flush_persisted();
+    result
 }
 
 #[cfg(test)]
@@ -1,7 +1,13 @@
 use super::TerminalImpl;
-use crossterm::event::{self, EventStream};
+use crossterm::{
+    event::{
+        self, DisableBracketedPaste, DisableMouseCapture, EnableBracketedPaste, EnableMouseCapture,
+        EventStream,
+    },
+    execute,
+};
 use futures::{StreamExt, stream::BoxStream};
-use ratatui::{Frame, TerminalOptions};
+use ratatui::{Frame, TerminalOptions, Viewport};
 use std::io::{self};
 
 // ================== 终端核心功能实现 ==================
@@ -20,17 +26,35 @@ impl CrossTerminal {
     // 创建终端实例
     // fullscreen: 是否启用备用屏幕（全屏模式）
     pub fn new() -> io::Result<Self> {
-        Ok(Self {
-            terminal: ratatui::init(),
-        })
+        let terminal = ratatui::init();
+        enable_bracketed_paste();
+        execute!(io::stdout(), EnableMouseCapture)?;
+        Ok(Self { terminal })
     }
 
     // 启用/禁用原始模式
+    // 仅全屏 viewport 捕获鼠标：inline viewport 保留终端原生的文本选择与滚动。
     pub fn with_options(options: TerminalOptions) -> io::Result<Self> {
-        Ok(Self {
-            terminal: ratatui::init_with_options(options),
-        })
+        let fullscreen = options.viewport == Viewport::Fullscreen;
+        let terminal = ratatui::init_with_options(options);
+        enable_bracketed_paste();
+        if fullscreen {
+            execute!(io::stdout(), EnableMouseCapture)?;
+        }
+        Ok(Self { terminal })
     }
+
+    // 恢复终端：关闭本类开启的终端模式（未开启时为 no-op），再交 `ratatui::restore`。
+    pub(crate) fn restore() {
+        let _ = execute!(io::stdout(), DisableMouseCapture, DisableBracketedPaste);
+        ratatui::restore();
+    }
+}
+
+// 开启 bracketed paste：粘贴以单个 `Event::Paste` 到达，而非逐字符的按键（换行也不再是 `Enter`）。
+// 尽力而为：旧版 Windows 控制台不支持时忽略错误，退化为逐键输入。
+fn enable_bracketed_paste() {
+    let _ = execute!(io::stdout(), EnableBracketedPaste);
 }
 
 // ================== 生命周期管理 ==================
@@ -4,6 +4,8 @@ use std::{fmt::Debug, io};
 
 mod cross_terminal;
 pub use cross_terminal::CrossTerminal;
+mod record;
+pub use record::*;
 
 pub trait TerminalImpl: Send {
     type Event: Clone + Debug;
@@ -43,6 +45,20 @@ where
         })
     }
 
+    // 先按原时间间隔回放 `recording` 中的事件，放完后接上终端的实时事件流。
+    pub fn with_replay(inner: T, recording: Recording) -> io::Result<Self>
+    where
+        T: TerminalImpl<Event = crossterm::event::Event>,
+    {
+        let mut inner = Box::new(inner);
+        Ok(Self {
+            event_stream: record::replay_stream(recording)
+                .chain(inner.event_stream()?)
+                .boxed(),
+            inner,
+        })
+    }
+
     pub fn draw<F>(&mut self, f: F) -> io::Result<()>
     where
         F: FnOnce(&mut ratatui::Frame),
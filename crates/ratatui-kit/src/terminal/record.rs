@@ -0,0 +1,207 @@
+// 输入会话录制与回放：复现用户报告的问题。
+//
+// - 录制：[`Recorder`] 挂在渲染循环上（`ElementExt::fullscreen_recorded`），在
+//   `Terminal::next_event` 与 `InputRuntime::dispatch` 之间记下每个 raw 事件及其相对时间戳，
+//   时间从渲染循环开始时起算。退出后取 [`Recorder::recording`]；开启 `serde` feature 后可用
+//   [`Recorder::save`] / [`Recording::save`] 写成 JSON 文件，[`Recording::load`] 读回。
+// - 回放：`ElementExt::replay` 在真实终端按原时间间隔重放，放完后交回实时输入；
+//   无头回放见 `test_util::replay`（`test-util` feature）。
+//
+// 终端尺寸：录制起始尺寸存于 [`Recording`]，之后的尺寸变化本身就是 `Event::Resize`。
+// 真实终端回放无法强制尺寸，尺寸不一致时布局可能与录制时不同。
+
+#[cfg(feature = "serde")]
+use std::{
+    fs::File,
+    io::{self, BufReader, BufWriter, Write},
+    path::Path,
+};
+use std::{
+    sync::{Arc, Mutex},
+    thread,
+    time::{Duration, Instant},
+};
+
+use crossterm::event::Event;
+use futures::{StreamExt, channel::mpsc, stream::BoxStream};
+
+// 一次录制的完整输入会话。
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Recording {
+    // 录制开始时的终端宽度。
+    pub width: u16,
+    // 录制开始时的终端高度。
+    pub height: u16,
+    // 按到达顺序排列的事件。
+    pub events: Vec<RecordedEvent>,
+}
+
+#[cfg(feature = "serde")]
+impl Recording {
+    // 写成 JSON 文件，覆盖已有文件。
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        serde_json::to_writer(&mut writer, self)?;
+        writer.flush()
+    }
+
+    // 读取 `save` 写出的文件。
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        let reader = BufReader::new(File::open(path)?);
+        Ok(serde_json::from_reader(reader)?)
+    }
+}
+
+// 录制中的单个 raw 事件。
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RecordedEvent {
+    // 距录制开始的时间。
+    pub at: Duration,
+    pub event: Event,
+}
+
+// 录制句柄。`clone` 共享同一份录制，渲染循环写入、调用方随时取快照。
+#[derive(Clone)]
+pub struct Recorder {
+    // 时间戳的起点，渲染循环开始时重置。
+    start: Arc<Mutex<Instant>>,
+    recording: Arc<Mutex<Recording>>,
+}
+
+impl Default for Recorder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Recorder {
+    // 以当前终端尺寸开始录制；取不到尺寸（非终端环境）时记为 0x0。
+    pub fn new() -> Self {
+        let (width, height) = crossterm::terminal::size().unwrap_or_default();
+        Self::with_size(width, height)
+    }
+
+    // 以给定的起始尺寸开始录制。
+    pub fn with_size(width: u16, height: u16) -> Self {
+        Self {
+            start: Arc::new(Mutex::new(Instant::now())),
+            recording: Arc::new(Mutex::new(Recording {
+                width,
+                height,
+                events: Vec::new(),
+            })),
+        }
+    }
+
+    // 渲染循环开始：之后的时间戳从此刻起算，构造到启动之间的准备时间不计入首个间隔。
+    pub(crate) fn restart(&self) {
+        *self.start.lock().unwrap() = Instant::now();
+    }
+
+    pub(crate) fn record(&self, event: &Event) {
+        let at = self.start.lock().unwrap().elapsed();
+        self.recording.lock().unwrap().events.push(RecordedEvent {
+            at,
+            event: event.clone(),
+        });
+    }
+
+    // 当前已录制内容的快照。
+    pub fn recording(&self) -> Recording {
+        self.recording.lock().unwrap().clone()
+    }
+
+    // 把当前已录制的内容写成 JSON 文件，见 [`Recording::save`]。
+    #[cfg(feature = "serde")]
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        self.recording.lock().unwrap().save(path)
+    }
+}
+
+// 按录制的时间间隔逐个产出事件的流。由独立线程计时，运行时无关（不依赖 tokio 定时器）；
+// 流被丢弃后线程在下一次发送时退出。
+pub(crate) fn replay_stream(recording: Recording) -> BoxStream<'static, Event> {
+    let (tx, rx) = mpsc::unbounded();
+    thread::spawn(move || {
+        let start = Instant::now();
+        for RecordedEvent { at, event } in recording.events {
+            if let Some(wait) = at.checked_sub(start.elapsed()) {
+                thread::sleep(wait);
+            }
+            if tx.unbounded_send(event).is_err() {
+                break;
+            }
+        }
+    });
+    rx.boxed()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    fn key(c: char) -> Event {
+        Event::Key(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE))
+    }
+
+    #[test]
+    fn recorder_keeps_order_and_monotonic_timestamps() {
+        let recorder = Recorder::with_size(80, 24);
+        recorder.record(&key('a'));
+        recorder.clone().record(&Event::Resize(100, 30));
+
+        let recording = recorder.recording();
+        assert_eq!((recording.width, recording.height), (80, 24));
+        let events: Vec<_> = recording.events.iter().map(|e| e.event.clone()).collect();
+        assert_eq!(events, [key('a'), Event::Resize(100, 30)]);
+        assert!(recording.events[0].at <= recording.events[1].at);
+    }
+
+    #[test]
+    fn restart_moves_the_time_origin() {
+        let recorder = Recorder::with_size(80, 24);
+        thread::sleep(Duration::from_millis(20));
+        recorder.restart();
+        recorder.record(&key('a'));
+        assert!(recorder.recording().events[0].at < Duration::from_millis(20));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn recording_round_trips_through_a_file() {
+        let recorder = Recorder::with_size(80, 24);
+        recorder.record(&key('a'));
+        recorder.record(&Event::Resize(100, 30));
+        let path =
+            std::env::temp_dir().join(format!("ratatui-kit-recording-{}.json", std::process::id()));
+
+        recorder.save(&path).unwrap();
+        let loaded = Recording::load(&path).unwrap();
+        let _ = std::fs::remove_file(&path);
+        assert_eq!(loaded, recorder.recording());
+    }
+
+    #[test]
+    fn replay_stream_yields_recorded_events_in_order() {
+        let recording = Recording {
+            width: 10,
+            height: 2,
+            events: vec![
+                RecordedEvent {
+                    at: Duration::ZERO,
+                    event: key('a'),
+                },
+                RecordedEvent {
+                    at: Duration::from_millis(5),
+                    event: key('b'),
+                },
+            ],
+        };
+
+        let events: Vec<_> = futures::executor::block_on(replay_stream(recording).collect());
+        assert_eq!(events, [key('a'), key('b')]);
+    }
+}
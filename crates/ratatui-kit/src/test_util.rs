@@ -24,15 +24,27 @@
 //! assert_eq!(buf[(0, 0)].style().fg, Some(ratatui::style::Color::Red));
 //! ```
 
-use std::io;
+use std::{
+    cell::RefCell,
+    io,
+    time::{Duration, Instant},
+};
 
+use crossterm::event::Event;
 use ratatui::{backend::TestBackend, buffer::Buffer};
 
 use crate::{
-    AnyElement, ComponentDrawer, element::ElementRepr, render::tree::Tree,
-    terminal::UpdaterTerminal,
+    AnyElement, ComponentDrawer,
+    element::ElementRepr,
+    render::tree::Tree,
+    terminal::{Recording, UpdaterTerminal},
+    time::{Clock, ClockGuard},
 };
 
+thread_local! {
+    static ACTIVE_CLOCK: RefCell<Option<FakeClock>> = const { RefCell::new(None) };
+}
+
 // no-op 终端：`insert_before` 空操作，仅供驱动 update；事件不经终端订阅。
 struct NoopTerminal;
 
@@ -55,6 +67,10 @@ pub fn render_frame(el: impl Into<AnyElement<'static>>, width: u16, height: u16)
 /// Render a component tree over several frames into an offscreen buffer.
 /// Needed for components that read layout info from the previous frame
 /// (e.g. `Input`'s `use_previous_size`).
+///
+/// With a [`FakeClock`] installed, the clock advances by its frame step
+/// before every frame after the first, and timers that came due fire before
+/// the frame renders.
 #[must_use]
 pub fn render_frames(
     el: impl Into<AnyElement<'static>>,
@@ -66,19 +82,190 @@ pub fn render_frames(
     let helper = el.helper();
     let mut tree = Tree::new(el.props_mut(), helper);
 
-    let mut noop = NoopTerminal;
     let mut terminal = ratatui::Terminal::new(TestBackend::new(width, height)).unwrap();
 
-    for _ in 0..frames.max(1) {
-        tree.update_once(&mut noop);
-        terminal
-            .draw(|frame| {
-                let area = frame.area();
-                let mut drawer = ComponentDrawer::new(frame, area);
-                tree.draw_root(&mut drawer);
-            })
-            .unwrap();
+    for index in 0..frames.max(1) {
+        if index > 0 {
+            step_clock(&mut tree);
+        }
+        frame(&mut tree, &mut terminal);
+    }
+
+    terminal.backend().buffer().clone()
+}
+
+/// Replay a recorded input session headlessly and return the final frame.
+///
+/// The buffer starts at the recording's size and follows `Event::Resize`.
+/// Events are fed one per frame, as the render loop does, ignoring the
+/// recorded timestamps; application events published along the way are
+/// delivered after the event that caused them. Replay stops early where the
+/// app would have exited (Ctrl+C or `use_exit`).
+///
+/// ```
+/// use std::time::Duration;
+/// use ratatui_kit::crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+/// use ratatui_kit::prelude::*;
+/// use ratatui_kit::test_util::replay;
+///
+/// #[component]
+/// fn Echo(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+///     let mut last = hooks.use_state(|| ' ');
+///     hooks.use_event_handler(EventScope::Current, EventPriority::Normal, move |event| {
+///         if let Event::Key(KeyEvent { code: KeyCode::Char(c), .. }) = event {
+///             last.set(c);
+///         }
+///         EventResult::Ignored
+///     });
+///     element!(Text(text: last.get().to_string()))
+/// }
+///
+/// let recording = Recording {
+///     width: 4,
+///     height: 1,
+///     events: vec![RecordedEvent {
+///         at: Duration::ZERO,
+///         event: Event::Key(KeyEvent::new(KeyCode::Char('k'), KeyModifiers::NONE)),
+///     }],
+/// };
+/// let buf = replay(element!(Echo), &recording);
+/// assert_eq!(buf[(0, 0)].symbol(), "k");
+/// ```
+#[must_use]
+pub fn replay(el: impl Into<AnyElement<'static>>, recording: &Recording) -> Buffer {
+    let mut el = el.into();
+    let helper = el.helper();
+    let mut tree = Tree::new(el.props_mut(), helper);
+    let mut terminal =
+        ratatui::Terminal::new(TestBackend::new(recording.width, recording.height)).unwrap();
+
+    frame(&mut tree, &mut terminal);
+    for recorded in &recording.events {
+        if let Event::Resize(width, height) = recorded.event {
+            terminal.backend_mut().resize(width, height);
+        }
+        if !tree.handle_event(recorded.event.clone()) {
+            break;
+        }
+        frame(&mut tree, &mut terminal);
+        while tree.dispatch_app_event() {
+            frame(&mut tree, &mut terminal);
+        }
+        if tree.should_exit() {
+            break;
+        }
     }
 
     terminal.backend().buffer().clone()
 }
+
+/// A manually advanced clock for deterministic tests of time-based hooks
+/// (`use_interval`, `use_timeout`, `use_debounced_value`, …).
+///
+/// Component trees created on this thread while the clock is
+/// [installed](FakeClock::install) read time from it instead of the system
+/// clock, and their timers fire only when it is advanced.
+///
+/// ```
+/// use std::time::Duration;
+/// use ratatui_kit::prelude::*;
+/// use ratatui_kit::test_util::{FakeClock, render_frames};
+///
+/// #[component]
+/// fn Ticker(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+///     let mut ticks = hooks.use_state(|| 0);
+///     hooks.use_interval(Duration::from_millis(100), move || ticks += 1);
+///     element!(Text(text: ticks.get().to_string()))
+/// }
+///
+/// let clock = FakeClock::new().with_frame_step(Duration::from_millis(100));
+/// let _guard = clock.install();
+/// // Frames at 0ms, 100ms, 200ms and 300ms.
+/// let buf = render_frames(element!(Ticker), 2, 1, 4);
+/// assert_eq!(buf[(0, 0)].symbol(), "3");
+/// ```
+#[derive(Clone)]
+pub struct FakeClock {
+    clock: Clock,
+    frame_step: Duration,
+}
+
+impl Default for FakeClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FakeClock {
+    /// A clock frozen at the current instant, with a zero frame step.
+    pub fn new() -> Self {
+        Self {
+            clock: Clock::manual(Instant::now()),
+            frame_step: Duration::ZERO,
+        }
+    }
+
+    /// How far [`render_frames`] advances the clock between frames.
+    #[must_use]
+    pub fn with_frame_step(mut self, step: Duration) -> Self {
+        self.frame_step = step;
+        self
+    }
+
+    pub fn now(&self) -> Instant {
+        self.clock.now()
+    }
+
+    /// Move time forward and wake every timer that came due.
+    pub fn advance(&self, duration: Duration) {
+        self.clock.advance(duration);
+    }
+
+    /// The underlying [`Clock`], as seen by components through
+    /// `SystemContext::clock` / `use_clock`.
+    pub fn clock(&self) -> Clock {
+        self.clock.clone()
+    }
+
+    /// Use this clock for component trees created on the current thread
+    /// until the returned guard is dropped.
+    #[must_use = "the clock is uninstalled when the guard is dropped"]
+    pub fn install(&self) -> FakeClockGuard {
+        FakeClockGuard {
+            _clock: self.clock.install(),
+            previous: ACTIVE_CLOCK.replace(Some(self.clone())),
+        }
+    }
+}
+
+/// Keeps a [`FakeClock`] installed; restores the previous clock when dropped.
+pub struct FakeClockGuard {
+    _clock: ClockGuard,
+    previous: Option<FakeClock>,
+}
+
+impl Drop for FakeClockGuard {
+    fn drop(&mut self) {
+        ACTIVE_CLOCK.set(self.previous.take());
+    }
+}
+
+// 有假时钟时推进一个帧步长，并轮询组件树让到期的定时器在下一帧之前触发。
+fn step_clock(tree: &mut Tree) {
+    let Some(clock) = ACTIVE_CLOCK.with_borrow(Clone::clone) else {
+        return;
+    };
+    clock.advance(clock.frame_step);
+    tree.poll_once();
+}
+
+fn frame(tree: &mut Tree, terminal: &mut ratatui::Terminal<TestBackend>) {
+    tree.update_once(&mut NoopTerminal);
+    terminal
+        .draw(|frame| {
+            let area = frame.area();
+            let mut drawer = ComponentDrawer::new(frame, area);
+            tree.draw_root(&mut drawer);
+        })
+        .unwrap();
+}
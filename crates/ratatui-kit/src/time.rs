@@ -0,0 +1,382 @@
+// 运行时无关的计时原语：不依赖 tokio 等执行器的定时器，由后台线程计时后唤醒 future，
+// 因此在任意执行器下都可用。
+//
+// - [`Clock`]：组件树读取「当前时间」的唯一入口，由 `SystemContext` 持有。默认是系统时钟；
+//   测试可换成手动推进的时钟（`test_util::FakeClock`），让计时与动画完全确定。
+// - [`Timers`]：组件树的时间轮，由 `SystemContext` 持有。计时 hook 经 [`Timer`] 登记截止时间与
+//   waker，整棵树共用一条驱动线程，到期后唤醒对应组件，hook 在 `poll_change` 中触发回调。
+//   手动时钟下不启动驱动线程，由时钟推进时触发到期的定时器。hook 内的异步等待（如重试退避）
+//   用 [`TimerHandle::sleep`]，同样走时间轮与组件树的时钟。
+
+use std::{
+    collections::BTreeMap,
+    future::poll_fn,
+    sync::{Arc, Condvar, Mutex, MutexGuard, Weak},
+    task::{Poll, Waker},
+    thread,
+    time::{Duration, Instant},
+};
+
+// 组件树的时钟。`clone` 共享同一时间源。
+#[derive(Clone, Default)]
+pub struct Clock {
+    // `None` 为系统时钟。
+    manual: Option<Arc<ManualClock>>,
+}
+
+struct ManualClock {
+    now: Mutex<Instant>,
+    // 由该时钟驱动的时间轮，推进时逐个触发到期的定时器。
+    timers: Mutex<Vec<Weak<TimerShared>>>,
+}
+
+impl Clock {
+    pub fn system() -> Self {
+        Self::default()
+    }
+
+    pub fn now(&self) -> Instant {
+        match &self.manual {
+            Some(manual) => *lock(&manual.now),
+            None => Instant::now(),
+        }
+    }
+
+    // 从 `start` 起、只随 `advance` 前进的时钟。
+    #[cfg(any(test, feature = "test-util"))]
+    pub(crate) fn manual(start: Instant) -> Self {
+        Self {
+            manual: Some(Arc::new(ManualClock {
+                now: Mutex::new(start),
+                timers: Mutex::default(),
+            })),
+        }
+    }
+
+    // 推进手动时钟并唤醒到期的定时器；系统时钟上无操作。
+    #[cfg(any(test, feature = "test-util"))]
+    pub(crate) fn advance(&self, duration: Duration) {
+        let Some(manual) = &self.manual else {
+            return;
+        };
+        let now = {
+            let mut now = lock(&manual.now);
+            *now += duration;
+            *now
+        };
+        let timers: Vec<_> = {
+            let mut timers = lock(&manual.timers);
+            timers.retain(|shared| shared.strong_count() > 0);
+            timers.iter().filter_map(Weak::upgrade).collect()
+        };
+        for shared in timers {
+            let expired = shared.lock().take_expired(now);
+            expired.into_iter().for_each(Waker::wake);
+        }
+    }
+
+    // 当前线程新建组件树时采用的时钟：测试装入的手动时钟，否则为系统时钟。
+    pub(crate) fn current() -> Self {
+        #[cfg(any(test, feature = "test-util"))]
+        if let Some(clock) = INSTALLED.with_borrow(Clone::clone) {
+            return clock;
+        }
+        Self::system()
+    }
+
+    // 在当前线程装入时钟，直到返回的守卫被 drop（恢复之前的时钟）。
+    #[cfg(any(test, feature = "test-util"))]
+    pub(crate) fn install(&self) -> ClockGuard {
+        ClockGuard {
+            previous: INSTALLED.replace(Some(self.clone())),
+        }
+    }
+
+    fn is_manual(&self) -> bool {
+        self.manual.is_some()
+    }
+}
+
+#[cfg(any(test, feature = "test-util"))]
+thread_local! {
+    static INSTALLED: std::cell::RefCell<Option<Clock>> = const { std::cell::RefCell::new(None) };
+}
+
+#[cfg(any(test, feature = "test-util"))]
+pub(crate) struct ClockGuard {
+    previous: Option<Clock>,
+}
+
+#[cfg(any(test, feature = "test-util"))]
+impl Drop for ClockGuard {
+    fn drop(&mut self) {
+        INSTALLED.set(self.previous.take());
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|err| err.into_inner())
+}
+
+// 时间轮中的一项：截止时间 + 序号（同一时刻的多个定时器按登记顺序区分）。
+type TimerKey = (Instant, u64);
+
+#[derive(Default)]
+struct TimerQueue {
+    entries: BTreeMap<TimerKey, Waker>,
+    next_id: u64,
+    driver_started: bool,
+    closed: bool,
+}
+
+impl TimerQueue {
+    // 取出截止时间不晚于 `now` 的全部 waker。
+    fn take_expired(&mut self, now: Instant) -> Vec<Waker> {
+        let pending = self.entries.split_off(&(now, u64::MAX));
+        std::mem::replace(&mut self.entries, pending)
+            .into_values()
+            .collect()
+    }
+
+    fn next_deadline(&self) -> Option<Instant> {
+        self.entries
+            .first_key_value()
+            .map(|((deadline, _), _)| *deadline)
+    }
+}
+
+#[derive(Default)]
+struct TimerShared {
+    queue: Mutex<TimerQueue>,
+    changed: Condvar,
+}
+
+impl TimerShared {
+    fn lock(&self) -> MutexGuard<'_, TimerQueue> {
+        lock(&self.queue)
+    }
+}
+
+// 组件树的时间轮。持有者（`SystemContext`）销毁时驱动线程随之退出。
+#[derive(Default)]
+pub(crate) struct Timers {
+    shared: Arc<TimerShared>,
+    clock: Clock,
+}
+
+impl Timers {
+    pub(crate) fn new(clock: Clock) -> Self {
+        let shared = Arc::new(TimerShared::default());
+        if let Some(manual) = &clock.manual {
+            lock(&manual.timers).push(Arc::downgrade(&shared));
+        }
+        Self { shared, clock }
+    }
+
+    pub(crate) fn clock(&self) -> &Clock {
+        &self.clock
+    }
+
+    pub(crate) fn handle(&self) -> TimerHandle {
+        TimerHandle {
+            shared: self.shared.clone(),
+            clock: self.clock.clone(),
+        }
+    }
+}
+
+impl Drop for Timers {
+    fn drop(&mut self) {
+        self.shared.lock().closed = true;
+        self.shared.changed.notify_all();
+    }
+}
+
+// 时间轮的共享句柄，hook 持有它登记定时器。
+#[derive(Clone)]
+pub(crate) struct TimerHandle {
+    shared: Arc<TimerShared>,
+    clock: Clock,
+}
+
+impl TimerHandle {
+    pub(crate) fn now(&self) -> Instant {
+        self.clock.now()
+    }
+
+    // 在时间轮上等待 `duration` 后完成，计时取自组件树的时钟（手动时钟下随 `advance` 到期）。
+    // 丢弃返回的 future 即注销定时器。
+    pub(crate) fn sleep(&self, duration: Duration) -> impl Future<Output = ()> + use<> {
+        let mut timer = Timer::new(self.clone());
+        let deadline = timer.now() + duration;
+        poll_fn(move |cx| {
+            if timer.poll_deadline(deadline, cx) {
+                Poll::Ready(())
+            } else {
+                Poll::Pending
+            }
+        })
+    }
+
+    fn schedule(&self, previous: Option<TimerKey>, deadline: Instant, waker: &Waker) -> TimerKey {
+        let mut queue = self.shared.lock();
+        if let Some(previous) = previous {
+            queue.entries.remove(&previous);
+        }
+        let key = (deadline, queue.next_id);
+        queue.next_id += 1;
+        queue.entries.insert(key, waker.clone());
+        let earliest = queue.next_deadline() == Some(deadline);
+        // 手动时钟由 `Clock::advance` 触发，不需要驱动线程。
+        if !queue.driver_started && !self.clock.is_manual() {
+            queue.driver_started = true;
+            let shared = self.shared.clone();
+            thread::spawn(move || drive(&shared));
+        }
+        drop(queue);
+        // 只有最早的截止时间提前时驱动线程才需要重新计时。
+        if earliest {
+            self.shared.changed.notify_all();
+        }
+        key
+    }
+
+    fn cancel(&self, key: TimerKey) {
+        self.shared.lock().entries.remove(&key);
+    }
+}
+
+// 驱动线程：睡到最早的截止时间，唤醒到期的定时器。
+fn drive(shared: &TimerShared) {
+    let mut queue = shared.lock();
+    while !queue.closed {
+        let now = Instant::now();
+        let expired = queue.take_expired(now);
+        if !expired.is_empty() {
+            drop(queue);
+            expired.into_iter().for_each(Waker::wake);
+            queue = shared.lock();
+            continue;
+        }
+        queue = match queue.next_deadline() {
+            Some(deadline) => {
+                shared
+                    .changed
+                    .wait_timeout(queue, deadline - now)
+                    .unwrap_or_else(|err| err.into_inner())
+                    .0
+            }
+            None => shared
+                .changed
+                .wait(queue)
+                .unwrap_or_else(|err| err.into_inner()),
+        };
+    }
+}
+
+// 单个定时器，由 hook 持有：同一时刻最多登记一个截止时间，drop 即注销。
+pub(crate) struct Timer {
+    handle: TimerHandle,
+    key: Option<TimerKey>,
+}
+
+impl Timer {
+    pub(crate) fn new(handle: TimerHandle) -> Self {
+        Self { handle, key: None }
+    }
+
+    pub(crate) fn now(&self) -> Instant {
+        self.handle.now()
+    }
+
+    // 已到 `deadline` 返回 `true` 并注销；否则（重新）登记 waker，到期时唤醒。
+    pub(crate) fn poll_deadline(&mut self, deadline: Instant, cx: &std::task::Context) -> bool {
+        if self.now() >= deadline {
+            self.cancel();
+            return true;
+        }
+        // 每次轮询都以当前 waker 重新登记：驱动线程唤醒后即移除该项，组件的 waker 也可能更换。
+        self.key = Some(self.handle.schedule(self.key, deadline, cx.waker()));
+        false
+    }
+
+    pub(crate) fn cancel(&mut self) {
+        if let Some(key) = self.key.take() {
+            self.handle.cancel(key);
+        }
+    }
+}
+
+impl Drop for Timer {
+    fn drop(&mut self) {
+        self.cancel();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::mpsc;
+
+    struct ChannelWaker(Mutex<mpsc::Sender<()>>);
+
+    impl std::task::Wake for ChannelWaker {
+        fn wake(self: Arc<Self>) {
+            let _ = self.0.lock().unwrap().send(());
+        }
+    }
+
+    #[test]
+    fn take_expired_splits_at_now() {
+        let start = Instant::now();
+        let mut queue = TimerQueue::default();
+        for (id, offset) in [(0, 5), (1, 10), (2, 10), (3, 20)] {
+            queue.entries.insert(
+                (start + Duration::from_millis(offset), id),
+                futures::task::noop_waker(),
+            );
+        }
+
+        assert_eq!(
+            queue.take_expired(start + Duration::from_millis(10)).len(),
+            3
+        );
+        assert_eq!(
+            queue.next_deadline(),
+            Some(start + Duration::from_millis(20))
+        );
+    }
+
+    #[test]
+    fn manual_clock_fires_timers_only_on_advance() {
+        let clock = Clock::manual(Instant::now());
+        let timers = Timers::new(clock.clone());
+        let (tx, rx) = mpsc::channel();
+        let waker = Waker::from(Arc::new(ChannelWaker(Mutex::new(tx))));
+        let cx = std::task::Context::from_waker(&waker);
+        let mut timer = Timer::new(timers.handle());
+        let deadline = clock.now() + Duration::from_secs(60);
+
+        assert!(!timer.poll_deadline(deadline, &cx));
+        clock.advance(Duration::from_secs(59));
+        assert!(rx.try_recv().is_err());
+        clock.advance(Duration::from_secs(1));
+        assert!(rx.try_recv().is_ok());
+        assert!(timer.poll_deadline(deadline, &cx));
+    }
+
+    #[test]
+    fn driver_wakes_timer_after_deadline() {
+        let timers = Timers::default();
+        let (tx, rx) = mpsc::channel();
+        let waker = Waker::from(Arc::new(ChannelWaker(Mutex::new(tx))));
+        let cx = std::task::Context::from_waker(&waker);
+        let mut timer = Timer::new(timers.handle());
+        let deadline = timer.now() + Duration::from_millis(10);
+
+        assert!(!timer.poll_deadline(deadline, &cx));
+        rx.recv_timeout(Duration::from_secs(5))
+            .expect("timer should fire");
+        assert!(timer.poll_deadline(deadline, &cx));
+    }
+}
@@ -0,0 +1,159 @@
+//! 表单示例：`use_form` + `Form` / `FormField`，字段绑定 `Input`、`Select`、`MultiSelect`。
+//!
+//! Tab / Shift+Tab 切换字段，`Ctrl+S` 提交，`Ctrl+R` 重置，`Ctrl+Q` 退出。用户名带模拟的异步查重，
+//! 提交失败时焦点回到第一个出错的字段。
+
+use std::{collections::HashSet, time::Duration};
+
+use ratatui_kit::{
+    crossterm::event::{Event, KeyCode, KeyEventKind, KeyModifiers},
+    prelude::*,
+    ratatui::{
+        layout::{Constraint, Direction},
+        style::{Style, Stylize},
+        text::Line,
+        widgets::Padding,
+    },
+};
+
+const ROLES: [&str; 3] = ["Developer", "Designer", "Manager"];
+const TOPICS: [&str; 4] = ["Rust", "Terminal UI", "Async", "Testing"];
+const TAKEN: [&str; 2] = ["admin", "root"];
+
+#[tokio::main]
+async fn main() {
+    element!(App)
+        .fullscreen()
+        .await
+        .expect("Failed to run the application");
+}
+
+#[component]
+fn App(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+    let mut status = hooks.use_state(|| "fill in the form".to_string());
+    let form = hooks.use_form(|form| {
+        form.field("username", String::new())
+            .validate(|name: &String| {
+                if name.trim().len() < 3 {
+                    Err("at least 3 characters".to_string())
+                } else {
+                    Ok(())
+                }
+            })
+            .validate_async(|name: &String| {
+                let name = name.trim().to_string();
+                async move {
+                    tokio::time::sleep(Duration::from_millis(400)).await;
+                    if TAKEN.contains(&name.as_str()) {
+                        Err(format!("`{name}` is taken"))
+                    } else {
+                        Ok(())
+                    }
+                }
+            });
+        form.field("email", String::new())
+            .validate(|email: &String| {
+                if email.contains('@') {
+                    Ok(())
+                } else {
+                    Err("enter a valid email".to_string())
+                }
+            });
+        form.field("role", None::<usize>)
+            .validate(|role: &Option<usize>| role.map(|_| ()).ok_or("pick a role".to_string()));
+        form.field("topics", HashSet::<usize>::new())
+            .validate(|topics: &HashSet<usize>| {
+                if topics.is_empty() {
+                    Err("pick at least one topic".to_string())
+                } else {
+                    Ok(())
+                }
+            });
+        form.on_submit(move |values| {
+            let name = values.get::<String>("username");
+            async move {
+                tokio::time::sleep(Duration::from_millis(300)).await;
+                status.set(format!("welcome, {name}!"));
+                Ok(())
+            }
+        });
+    });
+    let mut exit = hooks.use_exit();
+
+    hooks.use_event_handler(EventScope::Current, EventPriority::Low, move |event| {
+        let Event::Key(key) = event else {
+            return EventResult::Ignored;
+        };
+        if key.kind != KeyEventKind::Press || !key.modifiers.contains(KeyModifiers::CONTROL) {
+            return EventResult::Ignored;
+        }
+        match key.code {
+            KeyCode::Char('s') => {
+                form.submit();
+                status.set("submitting…".to_string());
+            }
+            KeyCode::Char('r') => {
+                form.reset();
+                status.set("form reset".to_string());
+            }
+            KeyCode::Char('q') => exit(),
+            _ => return EventResult::Ignored,
+        }
+        EventResult::Consumed
+    });
+
+    let username = form.field::<String>("username");
+    let email = form.field::<String>("email");
+    let role = form.field::<Option<usize>>("role");
+    let topics = form.field::<HashSet<usize>>("topics");
+
+    let status_line = if form.is_submitting() {
+        Line::from("submitting…").yellow()
+    } else if form.submit_count() > 0 && !form.is_valid() {
+        Line::from("fix the highlighted fields").red()
+    } else {
+        Line::from(status.read().to_string()).green()
+    };
+    let username_label = if username.is_validating() {
+        "Username (checking…)"
+    } else {
+        "Username"
+    };
+
+    element!(Center(width: Constraint::Length(64), height: Constraint::Length(26)) {
+        Border(
+            top_title: Line::from(" sign up ").bold().centered(),
+            bottom_title: Line::from(" Tab next | Ctrl+S submit | Ctrl+R reset | Ctrl+Q quit ")
+                .dark_gray()
+                .centered(),
+            padding: Padding::horizontal(1),
+        ) {
+            Form(form: form, flex_direction: Direction::Vertical) {
+                FormField(field: username, label: username_label, height: Constraint::Length(3)) {
+                    Input(field: username, placeholder: "pick a handle".to_string())
+                }
+                FormField(field: email, label: "Email", height: Constraint::Length(3)) {
+                    Input(field: email, placeholder: "you@example.com".to_string())
+                }
+                FormField(field: role, label: "Role", height: Constraint::Length(7)) {
+                    Select<&'static str>(
+                        items: ROLES.to_vec(),
+                        field: role,
+                        highlight_symbol: "> ",
+                    )
+                }
+                FormField(field: topics, label: "Topics (Space to toggle)", height: Constraint::Length(8)) {
+                    MultiSelect<&'static str>(
+                        items: TOPICS.to_vec(),
+                        field: topics,
+                        highlight_symbol: "> ",
+                        selected_item_style: Style::new().cyan(),
+                    )
+                }
+                View(height: Constraint::Length(1)) {
+                    Text(text: status_line)
+                }
+            }
+        }
+    })
+}
@@ -1,6 +1,6 @@
 //! Input 内置组件示例。
 //!
-//! `Input` 只负责渲染 `tui_input::Input` 状态；键盘事件由页面 handler 转发。
+//! `Input` 只负责渲染 `tui_input::Input` 状态；键盘与粘贴事件由页面 handler 转发。
 
 use ratatui_kit::{
     crossterm::event::{Event, KeyCode, KeyEventKind},
@@ -30,6 +30,13 @@ fn App(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
     let mut exit = hooks.use_exit();
 
     hooks.use_event_handler(EventScope::Current, EventPriority::Normal, move |event| {
+        // bracketed paste:整段文本一次插入,换行替换为空格而不是触发提交。
+        if let Event::Paste(text) = &event {
+            if paste_into(&mut input.write(), text, PasteNewlines::Space) {
+                status.set(format!("pasted {} chars", text.chars().count()));
+            }
+            return EventResult::Consumed;
+        }
         let Event::Key(key) = &event else {
             return EventResult::Ignored;
         };
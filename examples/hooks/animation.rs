@@ -0,0 +1,116 @@
+use std::time::Duration;
+
+use ratatui_kit::{
+    crossterm::event::{Event, KeyCode, KeyEventKind},
+    prelude::*,
+    ratatui::{
+        layout::{Constraint, Direction, Flex, Rect},
+        style::{Color, Style, Stylize},
+        text::Line,
+    },
+};
+
+const BAR_WIDTH: u16 = 40;
+
+#[tokio::main]
+async fn main() {
+    element!(AnimationDemo)
+        .fullscreen()
+        .await
+        .expect("Failed to run the application");
+}
+
+#[component]
+fn AnimationDemo(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+    let mut progress = hooks.use_state(|| 0_u16);
+    let mut flash = hooks.use_state(|| false);
+    let mut panel_open = hooks.use_state(|| false);
+
+    // 进度条平滑：目标每次跳变，宽度都缓动过去。
+    let filled = hooks.use_tween(
+        progress.get() * BAR_WIDTH / 100,
+        Duration::from_millis(300),
+        Easing::EaseOut,
+    );
+    // 高亮淡出：按下 h 时瞬间变亮，随后回落。
+    let highlight = hooks.use_animation(
+        if flash.get() {
+            Color::Rgb(250, 200, 60)
+        } else {
+            Color::Rgb(60, 60, 60)
+        },
+        Tween {
+            duration: Duration::from_millis(600),
+            easing: Easing::EaseInOut,
+        },
+    );
+    if flash.get() && !highlight.is_animating() {
+        flash.set(false);
+    }
+    // 侧栏滑入：弹簧过渡，略带回弹。
+    let panel = hooks.use_animation(
+        Rect::new(
+            if panel_open.get() { 2 } else { 0 },
+            1,
+            if panel_open.get() { 24 } else { 0 },
+            6,
+        ),
+        Spring::default(),
+    );
+
+    let mut exit = hooks.use_exit();
+    hooks.use_event_handler(EventScope::Current, EventPriority::Normal, move |event| {
+        let Event::Key(key) = event else {
+            return EventResult::Ignored;
+        };
+        if key.kind != KeyEventKind::Press {
+            return EventResult::Ignored;
+        }
+
+        match key.code {
+            KeyCode::Char('+') => progress.set((progress.get() + 25).min(100)),
+            KeyCode::Char('-') => progress.set(progress.get().saturating_sub(25)),
+            KeyCode::Char('h') => flash.set(true),
+            KeyCode::Char('p') => panel_open.set(!panel_open.get()),
+            KeyCode::Char('q') => exit(),
+            _ => return EventResult::Ignored,
+        }
+        EventResult::Consumed
+    });
+
+    let bar = format!(
+        "{}{}",
+        "█".repeat(filled as usize),
+        "░".repeat((BAR_WIDTH - filled) as usize)
+    );
+    let panel = *panel.value();
+
+    element!(
+        View {
+            Center(
+                width: Constraint::Length(56),
+                height: Constraint::Length(9),
+            ) {
+                Border(
+                    border_style: Style::new().cyan(),
+                    flex_direction: Direction::Vertical,
+                    justify_content: Flex::Center,
+                    top_title: Line::from(" use_tween / use_animation ").cyan().bold().centered(),
+                    bottom_title: Line::from(" +/- progress · h highlight · p panel · q quit ").dark_gray().centered(),
+                ) {
+                    View(height: Constraint::Length(1)) {
+                        Text(text: Line::from(format!("{bar} {:>3}%", progress.get())).green().centered())
+                    }
+                    View(height: Constraint::Length(1)) {
+                        Text(text: Line::from(" highlighted row ").bg(*highlight.value()).centered())
+                    }
+                }
+            }
+            Positioned(x: panel.x, y: panel.y, width: panel.width, height: panel.height, clear: true) {
+                Border(border_style: Style::new().magenta(), top_title: Line::from(" panel ").magenta()) {
+                    Text(text: "spring-driven slide")
+                }
+            }
+        }
+    )
+}
@@ -50,10 +50,18 @@ fn AsyncStateDemo(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
         }
 
         match key.code {
-            KeyCode::Char('r') | KeyCode::Char('R') => {
+            KeyCode::Char('n') | KeyCode::Char('N') => {
                 refresh += 1;
                 EventResult::Consumed
             }
+            KeyCode::Char('r') | KeyCode::Char('R') => {
+                result.refetch();
+                EventResult::Consumed
+            }
+            KeyCode::Char('c') | KeyCode::Char('C') => {
+                result.cancel();
+                EventResult::Consumed
+            }
             KeyCode::Char('q') | KeyCode::Char('Q') => {
                 exit();
                 EventResult::Consumed
@@ -65,25 +73,25 @@ fn AsyncStateDemo(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
     let mut lines = vec![
         Line::from(" use_async_state ").cyan().bold().centered(),
         Line::from(format!("request #{:02}", request_id + 1)).centered(),
-        Line::from("r refresh | q quit | Ctrl+C exit")
+        Line::from("n next request | r refetch | c cancel | q quit")
             .dark_gray()
             .centered(),
         Line::from(""),
     ];
 
-    if result.loading.get() {
-        lines.push(Line::from("status: loading").yellow().centered());
-    } else if result.error.read().is_some() {
-        lines.push(Line::from("status: error").red().centered());
-    } else {
-        lines.push(Line::from("status: ready").green().centered());
+    let value = result.read();
+    match value.status() {
+        AsyncStatus::Idle => lines.push(Line::from("status: cancelled").dark_gray().centered()),
+        AsyncStatus::Loading => lines.push(Line::from("status: loading").yellow().centered()),
+        AsyncStatus::Error => lines.push(Line::from("status: error").red().centered()),
+        AsyncStatus::Success => lines.push(Line::from("status: ready").green().centered()),
     }
 
-    if let Some(error) = result.error.read().as_ref() {
+    if let Some(error) = value.error() {
         lines.push(Line::from(format!("error: {error}")).red().centered());
     }
 
-    if let Some(items) = result.data.read().as_ref() {
+    if let Some(items) = value.data() {
         for item in items {
             lines.push(Line::from(format!("- {item}")).centered());
         }
@@ -0,0 +1,149 @@
+use ratatui_kit::{
+    crossterm::event::{Event, KeyCode, KeyEventKind},
+    prelude::*,
+    ratatui::{
+        layout::{Constraint, Direction, Flex},
+        style::{Style, Stylize},
+        text::Line,
+    },
+};
+
+#[tokio::main]
+async fn main() {
+    element!(ReducerDemo)
+        .fullscreen()
+        .await
+        .expect("Failed to run the application");
+}
+
+#[derive(Clone, Default)]
+struct Playlist {
+    tracks: Vec<String>,
+    cursor: usize,
+}
+
+#[derive(Debug)]
+enum Action {
+    Add,
+    Remove,
+    Up,
+    Down,
+    Undo,
+}
+
+fn reduce(playlist: &mut Playlist, action: Action) {
+    match action {
+        Action::Add => {
+            playlist
+                .tracks
+                .push(format!("Track {}", playlist.tracks.len() + 1));
+            playlist.cursor = playlist.tracks.len() - 1;
+        }
+        Action::Remove if !playlist.tracks.is_empty() => {
+            playlist.tracks.remove(playlist.cursor);
+            playlist.cursor = playlist.cursor.min(playlist.tracks.len().saturating_sub(1));
+        }
+        Action::Up => playlist.cursor = playlist.cursor.saturating_sub(1),
+        Action::Down => {
+            playlist.cursor = (playlist.cursor + 1).min(playlist.tracks.len().saturating_sub(1))
+        }
+        Action::Remove | Action::Undo => {}
+    }
+}
+
+#[component]
+fn ReducerDemo(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+    let last_action = hooks.use_state(String::new);
+    let history = hooks.use_state(Vec::<Playlist>::new);
+
+    // 日志中间件：记录最近一次动作。
+    let logger = move |_: State<Playlist>, action: Action, next: &dyn Fn(Action)| {
+        *last_action.write() = format!("{action:?}");
+        next(action);
+    };
+    // 撤销中间件：修改前保存快照，`Undo` 直接恢复快照而不经过归约器。
+    let undo = move |mut state: State<Playlist>, action: Action, next: &dyn Fn(Action)| {
+        if let Action::Undo = action {
+            if let Some(previous) = history.write().pop() {
+                state.set(previous);
+            }
+            return;
+        }
+        history.write().push(state.read().clone());
+        next(action);
+    };
+
+    let (playlist, dispatch) = hooks.use_reducer_with_middleware(
+        reduce,
+        Playlist::default,
+        vec![Box::new(logger), Box::new(undo)],
+    );
+
+    let mut exit = hooks.use_exit();
+    hooks.use_event_handler(EventScope::Current, EventPriority::Normal, move |event| {
+        let Event::Key(key) = event else {
+            return EventResult::Ignored;
+        };
+        if key.kind != KeyEventKind::Press {
+            return EventResult::Ignored;
+        }
+
+        let action = match key.code {
+            KeyCode::Esc => {
+                exit();
+                return EventResult::Consumed;
+            }
+            KeyCode::Char('a') => Action::Add,
+            KeyCode::Char('d') => Action::Remove,
+            KeyCode::Char('u') => Action::Undo,
+            KeyCode::Up => Action::Up,
+            KeyCode::Down => Action::Down,
+            _ => return EventResult::Ignored,
+        };
+        dispatch.dispatch(action);
+        EventResult::Consumed
+    });
+
+    let playlist = playlist.read();
+    let rows: Vec<Line<'static>> = playlist
+        .tracks
+        .iter()
+        .enumerate()
+        .map(|(index, track)| {
+            if index == playlist.cursor {
+                Line::from(format!("> {track}")).yellow().bold()
+            } else {
+                Line::from(format!("  {track}"))
+            }
+        })
+        .collect();
+    let status = format!(
+        "last action: {}  undo steps: {}",
+        *last_action.read(),
+        history.read().len()
+    );
+
+    element!(
+        Center(
+            width: Constraint::Length(48),
+            height: Constraint::Length(14),
+        ) {
+            Border(
+                border_style: Style::new().cyan(),
+                flex_direction: Direction::Vertical,
+                justify_content: Flex::Start,
+                top_title: Line::from(" use_reducer ").cyan().bold().centered(),
+                bottom_title: Line::from(" a add · d remove · ↑/↓ move · u undo · Esc quit ").dark_gray().centered(),
+            ) {
+                View(height: Constraint::Length(1)) {
+                    Text(text: Line::from(status).dark_gray())
+                }
+                {rows.into_iter().enumerate().map(|(index, row)| element!(
+                    View(key: index, height: Constraint::Length(1)) {
+                        Text(text: row)
+                    }
+                ))}
+            }
+        }
+    )
+}
@@ -0,0 +1,178 @@
+//! 全局 store 示例：`Store` + 中间件 + 异步 thunk + 动作日志面板（时间旅行）。
+//!
+//! `a` 添加、`x` 切换完成、`c` 清除已完成、`l` 异步加载示例任务；`Tab` 切到右侧日志面板，
+//! 选中任一条目按 Enter 回到该时刻的状态；`s` 把日志写入 `store-actions.log`，`Esc` 退出。
+
+use std::{sync::Mutex, time::Duration};
+
+use ratatui_kit::{
+    crossterm::event::{Event, KeyCode, KeyEventKind},
+    prelude::*,
+    ratatui::{
+        layout::{Constraint, Direction},
+        style::{Style, Stylize},
+        text::Line,
+    },
+};
+
+#[derive(Clone, Default)]
+struct Todos {
+    items: Vec<(String, bool)>,
+    cursor: usize,
+}
+
+#[derive(Debug)]
+enum Action {
+    Add(String),
+    Toggle,
+    ClearDone,
+    Up,
+    Down,
+}
+
+fn reduce(todos: &mut Todos, action: Action) {
+    match action {
+        Action::Add(title) => {
+            todos.items.push((title, false));
+            todos.cursor = todos.items.len() - 1;
+        }
+        Action::Toggle => {
+            if let Some(item) = todos.items.get_mut(todos.cursor) {
+                item.1 = !item.1;
+            }
+        }
+        Action::ClearDone => {
+            todos.items.retain(|(_, done)| !done);
+            todos.cursor = todos.cursor.min(todos.items.len().saturating_sub(1));
+        }
+        Action::Up => todos.cursor = todos.cursor.saturating_sub(1),
+        Action::Down => todos.cursor = (todos.cursor + 1).min(todos.items.len().saturating_sub(1)),
+    }
+}
+
+static TODOS: Store<Todos, Action> = Store::new(Todos::default, reduce);
+static LAST_ACTION: Mutex<String> = Mutex::new(String::new());
+
+#[tokio::main]
+async fn main() {
+    // 日志中间件：记录最近一次动作，显示在底栏。
+    TODOS.add_middleware(
+        |_: &'static Store<Todos, Action>, action: Action, next: &dyn Fn(Action)| {
+            *LAST_ACTION.lock().unwrap() = format!("{action:?}");
+            next(action);
+        },
+    );
+    TODOS.record_actions(200);
+
+    element!(App)
+        .fullscreen()
+        .await
+        .expect("Failed to run the application");
+}
+
+#[component]
+fn App(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+    let todos = hooks.use_store(&TODOS);
+    let log = hooks.use_action_log(&TODOS);
+    let mut panel_focused = hooks.use_state(|| false);
+    let mut status = hooks.use_state(String::new);
+    let mut exit = hooks.use_exit();
+
+    hooks.use_event_handler(EventScope::Current, EventPriority::Low, move |event| {
+        let Event::Key(key) = event else {
+            return EventResult::Ignored;
+        };
+        if key.kind != KeyEventKind::Press {
+            return EventResult::Ignored;
+        }
+        match key.code {
+            KeyCode::Esc => exit(),
+            KeyCode::Tab => panel_focused.set(!panel_focused.get()),
+            KeyCode::Char('s') => {
+                let message = match TODOS.dump_log("store-actions.log") {
+                    Ok(()) => "log written to store-actions.log".to_string(),
+                    Err(err) => format!("failed to write log: {err}"),
+                };
+                status.set(message);
+            }
+            _ if panel_focused.get() => return EventResult::Ignored,
+            KeyCode::Char('a') => {
+                let count = TODOS.state().read().items.len();
+                TODOS.dispatch(Action::Add(format!("Task {}", count + 1)));
+            }
+            KeyCode::Char('x') => TODOS.dispatch(Action::Toggle),
+            KeyCode::Char('c') => TODOS.dispatch(Action::ClearDone),
+            KeyCode::Up => TODOS.dispatch(Action::Up),
+            KeyCode::Down => TODOS.dispatch(Action::Down),
+            KeyCode::Char('l') => {
+                status.set("loading samples…".to_string());
+                TODOS.dispatch_async(move |store| async move {
+                    tokio::time::sleep(Duration::from_millis(600)).await;
+                    for title in ["Write docs", "Review PR", "Ship release"] {
+                        store.dispatch(Action::Add(title.to_string()));
+                    }
+                    status.set("samples loaded".to_string());
+                });
+            }
+            _ => return EventResult::Ignored,
+        }
+        EventResult::Consumed
+    });
+
+    let todos = todos.read();
+    let rows: Vec<Line<'static>> = todos
+        .items
+        .iter()
+        .enumerate()
+        .map(|(index, (title, done))| {
+            let mark = if *done { "[x]" } else { "[ ]" };
+            let line = Line::from(format!("{mark} {title}"));
+            let line = if *done { line.dark_gray() } else { line };
+            if index == todos.cursor {
+                line.yellow().bold()
+            } else {
+                line
+            }
+        })
+        .collect();
+    let footer = format!(
+        "last action: {}  {}",
+        LAST_ACTION.lock().unwrap(),
+        *status.read()
+    );
+    let (todo_border, panel_active) = if panel_focused.get() {
+        (Style::new().dark_gray(), true)
+    } else {
+        (Style::new().cyan(), false)
+    };
+
+    element!(View(flex_direction: Direction::Vertical) {
+        View(flex_direction: Direction::Horizontal) {
+            Border(
+                border_style: todo_border,
+                flex_direction: Direction::Vertical,
+                top_title: Line::from(" todos ").bold(),
+                bottom_title: Line::from(" a add · x toggle · c clear · l load · Tab log ")
+                    .dark_gray()
+                    .centered(),
+            ) {
+                {rows.into_iter().enumerate().map(|(index, row)| element!(
+                    View(key: index, height: Constraint::Length(1)) {
+                        Text(text: row)
+                    }
+                ))}
+            }
+            ActionLogPanel(
+                log: log,
+                active: panel_active,
+                width: Constraint::Length(36),
+                on_jump: move |index| {
+                    TODOS.jump_to(index);
+                },
+            )
+        }
+        View(height: Constraint::Length(1)) {
+            Text(text: Line::from(footer).dark_gray())
+        }
+    })
+}
@@ -0,0 +1,97 @@
+use std::time::Duration;
+
+use ratatui_kit::{
+    crossterm::event::{Event, KeyCode, KeyEventKind},
+    prelude::*,
+    ratatui::{
+        layout::{Constraint, Direction, Flex},
+        style::{Style, Stylize},
+        text::Line,
+    },
+};
+
+const SPINNER: [&str; 4] = ["|", "/", "-", "\\"];
+
+#[tokio::main]
+async fn main() {
+    element!(TimersDemo)
+        .fullscreen()
+        .await
+        .expect("Failed to run the application");
+}
+
+#[component]
+fn TimersDemo(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+    let mut frame = hooks.use_state(|| 0_usize);
+    hooks.use_interval(Duration::from_millis(120), move || frame += 1);
+
+    let mut hint = hooks.use_state(|| true);
+    hooks.use_timeout(Duration::from_secs(3), move || hint.set(false));
+
+    let query = hooks.use_state(String::new);
+    let debounced = hooks.use_debounced_value(query.read().clone(), Duration::from_millis(400));
+
+    let mut saves = hooks.use_state(|| 0_u32);
+    let save = hooks.use_throttled_callback(Duration::from_secs(1), move |()| saves += 1);
+
+    let mut exit = hooks.use_exit();
+    hooks.use_event_handler(EventScope::Current, EventPriority::Normal, move |event| {
+        let Event::Key(key) = event else {
+            return EventResult::Ignored;
+        };
+        if key.kind != KeyEventKind::Press {
+            return EventResult::Ignored;
+        }
+
+        match key.code {
+            KeyCode::Esc => {
+                exit();
+            }
+            KeyCode::Enter => save.call(()),
+            KeyCode::Backspace => {
+                query.write().pop();
+            }
+            KeyCode::Char(c) => query.write().push(c),
+            _ => return EventResult::Ignored,
+        }
+        EventResult::Consumed
+    });
+
+    let lines = [
+        Line::from(format!("{} working", SPINNER[frame.get() % SPINNER.len()]))
+            .yellow()
+            .centered(),
+        Line::from(format!("typed:     {}", *query.read())).centered(),
+        Line::from(format!("debounced: {debounced}"))
+            .green()
+            .centered(),
+        Line::from(format!("saves (throttled to 1/s): {}", saves.get())).centered(),
+        if hint.get() {
+            Line::from("type to search | Enter save | Esc quit")
+                .dark_gray()
+                .centered()
+        } else {
+            Line::from("")
+        },
+    ];
+
+    element!(
+        Center(
+            width: Constraint::Length(56),
+            height: Constraint::Length(9),
+        ) {
+            Border(
+                border_style: Style::new().cyan(),
+                flex_direction: Direction::Vertical,
+                justify_content: Flex::Center,
+                top_title: Line::from(" timers without tokio::time ").cyan().bold().centered(),
+            ) {
+                for (index, line) in lines.into_iter().enumerate() {
+                    View(height: Constraint::Length(1), key: index) {
+                        Text(text: line)
+                    }
+                }
+            }
+        }
+    )
+}